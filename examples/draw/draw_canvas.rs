@@ -0,0 +1,66 @@
+//! Draw to a fixed 1920x1080 canvas that is fitted to the window.
+//!
+//! Resize the window to see the canvas fitted to it. Press `1`, `2` or `3` to view the canvas with
+//! letterbox, stretch or fill fitting. The mouse position is reported in canvas coordinates.
+
+use nannou::draw::canvas::Fit;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).run();
+}
+
+fn model(app: &App) {
+    app.new_window()
+        .size(960, 720)
+        .canvas_size(1920.0, 1080.0)
+        .key_pressed(key_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+}
+
+fn key_pressed(app: &App, _model: &mut (), key: Key) {
+    let fit = match key {
+        Key::Key1 => Fit::Letterbox,
+        Key::Key2 => Fit::Stretch,
+        Key::Key3 => Fit::Fill,
+        _ => return,
+    };
+    let window = app.main_window();
+    let canvas = window.canvas().map(|canvas| canvas.fit(fit));
+    window.set_canvas(canvas);
+}
+
+fn view(app: &App, _model: &(), frame: Frame) {
+    frame.clear(BLACK);
+    let draw = app.draw();
+    let window = app.main_window();
+    let canvas = window.canvas().unwrap();
+    let rect = window.canvas_rect();
+
+    draw.rect().wh(rect.wh()).color(DARKSLATEGREY);
+    for x in (-8..=8).map(|i| i as f32 * 120.0) {
+        draw.line()
+            .start(pt2(x, rect.bottom()))
+            .end(pt2(x, rect.top()))
+            .color(SLATEGREY);
+    }
+    draw.ellipse()
+        .wh(rect.wh() * 0.5)
+        .no_fill()
+        .stroke(WHITE)
+        .stroke_weight(4.0);
+
+    // The window's mouse position is always in the coordinates of its canvas.
+    let mouse = app.mouse.position();
+    draw.ellipse().xy(mouse).radius(20.0).color(ORANGE);
+    let label = format!("{:?}\n{:.0}, {:.0}", canvas.fit, mouse.x, mouse.y);
+    draw.text(&label)
+        .xy(rect.top_left() + vec2(200.0, -60.0))
+        .w(400.0)
+        .font_size(32)
+        .left_justify();
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -8,16 +8,12 @@ fn main() {
 }
 
 struct Model {
-    // The texture that we will draw to.
-    texture: wgpu::Texture,
+    // The texture that we will draw to along with the renderer used to draw to it.
+    target: nannou::draw::RenderTarget,
     // Create a `Draw` instance for drawing to our texture.
     draw: nannou::Draw,
-    // The type used to render the `Draw` vertices to our texture.
-    renderer: nannou::draw::Renderer,
     // The type used to capture the texture.
     texture_capturer: wgpu::TextureCapturer,
-    // The type used to resize our texture to the window texture.
-    texture_reshaper: wgpu::TextureReshaper,
 }
 
 fn model(app: &App) -> Model {
@@ -38,51 +34,29 @@ fn model(app: &App) -> Model {
     // Retrieve the wgpu device.
     let device = window.device();
 
-    // Create our custom texture.
-    let sample_count = window.msaa_samples();
-    let texture = wgpu::TextureBuilder::new()
+    // Create our render target.
+    let target = nannou::draw::RenderTarget::builder()
         .size(texture_size)
-        // Our texture will be used as the RENDER_ATTACHMENT for our `Draw` render pass.
-        // It will also be SAMPLED by the `TextureCapturer` and `TextureResizer`.
-        .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING)
-        // Use nannou's default multisampling sample count.
-        .sample_count(sample_count)
+        // Use nannou's default multisampling sample count. The target resolves it for us.
+        .msaa_samples(window.msaa_samples())
         // Use a spacious 16-bit linear sRGBA format suitable for high quality drawing.
         .format(wgpu::TextureFormat::Rgba16Float)
         // Build it!
         .build(device);
 
-    // Create our `Draw` instance and a renderer for it.
+    // Create our `Draw` instance.
     let draw = nannou::Draw::new();
-    let descriptor = texture.descriptor();
-    let renderer =
-        nannou::draw::RendererBuilder::new().build_from_texture_descriptor(device, descriptor);
 
     // Create the texture capturer.
     let texture_capturer = wgpu::TextureCapturer::default();
 
-    // Create the texture reshaper.
-    let texture_view = texture.view().build();
-    let texture_sample_type = texture.sample_type();
-    let dst_format = Frame::TEXTURE_FORMAT;
-    let texture_reshaper = wgpu::TextureReshaper::new(
-        device,
-        &texture_view,
-        sample_count,
-        texture_sample_type,
-        sample_count,
-        dst_format,
-    );
-
     // Make sure the directory where we will save images to exists.
     std::fs::create_dir_all(&capture_directory(app)).unwrap();
 
     Model {
-        texture,
+        target,
         draw,
-        renderer,
         texture_capturer,
-        texture_reshaper,
     }
 }
 
@@ -92,7 +66,7 @@ fn update(app: &App, model: &mut Model, _update: Update) {
     draw.reset();
 
     // Create a `Rect` for our texture to help with drawing.
-    let [w, h] = model.texture.size();
+    let [w, h] = model.target.size();
     let r = geom::Rect::from_w_h(w as f32, h as f32);
 
     // Use the frame number to animate, ensuring we get a constant update time.
@@ -142,18 +116,15 @@ fn update(app: &App, model: &mut Model, _update: Update) {
         label: Some("texture renderer"),
     };
     let mut encoder = device.create_command_encoder(&ce_desc);
-    model
-        .renderer
-        .render_to_texture(device, &mut encoder, draw, &model.texture);
+    model.target.encode_render_pass(device, &mut encoder, draw);
 
     // Take a snapshot of the texture. The capturer will do the following:
     //
-    // 1. Resolve the texture to a non-multisampled texture if necessary.
-    // 2. Convert the format to non-linear 8-bit sRGBA ready for image storage.
-    // 3. Copy the result to a buffer ready to be mapped for reading.
+    // 1. Convert the format to non-linear 8-bit sRGBA ready for image storage.
+    // 2. Copy the result to a buffer ready to be mapped for reading.
     let snapshot = model
-        .texture_capturer
-        .capture(device, &mut encoder, &model.texture);
+        .target
+        .capture(device, &mut encoder, &model.texture_capturer);
 
     // Submit the commands for our drawing and texture capture to the GPU.
     window.queue().submit(Some(encoder.finish()));
@@ -176,12 +147,12 @@ fn update(app: &App, model: &mut Model, _update: Update) {
 }
 
 // Draw the state of your `Model` into the given `Frame` here.
-fn view(_app: &App, model: &Model, frame: Frame) {
-    // Sample the texture and write it to the frame.
-    let mut encoder = frame.command_encoder();
-    model
-        .texture_reshaper
-        .encode_render_pass(frame.texture_view(), &mut *encoder);
+fn view(app: &App, model: &Model, frame: Frame) {
+    // Draw the texture scaled down to fit the window.
+    let draw = app.draw();
+    let win = app.window_rect();
+    draw.texture(&model.target).wh(win.wh());
+    draw.to_frame(app, &frame).unwrap();
 }
 
 // Wait for capture to finish.
@@ -0,0 +1,47 @@
+//! Apply bloom, chromatic aberration and a vignette to the window's frames.
+//!
+//! Move the mouse horizontally to vary the intensity of the bloom.
+
+use nannou::frame::PostProcess;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    window: window::Id,
+}
+
+fn model(app: &App) -> Model {
+    let window = app.new_window().view(view).build().unwrap();
+    Model { window }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let w = app.window_rect();
+    let intensity = map_range(app.mouse.x, w.left(), w.right(), 0.0, 2.0);
+    let post = PostProcess::new()
+        .bloom(0.6, intensity, 12.0)
+        .chromatic_aberration(4.0)
+        .vignette(0.7, 0.6);
+    if let Some(window) = app.window(model.window) {
+        window.set_post_process(post);
+    }
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let t = app.time;
+    for i in 0..12 {
+        let angle = i as f32 / 12.0 * TAU + t * 0.5;
+        let radius = 150.0 + (t * 2.0 + i as f32).sin() * 40.0;
+        let hue = i as f32 / 12.0;
+        draw.ellipse()
+            .x_y(angle.cos() * radius, angle.sin() * radius)
+            .radius(20.0)
+            .color(hsl(hue, 1.0, 0.6));
+    }
+    draw.to_frame(app, &frame).unwrap();
+}
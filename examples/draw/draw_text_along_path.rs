@@ -0,0 +1,49 @@
+//! Demonstrates laying text out along a curve via `draw.text(s).along_path(&path)`.
+//!
+//! Each glyph is rotated to follow the tangent of the curve. Animating the path offset scrolls the
+//! text along the curve.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    // A wave spanning the width of the window.
+    let win_rect = app.main_window().rect().pad(40.0);
+    let t = app.time;
+    let points = (0..=100).map(|i| {
+        let x = map_range(i, 0, 100, win_rect.left(), win_rect.right());
+        let y = (x * 0.01 + t).sin() * win_rect.h() * 0.25;
+        pt2(x, y)
+    });
+    let mut builder = geom::path();
+    for (i, p) in points.enumerate() {
+        builder = match i {
+            0 => builder.begin(p),
+            _ => builder.line_to(p),
+        };
+    }
+    let path = builder.end().build();
+
+    draw.path()
+        .stroke()
+        .weight(1.0)
+        .color(LIGHTGREY)
+        .events(path.iter());
+
+    // Scroll the text back and forth along the wave.
+    let offset = (t * 0.5).sin() * win_rect.w() * 0.25;
+    draw.text("Kinetic typography flowing along a curve")
+        .font_size(32)
+        .no_line_wrap()
+        .along_path(&path)
+        .path_offset(offset)
+        .color(BLACK);
+
+    draw.to_frame(app, &frame).unwrap();
+}
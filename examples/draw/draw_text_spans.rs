@@ -0,0 +1,43 @@
+//! Demonstrates styling ranges of text with spans via `draw.text(s).spans(spans)`.
+//!
+//! Each span may override the font size and color of its range and may add an underline or
+//! strikethrough. Lines are wrapped and sized according to the largest text that they contain.
+
+use nannou::prelude::*;
+use nannou::text::span::{self, Style};
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    let win_rect = app.main_window().rect().pad(20.0);
+
+    let (text, spans) = span::concat(vec![
+        ("Spans ", Style::new().font_size(48).color(STEELBLUE)),
+        ("allow for ", Style::new()),
+        ("mixed sizes", Style::new().font_size(36)),
+        (", ", Style::new()),
+        ("colors", Style::new().color(CRIMSON)),
+        (", ", Style::new()),
+        ("underlines", Style::new().underline()),
+        (" and ", Style::new()),
+        ("strikethroughs", Style::new().strikethrough().color(GREY)),
+        (" within a single block of wrapped text.", Style::new()),
+        (
+            "\n\nResize the window to test dynamic layout.",
+            Style::new().font_size(16),
+        ),
+    ]);
+
+    draw.text(&text)
+        .color(BLACK)
+        .spans(spans)
+        .font_size(24)
+        .wh(win_rect.wh());
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -33,6 +33,7 @@ fn model(app: &App) -> Model {
         .focused(window_focused)
         .unfocused(window_unfocused)
         .closed(window_closed)
+        .gamepad(gamepad)
         .build()
         .unwrap();
     Model {}
@@ -49,6 +50,7 @@ fn event(_app: &App, _model: &mut Model, event: Event) {
         Event::Update(_dt) => {}
         Event::Suspended => {}
         Event::Resumed => {}
+        Event::Gamepad(_event) => {}
     }
 }
 
@@ -104,6 +106,8 @@ fn touch(_app: &App, _model: &mut Model, _touch: TouchEvent) {}
 
 fn touchpad_pressure(_app: &App, _model: &mut Model, _pressure: TouchpadPressure) {}
 
+fn gamepad(_app: &App, _model: &mut Model, _event: GamepadEvent) {}
+
 fn window_moved(_app: &App, _model: &mut Model, _pos: Point2) {}
 
 fn window_resized(_app: &App, _model: &mut Model, _dim: Vec2) {}
@@ -0,0 +1,39 @@
+//! Render a short animation without a window or display server.
+//!
+//! Each frame is written to the `headless` directory next to the example's executable. This is
+//! useful for rendering on machines without a display, e.g. render farms or CI containers.
+
+use nannou::headless;
+use nannou::prelude::*;
+
+fn main() {
+    let directory = headless_directory();
+    nannou::sketch(view)
+        .headless(headless::Builder::new().size_pixels(640, 480))
+        .loop_mode(LoopMode::offline(directory, 120, 60.0))
+        .run();
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let r = app.window_rect();
+    let t = app.time;
+    for i in 0..8 {
+        let phase = i as f32 / 8.0 * TAU;
+        let x = (t * 2.0 + phase).sin() * r.w() * 0.4;
+        let y = (t * 3.0 + phase).cos() * r.h() * 0.4;
+        draw.ellipse()
+            .x_y(x, y)
+            .radius(20.0)
+            .hsv(i as f32 / 8.0, 0.8, 1.0);
+    }
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn headless_directory() -> std::path::PathBuf {
+    let exe = std::env::current_exe().expect("failed to locate executable");
+    exe.parent()
+        .expect("executable has no parent directory")
+        .join("headless")
+}
@@ -0,0 +1,122 @@
+//! Declare the parameters of a sketch once and get an egui panel, OSC bindings and TOML presets.
+//!
+//! - Tweak the parameters via the egui window.
+//! - Send OSC messages to port 34254, e.g. `/param/radius 120.0` or `/param/shape "rect"`.
+//! - Press `S` to save a preset and `L` to load it again.
+
+use nannou::params::Params;
+use nannou::prelude::*;
+use nannou_egui::{self, egui, Egui};
+use nannou_osc as osc;
+
+const PORT: u16 = 34254;
+const PRESET_FILE: &str = "params_preset.toml";
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    egui: Egui,
+    params: Params,
+    receiver: osc::Receiver,
+}
+
+fn model(app: &App) -> Model {
+    let window_id = app
+        .new_window()
+        .title("Nannou + Params")
+        .size(640, 360)
+        .raw_event(raw_window_event)
+        .key_pressed(key_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+    let window = app.window(window_id).unwrap();
+
+    let params = Params::new()
+        .float("radius", 40.0, 10.0..=150.0)
+        .float("speed", 1.0, 0.0..=5.0)
+        .bool("animate", true)
+        .color("fill", srgba(1.0, 0.4, 0.2, 1.0))
+        .choice("shape", 0, &["ellipse", "rect", "tri"]);
+
+    Model {
+        egui: Egui::from_window(&window),
+        params,
+        receiver: osc::receiver(PORT).unwrap(),
+    }
+}
+
+fn update(_app: &App, model: &mut Model, update: Update) {
+    model.receiver.try_update_params(&mut model.params).ok();
+
+    let Model {
+        ref mut egui,
+        ref mut params,
+        ..
+    } = *model;
+    egui.set_elapsed_time(update.since_start);
+    let ctx = egui.begin_frame();
+    egui::Window::new("Params").show(&ctx, |ui| {
+        nannou_egui::edit_params(ui, params);
+    });
+}
+
+fn raw_window_event(_app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    model.egui.handle_raw_event(event);
+}
+
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let path = app.project_path().unwrap().join(PRESET_FILE);
+    match key {
+        Key::S => {
+            if let Err(err) = model.params.save_preset(&path) {
+                eprintln!("failed to save preset: {}", err);
+            }
+        }
+        Key::L => {
+            if let Err(err) = model.params.load_preset(&path) {
+                eprintln!("failed to load preset: {}", err);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    frame.clear(BLACK);
+
+    let params = &model.params;
+    let radius = params.get_f32("radius").unwrap();
+    let color = params.get_color("fill").unwrap();
+    let angle = match params.get_bool("animate").unwrap() {
+        true => app.time * params.get_f32("speed").unwrap(),
+        false => 0.0,
+    };
+
+    match params.get_choice_name("shape").unwrap() {
+        "ellipse" => {
+            draw.ellipse()
+                .radius(radius)
+                .x(angle.cos() * radius)
+                .color(color);
+        }
+        "rect" => {
+            draw.rect()
+                .w_h(radius * 2.0, radius * 2.0)
+                .rotate(angle)
+                .color(color);
+        }
+        _ => {
+            draw.tri()
+                .w_h(radius * 2.0, radius * 2.0)
+                .rotate(angle)
+                .color(color);
+        }
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+    model.egui.draw_to_frame(&frame).unwrap();
+}
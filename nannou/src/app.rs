@@ -11,7 +11,13 @@
 use crate::draw;
 use crate::event::{self, Event, Key, LoopEvent, Update};
 use crate::frame::{Frame, RawFrame};
-use crate::geom;
+use crate::gamepad;
+use crate::geom::{self, Point2};
+use crate::gesture;
+use crate::glam::Vec2;
+use crate::headless;
+use crate::recording::{self, RecordedEvent, Recording};
+use crate::snapshot::Snapshot;
 use crate::state;
 use crate::time::DurationF64;
 use crate::wgpu;
@@ -19,9 +25,9 @@ use crate::window::{self, Window};
 use find_folder;
 use instant::Instant;
 use std::cell::{RefCell, RefMut};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::atomic::{self, AtomicBool};
 use std::sync::Arc;
@@ -48,6 +54,12 @@ pub type SketchViewFn = fn(&App, Frame);
 /// The user function type allowing them to consume the `model` when the application exits.
 pub type ExitFn<Model> = fn(&App, Model);
 
+/// The user function type for processing gamepad events.
+pub type GamepadFn<Model> = fn(&App, &mut Model, event::GamepadEvent);
+
+// The interval at which gamepads and long presses are polled while the `Wait` loop mode is idle.
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(16);
+
 /// The **App**'s view function.
 enum View<Model = ()> {
     /// A view function allows for viewing the user's model.
@@ -64,11 +76,16 @@ pub struct Builder<M = (), E = Event> {
     update: Option<UpdateFn<M>>,
     default_view: Option<View<M>>,
     exit: Option<ExitFn<M>>,
+    gamepad: Option<GamepadFn<M>>,
     create_default_window: bool,
     default_window_size: Option<DefaultWindowSize>,
     capture_frame_timeout: Option<Option<Duration>>,
     max_capture_frame_jobs: Option<u32>,
     backends: wgpu::Backends,
+    headless: Option<headless::Builder>,
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    snapshot: Option<Snapshot<M>>,
 }
 
 /// A nannou `Sketch` builder.
@@ -110,6 +127,14 @@ pub struct App {
     pub(crate) event_loop_window_target: Option<EventLoopWindowTarget>,
     pub(crate) event_loop_proxy: Proxy,
     pub(crate) windows: RefCell<HashMap<window::Id, Window>>,
+    /// The offscreen target rendered to in place of windows when running headless.
+    pub(crate) headless: Option<headless::Target>,
+    /// The IDs of all windows in order of creation, used to identify windows within recordings.
+    pub(crate) window_creation_order: RefCell<Vec<window::Id>>,
+    /// Records the events delivered to the model if requested via `app::Builder::record`.
+    recorder: RefCell<Option<recording::Recorder>>,
+    /// Replays recorded events in place of live input if requested via `app::Builder::replay`.
+    player: RefCell<Option<recording::Player>>,
     /// The wgpu backends to choose between.
     backends: wgpu::Backends,
     /// The main wgpu instance.
@@ -121,6 +146,16 @@ pub struct App {
     pub(crate) focused_window: RefCell<Option<window::Id>>,
     /// The current state of the `Mouse`.
     pub mouse: state::Mouse,
+    /// The state of all touches currently in contact with a touch screen.
+    pub touches: state::Touches,
+    /// Recognises gestures from the touch events of each window.
+    gesture_recognizers: RefCell<HashMap<window::Id, gesture::Recognizer>>,
+    /// The state of all connected gamepads.
+    ///
+    /// Gamepads are only tracked when nannou is built with the `gamepad` feature.
+    pub gamepads: state::Gamepads,
+    /// Polls the OS for gamepad events.
+    gamepad_backend: RefCell<gamepad::Backend>,
     /// State of the keyboard keys.
     ///
     /// `mods` provides state of each of the modifier keys: `shift`, `ctrl`, `alt`, `logo`.
@@ -176,7 +211,8 @@ struct DrawState {
 /// **App**'s inner event loop.
 #[derive(Clone)]
 pub struct Proxy {
-    event_loop_proxy: winit::event_loop::EventLoopProxy<()>,
+    // `None` in the case that the **App** is headless and there is no event loop to wake.
+    event_loop_proxy: Option<winit::event_loop::EventLoopProxy<()>>,
     // Whether or not a wakeup is already queued.
     //
     // Used to avoid spuriously calling `EventLoopProxy::send_event` as this can be expensive on
@@ -190,6 +226,32 @@ struct LoopState {
     loop_start: Instant,
     last_update: Instant,
     total_updates: u64,
+    // The moment at which time was last accumulated for the `Rate` loop mode.
+    last_tick: Instant,
+    // Time accumulated but not yet consumed by fixed updates in the `Rate` loop mode.
+    accumulator: Duration,
+    // The sum of the intervals of all fixed updates emitted in the `Rate` loop mode.
+    fixed_since_start: Duration,
+    // The number of updates emitted in the `Offline` loop mode.
+    offline_frames: u64,
+    // Windows that are yet to present the frame for the latest `Offline` update.
+    offline_awaiting: HashSet<window::Id>,
+}
+
+impl LoopState {
+    fn new(loop_start: Instant) -> Self {
+        LoopState {
+            updates_since_event: 0,
+            loop_start,
+            last_update: loop_start,
+            total_updates: 0,
+            last_tick: loop_start,
+            accumulator: Duration::from_secs(0),
+            fixed_since_start: Duration::from_secs(0),
+            offline_frames: 0,
+            offline_awaiting: HashSet::new(),
+        }
+    }
 }
 
 /// The mode in which the **App** is currently running the event loop and emitting `Update` events.
@@ -202,13 +264,24 @@ pub enum LoopMode {
     /// get called at a fairly consistent interval that is close to 60 times per second.
     RefreshSync,
 
-    /// Specifies that the application is continuously looping at a consistent rate.
+    /// Emits `Update` events at a fixed rate, independent of the display refresh rate.
     ///
-    /// **NOTE:** This currently behaves the same as `RefreshSync`. Need to upate this to handled a
-    /// fix step properly in the future. See #456.
+    /// Elapsed time is accumulated on each pass of the event loop and consumed in steps of
+    /// `update_interval`, emitting one `Update` per step. Each `Update` advances `since_start` by
+    /// exactly `update_interval`, making simulations deterministic regardless of how quickly
+    /// frames are presented. The time left over after each pass is reported via
+    /// `Update::leftover`.
+    ///
+    /// If the app falls behind, at most `max_catch_up` updates are emitted in a single pass and
+    /// any remaining whole intervals are discarded. This avoids a "spiral of death" where slow
+    /// updates cause ever more updates to be required.
     Rate {
-        /// The minimum interval between emitted updates.
+        /// The fixed interval between emitted updates.
         update_interval: Duration,
+        /// The maximum number of updates emitted in a single pass of the event loop.
+        ///
+        /// A value of `0` is treated as `1`.
+        max_catch_up: u32,
     },
 
     /// Waits for user input, window, device and wake-up events to occur before producing `Update`
@@ -231,6 +304,28 @@ pub enum LoopMode {
         /// The number of updates that must be emited regardless of non-update events
         number_of_updates: usize,
     },
+
+    /// Renders exactly `number_of_frames` frames driven by a virtual clock, capturing each frame
+    /// of each window to a numbered PNG image within `directory`.
+    ///
+    /// Each `Update` advances `app.time` and `Update::since_start` by exactly `frame_interval`,
+    /// regardless of how long each frame takes to render. The next update is only emitted once
+    /// every window has presented the frame for the previous update, so no frames are skipped.
+    /// This is useful for rendering videos of sketches that cannot run in real time.
+    ///
+    /// Frames are written via each window's `capture_frame` worker pool and are named by their
+    /// frame number, e.g. `000042.png`. Apps with more than one window append the window's index
+    /// in order of `window::Id`, e.g. `000042-1.png`.
+    ///
+    /// Once all frames have been rendered, the loop waits for events as in the `Wait` mode.
+    Offline {
+        /// The directory to which captured frames are written.
+        directory: PathBuf,
+        /// The number of frames to render.
+        number_of_frames: usize,
+        /// The virtual duration of each frame.
+        frame_interval: Duration,
+    },
 }
 
 impl<M> Builder<M, Event>
@@ -265,11 +360,16 @@ where
             update: None,
             default_view: None,
             exit: None,
+            gamepad: None,
             create_default_window: false,
             default_window_size: None,
             max_capture_frame_jobs: None,
             capture_frame_timeout: None,
             backends: Self::DEFAULT_BACKENDS,
+            headless: None,
+            record: None,
+            replay: None,
+            snapshot: None,
         }
     }
 
@@ -291,11 +391,16 @@ where
             update,
             default_view,
             exit,
+            gamepad,
             create_default_window,
             default_window_size,
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends,
+            headless,
+            record,
+            replay,
+            snapshot,
             ..
         } = self;
         Builder {
@@ -305,11 +410,16 @@ where
             update,
             default_view,
             exit,
+            gamepad,
             create_default_window,
             default_window_size,
             max_capture_frame_jobs,
             capture_frame_timeout,
-            backends
+            backends,
+            headless,
+            record,
+            replay,
+            snapshot,
         }
     }
 }
@@ -378,6 +488,17 @@ where
         self
     }
 
+    /// A function for processing gamepad events.
+    ///
+    /// Gamepad events are delivered before each update, after `app.gamepads` has been updated. The
+    /// event is also delivered to the `gamepad` function of the window in focus, if any.
+    ///
+    /// Gamepad events are only emitted when nannou is built with the `gamepad` feature.
+    pub fn gamepad(mut self, gamepad: GamepadFn<M>) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
     /// Specify the default window size in points.
     ///
     /// If a window is created and its size is not specified, this size will be used.
@@ -443,6 +564,60 @@ where
         self
     }
 
+    /// Run the app headless, without creating a window or connecting to a display server.
+    ///
+    /// Rather than presenting to the surface of a window, the default `view` function renders each
+    /// frame to an offscreen **headless::Target** described by the given builder. The target may
+    /// be accessed via `App::headless`, e.g. to capture frames or read back its texture. This is
+    /// useful for running sketches on render farms or in CI containers.
+    ///
+    /// As there are no windows, `App::new_window` returns an error and any window created via
+    /// `simple_window` or `sketch` is omitted. There are also no input events to wait on. The
+    /// `Wait` loop mode produces a single frame, `NTimes` and `Offline` produce their given number
+    /// of frames and `RefreshSync` and `Rate` run until `App::quit` is called. The app exits once
+    /// there are no more frames to produce.
+    pub fn headless(mut self, headless: headless::Builder) -> Self {
+        self.headless = Some(headless);
+        self
+    }
+
+    /// Record the window and gamepad input events and updates delivered to the model, writing them
+    /// to a JSON file at the given path when the app exits.
+    ///
+    /// The resulting **recording::Recording** may be fed back to the app via `replay`.
+    ///
+    /// `DeviceEvent`s, the `Suspended` and `Resumed` events and the raw winit events delivered to
+    /// window `raw_event` functions are not recorded, so a replay does not reproduce them.
+    pub fn record<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.record = Some(path.into());
+        self
+    }
+
+    /// Replay the **recording::Recording** stored at the given path in place of live input.
+    ///
+    /// Each recorded event is delivered at the time at which it was recorded, relative to the
+    /// launch of the app. Recorded updates are applied in place of the updates that the loop mode
+    /// would otherwise produce. The window and gamepad input events recorded before each update are
+    /// delivered to the `event`, `gamepad` and window functions first, in their original order. In the `Offline` loop
+    /// mode, replay proceeds on a virtual clock instead, applying each recorded update once the
+    /// frame of the previous one has been captured.
+    ///
+    /// Live keyboard, mouse, touch, gamepad and file input is ignored while replaying, and nothing
+    /// is recorded via `record` until the replay completes. Once the recording has been
+    /// exhausted, the app continues running as normal.
+    ///
+    /// **Panics** if the recording cannot be loaded.
+    pub fn replay<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.replay = Some(path.into());
+        self
+    }
+
     /// Build and run an `App` with the specified parameters.
     ///
     /// This function will not return until the application has exited.
@@ -455,6 +630,10 @@ where
     }
 
     pub async fn run_async(self) {
+        if self.headless.is_some() {
+            return self.run_headless_async().await;
+        }
+
         // Start the winit window event loop.
         let event_loop = winit::event_loop::EventLoop::new();
 
@@ -462,7 +641,7 @@ where
         let event_loop_proxy = event_loop.create_proxy();
         let wakeup_queued = Arc::new(AtomicBool::new(false));
         let event_loop_proxy = Proxy {
-            event_loop_proxy,
+            event_loop_proxy: Some(event_loop_proxy),
             wakeup_queued,
         };
 
@@ -494,8 +673,14 @@ where
             *app.focused_window.borrow_mut() = Some(window_id);
         }
 
-        // Call the user's model function.
+        app.init_recording(self.record, self.replay);
+
+        // Call the user's model function, then restore the model's snapshot if there is one.
         let model = Pin::from((self.model)(&app)).await;
+        let model = match self.snapshot {
+            Some(ref snapshot) => snapshot.restore(model),
+            None => model,
+        };
 
         // If there is not yet some default window in "focus" check to see if one has been created.
         if app.focused_window.borrow().is_none() {
@@ -511,8 +696,91 @@ where
             self.update,
             self.default_view,
             self.exit,
+            self.gamepad,
+            self.snapshot,
         );
     }
+
+    // Build and run a headless `App` without a winit event loop or windows.
+    async fn run_headless_async(self) {
+        // There is no event loop to wake.
+        let event_loop_proxy = Proxy {
+            event_loop_proxy: None,
+            wakeup_queued: Arc::new(AtomicBool::new(false)),
+        };
+
+        // Initialise the app.
+        let max_capture_frame_jobs = self
+            .max_capture_frame_jobs
+            .unwrap_or(num_cpus::get() as u32);
+        let capture_frame_timeout = self
+            .capture_frame_timeout
+            .unwrap_or(Some(Self::DEFAULT_CAPTURE_FRAME_TIMEOUT));
+        let mut app = App::new(
+            self.config,
+            event_loop_proxy,
+            None,
+            self.default_window_size,
+            max_capture_frame_jobs,
+            capture_frame_timeout,
+            self.backends,
+        );
+
+        // Create the offscreen target in place of the default window.
+        let headless = self.headless.expect("no headless target was specified");
+        let target = headless
+            .build_async(&app, max_capture_frame_jobs, capture_frame_timeout)
+            .await
+            .expect("could not build headless app target");
+        app.window_creation_order.borrow_mut().push(target.id());
+        app.headless = Some(target);
+        app.init_recording(self.record, self.replay);
+
+        // Call the user's model function, then restore the model's snapshot if there is one.
+        let model = Pin::from((self.model)(&app)).await;
+        let model = match self.snapshot {
+            Some(ref snapshot) => snapshot.restore(model),
+            None => model,
+        };
+
+        run_headless_loop(
+            app,
+            model,
+            self.event,
+            self.update,
+            self.default_view,
+            self.exit,
+            self.gamepad,
+            self.snapshot,
+        );
+    }
+}
+
+impl<M, E> Builder<M, E>
+where
+    M: 'static + serde::Serialize + for<'de> serde::Deserialize<'de>,
+    E: LoopEvent,
+{
+    /// Snapshot the model to a JSON file at the given path when the app exits and restore it from
+    /// that file the next time the app is launched.
+    ///
+    /// The `model` function is still called on each launch, allowing it to create windows and
+    /// other resources. If a snapshot exists, the model returned by the `model` function is then
+    /// replaced by the restored model. Any fields skipped via `#[serde(skip)]` take their default
+    /// values. This allows camera positions, seeds and parameters to persist across changes to a
+    /// sketch's code.
+    ///
+    /// If the snapshot cannot be restored, e.g. because the fields of the model have changed, a
+    /// warning is printed and the model returned by the `model` function is used instead.
+    ///
+    /// Snapshots are only restored on launch. See the `snapshot` module for details.
+    pub fn snapshot<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.snapshot = Some(Snapshot::new(path));
+        self
+    }
 }
 
 impl<E> SketchBuilder<E>
@@ -533,6 +801,14 @@ where
         self
     }
 
+    /// Run the sketch headless, rendering to an offscreen target rather than a window.
+    ///
+    /// This method delegates to `Builder::headless`.
+    pub fn headless(mut self, headless: headless::Builder) -> Self {
+        self.builder = self.builder.headless(headless);
+        self
+    }
+
     /// Build and run a `Sketch` with the specified parameters.
     ///
     /// This calls `App::run` internally. See that method for details!
@@ -567,6 +843,8 @@ fn update_interval(fps: f64) -> Duration {
 
 impl LoopMode {
     pub const DEFAULT_RATE_FPS: f64 = 60.0;
+    /// The default maximum number of updates emitted in a single pass in the **Rate** mode.
+    pub const DEFAULT_RATE_MAX_CATCH_UP: u32 = 5;
     /// The minimum number of updates that will be emitted after an event is triggered in Wait
     /// mode.
     pub const UPDATES_PER_WAIT_EVENT: u32 = 3;
@@ -579,10 +857,21 @@ impl LoopMode {
         LoopMode::RefreshSync
     }
 
-    /// Specify the **Rate** mode with the given frames-per-second.
+    /// Specify the **Rate** mode with the given updates-per-second.
     pub fn rate_fps(fps: f64) -> Self {
+        Self::rate_fps_max_catch_up(fps, Self::DEFAULT_RATE_MAX_CATCH_UP)
+    }
+
+    /// Specify the **Rate** mode with the given updates-per-second and the maximum number of
+    /// updates that may be emitted in a single pass of the event loop when catching up.
+    ///
+    /// A `max_catch_up` of `0` is treated as `1`.
+    pub fn rate_fps_max_catch_up(fps: f64, max_catch_up: u32) -> Self {
         let update_interval = update_interval(fps);
-        LoopMode::Rate { update_interval }
+        LoopMode::Rate {
+            update_interval,
+            max_catch_up: max_catch_up.max(1),
+        }
     }
 
     /// Specify the **Wait** mode.
@@ -601,6 +890,21 @@ impl LoopMode {
     pub fn loop_once() -> Self {
         Self::loop_ntimes(1)
     }
+
+    /// Specify the **Offline** mode, rendering `number_of_frames` frames at the given virtual
+    /// frames-per-second and capturing them to `directory`.
+    pub fn offline<P>(directory: P, number_of_frames: usize, fps: f64) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let directory = directory.into();
+        let frame_interval = update_interval(fps);
+        LoopMode::Offline {
+            directory,
+            number_of_frames,
+            frame_interval,
+        }
+    }
 }
 
 impl Default for LoopMode {
@@ -656,6 +960,10 @@ impl App {
             max_capture_frame_jobs,
             capture_frame_timeout,
             focused_window,
+            headless: None,
+            window_creation_order: RefCell::new(Vec::new()),
+            recorder: RefCell::new(None),
+            player: RefCell::new(None),
             backends,
             instance,
             adapters,
@@ -663,6 +971,10 @@ impl App {
             config,
             draw_state,
             mouse,
+            touches: Default::default(),
+            gesture_recognizers: Default::default(),
+            gamepads: Default::default(),
+            gamepad_backend: RefCell::new(gamepad::Backend::new()),
             keys,
             duration,
             time,
@@ -690,6 +1002,9 @@ impl App {
     /// May return None if none can be detected. For example, this can happen when running on Linux
     /// with Wayland.
     pub fn primary_monitor(&self) -> Option<winit::monitor::MonitorHandle> {
+        if self.headless.is_some() {
+            return None;
+        }
         match self.event_loop_window_target {
             Some(EventLoopWindowTarget::Owned(ref event_loop)) => event_loop.primary_monitor(),
             _ => {
@@ -757,8 +1072,13 @@ impl App {
 
     /// Return the **Id** of the currently focused window.
     ///
+    /// If the app is headless, this is the **Id** of the headless target.
+    ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_id(&self) -> window::Id {
+        if let Some(target) = self.headless() {
+            return target.id();
+        }
         self.focused_window
             .borrow()
             .expect("called `App::window_id` but there is no window currently in focus")
@@ -775,24 +1095,55 @@ impl App {
     ///
     /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor).
     ///
+    /// If the app is headless, this is the **Rect** of the headless target.
+    ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_rect(&self) -> geom::Rect<f32> {
+        if let Some(target) = self.headless() {
+            return target.rect();
+        }
         self.main_window().rect()
     }
 
     /// A reference to the window currently in focus.
     ///
-    /// **Panics** if their are no windows open in the **App**.
+    /// **Panics** if their are no windows open in the **App** or if the app is headless. Use
+    /// **App::headless** to access the target of a headless app.
     ///
     /// Uses the **App::window** method internally.
     ///
     /// TODO: Currently this produces a reference to the *focused* window, but this behaviour
     /// should be changed to track the "main" window (the first window created?).
     pub fn main_window(&self) -> std::cell::Ref<Window> {
+        assert!(
+            self.headless.is_none(),
+            "called `App::main_window` on a headless app, use `App::headless` instead"
+        );
         self.window(self.window_id())
             .expect("no window for focused id")
     }
 
+    /// The offscreen target rendered to in place of windows if the app is headless.
+    ///
+    /// Returns `None` if the app was not built via `app::Builder::headless`.
+    pub fn headless(&self) -> Option<&headless::Target> {
+        self.headless.as_ref()
+    }
+
+    /// Whether or not the app is currently replaying a **recording::Recording**.
+    ///
+    /// See `app::Builder::replay`.
+    pub fn is_replaying(&self) -> bool {
+        self.player.borrow().is_some()
+    }
+
+    /// Whether or not the app is currently recording its input events.
+    ///
+    /// See `app::Builder::record`.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.borrow().is_some()
+    }
+
     /// Return the wgpu `Backends` in use.
     pub fn backends(&self) -> wgpu::Backends {
         self.backends
@@ -890,7 +1241,12 @@ impl App {
 
     /// The number of times the focused window's **view** function has been called since the start
     /// of the program.
+    ///
+    /// If the app is headless, this is the number of frames rendered to the headless target.
     pub fn elapsed_frames(&self) -> u64 {
+        if let Some(target) = self.headless() {
+            return target.elapsed_frames();
+        }
         self.main_window().frame_count
     }
 
@@ -911,10 +1267,54 @@ impl App {
 
     /// Quits the currently running application.
     pub fn quit(&self) {
+        if let Some(target) = self.headless() {
+            target.quit_requested.set(true);
+        }
         self.windows.borrow_mut().clear();
     }
 }
 
+impl App {
+    // Begin recording and replaying as requested by the `app::Builder`.
+    fn init_recording(&self, record: Option<PathBuf>, replay: Option<PathBuf>) {
+        if let Some(path) = replay {
+            let recording = Recording::load(&path).expect("failed to load recording to replay");
+            *self.player.borrow_mut() = Some(recording::Player::new(recording));
+        }
+        if let Some(path) = record {
+            *self.recorder.borrow_mut() = Some(recording::Recorder::new(path));
+        }
+    }
+
+    // Record the given event if the app is recording.
+    //
+    // Nothing is recorded while replaying, as the replayed events are already recorded.
+    fn record(&self, event: RecordedEvent) {
+        if self.is_replaying() {
+            return;
+        }
+        if let Some(recorder) = self.recorder.borrow_mut().as_mut() {
+            recorder.record(event);
+        }
+    }
+
+    // Write the recording to its file if the app is recording.
+    fn save_recording(&self) {
+        if let Some(recorder) = self.recorder.borrow().as_ref() {
+            if let Err(err) = recorder.save() {
+                // TODO: Replace eprintlns with proper logging.
+                eprintln!("failed to save recording: {}", err);
+            }
+        }
+    }
+
+    // The index of the window with the given ID in order of creation.
+    fn window_index(&self, id: window::Id) -> Option<usize> {
+        let order = self.window_creation_order.borrow();
+        order.iter().position(|&other| other == id)
+    }
+}
+
 impl Proxy {
     /// Wake up the application!
     ///
@@ -927,9 +1327,15 @@ impl Proxy {
     /// immediately set the flag to false afterwards. This makes it safe to call the `wakeup`
     /// method as frequently as necessary across methods without causing any underlying OS methods
     /// to be called more than necessary.
+    ///
+    /// This method does nothing for headless apps as there is no event loop to wake.
     pub fn wakeup(&self) -> Result<(), winit::event_loop::EventLoopClosed<()>> {
+        let event_loop_proxy = match self.event_loop_proxy {
+            None => return Ok(()),
+            Some(ref proxy) => proxy,
+        };
         if !self.wakeup_queued.load(atomic::Ordering::SeqCst) {
-            self.event_loop_proxy.send_event(())?;
+            event_loop_proxy.send_event(())?;
             self.wakeup_queued.store(true, atomic::Ordering::SeqCst);
         }
         Ok(())
@@ -942,18 +1348,37 @@ impl draw::Draw {
     /// The **App** stores a unique render.
     pub fn to_frame(&self, app: &App, frame: &Frame) -> Result<(), draw::renderer::DrawError> {
         let window_id = frame.window_id();
-        let window = app
-            .window(window_id)
-            .expect("no window to draw to for `Draw`'s window_id");
+
+        // The frame is either for a window or for the headless target.
+        let (device_queue_pair, frame_dims, scale_factor, msaa_samples, canvas) =
+            match app.window(window_id) {
+                Some(window) => (
+                    window.device_queue_pair().clone(),
+                    window.tracked_state.physical_size.into(),
+                    window.tracked_state.scale_factor as f32,
+                    window.msaa_samples(),
+                    window.canvas(),
+                ),
+                None => {
+                    let target = app
+                        .headless()
+                        .filter(|target| target.id() == window_id)
+                        .expect("no window to draw to for `Draw`'s window_id");
+                    (
+                        target.device_queue_pair().clone(),
+                        target.size_pixels(),
+                        target.scale_factor(),
+                        target.msaa_samples(),
+                        None,
+                    )
+                }
+            };
+        let device = device_queue_pair.device();
 
         // Retrieve a renderer for this window.
         let renderers = app.draw_state.renderers.borrow_mut();
         let renderer = RefMut::map(renderers, |renderers| {
             renderers.entry(window_id).or_insert_with(|| {
-                let device = window.device();
-                let frame_dims: [u32; 2] = window.tracked_state.physical_size.into();
-                let scale_factor = window.tracked_state.scale_factor as f32;
-                let msaa_samples = window.msaa_samples();
                 let target_format = crate::frame::Frame::TEXTURE_FORMAT;
                 let renderer = draw::RendererBuilder::new().build(
                     device,
@@ -966,9 +1391,9 @@ impl draw::Draw {
             })
         });
 
-        let scale_factor = window.tracked_state.scale_factor as _;
         let mut renderer = renderer.borrow_mut();
-        renderer.render_to_frame(window.device(), self, scale_factor, frame);
+        renderer.set_canvas(canvas);
+        renderer.render_to_frame(device, self, scale_factor, frame);
         Ok(())
     }
 }
@@ -978,6 +1403,9 @@ impl<'a> wgpu::WithDeviceQueuePair for &'a crate::app::App {
     where
         F: FnOnce(&wgpu::Device, &wgpu::Queue) -> O,
     {
+        if let Some(target) = self.headless() {
+            return target.with_device_queue_pair(f);
+        }
         self.main_window().with_device_queue_pair(f)
     }
 }
@@ -1060,6 +1488,8 @@ fn run_loop<M, E>(
     update_fn: Option<UpdateFn<M>>,
     default_view: Option<View<M>>,
     exit_fn: Option<ExitFn<M>>,
+    gamepad_fn: Option<GamepadFn<M>>,
+    snapshot: Option<Snapshot<M>>,
 ) where
     M: 'static,
     E: LoopEvent,
@@ -1078,12 +1508,7 @@ fn run_loop<M, E>(
     };
 
     // Keep track of state related to the loop mode itself.
-    let mut loop_state = LoopState {
-        updates_since_event: 0,
-        loop_start,
-        last_update: loop_start,
-        total_updates: 0,
-    };
+    let mut loop_state = LoopState::new(loop_start);
 
     // Run the event loop.
     event_loop.run(move |mut event, event_loop_window_target, control_flow| {
@@ -1098,24 +1523,124 @@ fn run_loop<M, E>(
             // Check to see if we need to emit an update and request a redraw.
             winit::event::Event::MainEventsCleared => {
                 if let Some(model) = model.as_mut() {
+                    // Gamepad events and long presses count as events for the `Wait` loop mode.
+                    if poll_gamepads(&mut app, model, event_fn, gamepad_fn) {
+                        loop_state.updates_since_event = 0;
+                    }
+                    if poll_gestures(&app, model) {
+                        loop_state.updates_since_event = 0;
+                    }
                     let loop_mode = app.loop_mode();
                     let now = Instant::now();
+                    // Replay on the recorded clock, or on a virtual clock that advances once the
+                    // previous frame is captured in the `Offline` loop mode.
+                    let replayed = match loop_mode {
+                        LoopMode::Offline { .. } if !loop_state.offline_awaiting.is_empty() => None,
+                        LoopMode::Offline { .. } => {
+                            next_replayed_update(&mut app, model, event_fn, gamepad_fn, None)
+                        }
+                        _ => next_replayed_update(&mut app, model, event_fn, gamepad_fn, Some(now)),
+                    };
+                    let replaying = app.is_replaying();
                     let mut do_update = |loop_state: &mut LoopState| {
-                        apply_update(&mut app, model, event_fn, update_fn, loop_state, now);
+                        let update = wall_clock_update(loop_state, now);
+                        apply_update(&mut app, model, event_fn, update_fn, loop_state, update, now);
+                        loop_state.last_update = now;
                     };
-                    match loop_mode {
-                        LoopMode::NTimes { number_of_updates }
-                            if loop_state.total_updates >= number_of_updates as u64 => {}
-                        // Sometimes winit interrupts ControlFlow::Wait for no good reason, so we
-                        // make sure that there were some events in order to do an update when
-                        // LoopMode::Wait is used.
-                        LoopMode::Wait if loop_state.updates_since_event > 0 => {}
-                        // TODO: Consider allowing for a custom number of updates like so:
-                        // LoopMode::Wait { updates_before_waiting } =>
-                        //     if loop_state.updates_since_event > updates_before_waiting as u64 => {}
-                        _ => {
-                            do_update(&mut loop_state);
-                        },
+                    if let Some(update) = replayed {
+                        apply_update(
+                            &mut app,
+                            model,
+                            event_fn,
+                            update_fn,
+                            &mut loop_state,
+                            update,
+                            now,
+                        );
+                        if let LoopMode::Offline {
+                            number_of_frames, ..
+                        } = loop_mode
+                        {
+                            if loop_state.offline_frames < number_of_frames as u64 {
+                                begin_offline_frame(&app, &mut loop_state);
+                            }
+                        }
+                        loop_state.last_update = now;
+                    } else if replaying {
+                        // Retry windows that skipped presenting the latest frame.
+                        let windows = app.windows.borrow();
+                        for id in &loop_state.offline_awaiting {
+                            if let Some(window) = windows.get(id) {
+                                window.window.request_redraw();
+                            }
+                        }
+                    } else {
+                        match loop_mode {
+                            LoopMode::NTimes { number_of_updates }
+                                if loop_state.total_updates >= number_of_updates as u64 => {}
+                            // Sometimes winit interrupts ControlFlow::Wait for no good reason, so we
+                            // make sure that there were some events in order to do an update when
+                            // LoopMode::Wait is used.
+                            LoopMode::Wait if loop_state.updates_since_event > 0 => {}
+                            LoopMode::Rate {
+                                update_interval,
+                                max_catch_up,
+                            } => {
+                                let updates = fixed_updates(
+                                    &mut loop_state,
+                                    now,
+                                    update_interval,
+                                    max_catch_up,
+                                );
+                                for update in updates {
+                                    apply_update(
+                                        &mut app,
+                                        model,
+                                        event_fn,
+                                        update_fn,
+                                        &mut loop_state,
+                                        update,
+                                        now,
+                                    );
+                                }
+                                loop_state.last_update = now;
+                            }
+                            LoopMode::Offline {
+                                number_of_frames,
+                                frame_interval,
+                                ..
+                            } => {
+                                let done = loop_state.offline_frames >= number_of_frames as u64;
+                                if !done && loop_state.offline_awaiting.is_empty() {
+                                    let update = offline_update(&loop_state, frame_interval);
+                                    apply_update(
+                                        &mut app,
+                                        model,
+                                        event_fn,
+                                        update_fn,
+                                        &mut loop_state,
+                                        update,
+                                        now,
+                                    );
+                                    begin_offline_frame(&app, &mut loop_state);
+                                    loop_state.last_update = now;
+                                } else {
+                                    // Retry windows that skipped presenting the latest frame.
+                                    let windows = app.windows.borrow();
+                                    for id in &loop_state.offline_awaiting {
+                                        if let Some(window) = windows.get(id) {
+                                            window.window.request_redraw();
+                                        }
+                                    }
+                                }
+                            }
+                            // TODO: Consider allowing for a custom number of updates like so:
+                            // LoopMode::Wait { updates_before_waiting } =>
+                            //     if loop_state.updates_since_event > updates_before_waiting as u64 => {}
+                            _ => {
+                                do_update(&mut loop_state);
+                            },
+                        }
                     }
                 }
             }
@@ -1180,6 +1705,15 @@ fn run_loop<M, E>(
                             .expect("failed to find window for redraw request");
                         let frame_data = &window.frame_data;
 
+                        // Capture the frame if it is the first for the latest offline update.
+                        if let LoopMode::Offline { ref directory, .. } = app.loop_mode() {
+                            if loop_state.offline_awaiting.contains(&window_id) {
+                                let nth = loop_state.offline_frames - 1;
+                                let path = offline_frame_path(directory, nth, &windows, window_id);
+                                window.capture_frame(path);
+                            }
+                        }
+
                         // Construct and emit a frame via `view` for receiving the user's graphics commands.
                         let sf = window.tracked_state.scale_factor;
                         let (w, h) = window
@@ -1262,6 +1796,7 @@ fn run_loop<M, E>(
                         // Assume invalidated window was cleared above before `view()`
                         window.is_invalidated = false;
                         window.frame_count += 1;
+                        loop_state.offline_awaiting.remove(&window_id);
                     }
                 }
             }
@@ -1328,30 +1863,66 @@ fn run_loop<M, E>(
             }
         }
 
-        // Process the event with the user's functions and see if we need to exit.
+        // Process the event with the user's functions and see if we need to exit. Live input is
+        // ignored while replaying a recording.
         if let Some(model) = model.as_mut() {
-            exit |= process_and_emit_winit_event::<M, E>(&mut app, model, event_fn, &event);
+            if !(app.is_replaying() && is_input_winit_event(&event)) {
+                exit |= process_and_emit_winit_event::<M, E>(&mut app, model, event_fn, &event);
+            }
         }
 
         // Set the control flow based on the loop mode.
         let loop_mode = app.loop_mode();
         *control_flow = match loop_mode {
+            // Replay offline frames as quickly as they are captured.
+            LoopMode::Offline { .. } if app.is_replaying() => ControlFlow::Poll,
+            // Wake in time for the next recorded event.
+            _ if app.is_replaying() => match next_replay_due(&app) {
+                Some(due) => ControlFlow::WaitUntil(due),
+                None => ControlFlow::Poll,
+            },
+            // Gamepads and held touches must be polled, so wake periodically to check them.
+            LoopMode::Wait
+                if app.gamepad_backend.borrow().is_active() || !app.touches.is_empty() =>
+            {
+                ControlFlow::WaitUntil(Instant::now() + INPUT_POLL_INTERVAL)
+            }
             LoopMode::Wait => ControlFlow::Wait,
+            // Wake in time for the next fixed update.
+            LoopMode::Rate {
+                update_interval, ..
+            } => {
+                let remaining = update_interval
+                    .checked_sub(loop_state.accumulator)
+                    .unwrap_or_default();
+                ControlFlow::WaitUntil(loop_state.last_tick + remaining)
+            }
             LoopMode::NTimes { number_of_updates }
                 if loop_state.total_updates >= number_of_updates as u64 =>
             {
                 ControlFlow::Wait
             }
+            LoopMode::Offline {
+                number_of_frames, ..
+            } if loop_state.offline_frames >= number_of_frames as u64
+                && loop_state.offline_awaiting.is_empty() =>
+            {
+                ControlFlow::Wait
+            }
             _ => ControlFlow::Poll,
         };
 
         // If we need to exit, call the user's function and update control flow.
         if exit {
             if let Some(model) = model.take() {
+                if let Some(ref snapshot) = snapshot {
+                    snapshot.save_or_warn(&model);
+                }
                 if let Some(exit_fn) = exit_fn {
                     exit_fn(&app, model);
                 }
             }
+            app.save_recording();
 
             *control_flow = ControlFlow::Exit;
             return;
@@ -1367,29 +1938,296 @@ fn run_loop<M, E>(
     }
 }
 
+// The application loop for headless apps.
+//
+// Without a display server there are no window, device or user events to respond to. Instead, each
+// pass of the loop emits the updates called for by the loop mode and then renders a single frame
+// to the headless target. The loop exits once the loop mode has no more frames to produce or once
+// `App::quit` is called.
+fn run_headless_loop<M, E>(
+    mut app: App,
+    mut model: M,
+    event_fn: Option<EventFn<M, E>>,
+    update_fn: Option<UpdateFn<M>>,
+    default_view: Option<View<M>>,
+    exit_fn: Option<ExitFn<M>>,
+    gamepad_fn: Option<GamepadFn<M>>,
+    snapshot: Option<Snapshot<M>>,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    let mut loop_state = LoopState::new(Instant::now());
+
+    loop {
+        let now = Instant::now();
+        let mut capture_path = None;
+        poll_gamepads(&mut app, &mut model, event_fn, gamepad_fn);
+        // Replay on the recorded clock, or as quickly as frames are rendered in the `Offline` loop
+        // mode.
+        let replay_now = match app.loop_mode() {
+            LoopMode::Offline { .. } => None,
+            _ => Some(now),
+        };
+        let replayed = next_replayed_update(&mut app, &mut model, event_fn, gamepad_fn, replay_now);
+        let updates = match app.loop_mode() {
+            LoopMode::Offline {
+                directory,
+                number_of_frames,
+                ..
+            } if replayed.is_some() => {
+                if loop_state.offline_frames < number_of_frames as u64 {
+                    capture_path = Some(offline_headless_frame_path(
+                        &directory,
+                        loop_state.offline_frames,
+                    ));
+                    loop_state.offline_frames += 1;
+                }
+                replayed.into_iter().collect()
+            }
+            _ if replayed.is_some() => replayed.into_iter().collect(),
+            _ if app.is_replaying() => {
+                // Sleep until the next recorded event is due.
+                if let Some(due) = next_replay_due(&app) {
+                    std::thread::sleep(due.saturating_duration_since(now));
+                }
+                continue;
+            }
+            LoopMode::RefreshSync => vec![wall_clock_update(&loop_state, now)],
+            LoopMode::Wait if loop_state.total_updates == 0 => {
+                vec![wall_clock_update(&loop_state, now)]
+            }
+            LoopMode::NTimes { number_of_updates }
+                if loop_state.total_updates < number_of_updates as u64 =>
+            {
+                vec![wall_clock_update(&loop_state, now)]
+            }
+            LoopMode::Rate {
+                update_interval,
+                max_catch_up,
+            } => {
+                let updates = fixed_updates(&mut loop_state, now, update_interval, max_catch_up);
+                if updates.is_empty() {
+                    // Sleep until the next fixed update is due.
+                    let remaining = update_interval
+                        .checked_sub(loop_state.accumulator)
+                        .unwrap_or_else(|| Duration::from_secs(0));
+                    std::thread::sleep(remaining);
+                    continue;
+                }
+                updates
+            }
+            LoopMode::Offline {
+                directory,
+                number_of_frames,
+                frame_interval,
+            } if loop_state.offline_frames < number_of_frames as u64 => {
+                let update = offline_update(&loop_state, frame_interval);
+                capture_path = Some(offline_headless_frame_path(
+                    &directory,
+                    loop_state.offline_frames,
+                ));
+                loop_state.offline_frames += 1;
+                vec![update]
+            }
+            // There are no events to wait on, so there is nothing left to do.
+            LoopMode::Wait | LoopMode::NTimes { .. } | LoopMode::Offline { .. } => break,
+        };
+
+        for update in updates {
+            apply_update(
+                &mut app,
+                &mut model,
+                event_fn,
+                update_fn,
+                &mut loop_state,
+                update,
+                now,
+            );
+        }
+        loop_state.last_update = now;
+
+        let target = app.headless().expect("headless app has no target");
+        if target.quit_requested.get() {
+            break;
+        }
+        if let Some(path) = capture_path {
+            target.capture_frame(path);
+        }
+        headless_view(&app, &model, target, default_view.as_ref());
+        target.device().poll(wgpu::Maintain::Poll);
+        if target.quit_requested.get() {
+            break;
+        }
+    }
+
+    if let Some(ref snapshot) = snapshot {
+        snapshot.save_or_warn(&model);
+    }
+    if let Some(exit_fn) = exit_fn {
+        exit_fn(&app, model);
+    }
+    app.save_recording();
+}
+
+// Deliver all pending gamepad events to the user's functions.
+//
+// Live gamepad input is ignored while replaying a recording. Returns whether or not any events
+// were delivered.
+fn poll_gamepads<M, E>(
+    app: &mut App,
+    model: &mut M,
+    event_fn: Option<EventFn<M, E>>,
+    gamepad_fn: Option<GamepadFn<M>>,
+) -> bool
+where
+    M: 'static,
+    E: LoopEvent,
+{
+    let mut any = false;
+    loop {
+        let event = match app.gamepad_backend.borrow_mut().next_event() {
+            None => break,
+            Some(event) => event,
+        };
+        // Live input is ignored while replaying, as the recorded gamepad events are replayed.
+        if app.is_replaying() {
+            continue;
+        }
+        any = true;
+        app.record(RecordedEvent::Gamepad(event.clone()));
+        emit_gamepad_event(app, model, event_fn, gamepad_fn, event);
+    }
+    any
+}
+
+// Apply the gamepad event to the app's gamepad state and deliver it to the user's functions.
+fn emit_gamepad_event<M, E>(
+    app: &mut App,
+    model: &mut M,
+    event_fn: Option<EventFn<M, E>>,
+    gamepad_fn: Option<GamepadFn<M>>,
+    event: event::GamepadEvent,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    app.gamepads.apply_event(&event);
+
+    if let Some(event_fn) = event_fn {
+        if let Some(loop_event) = E::from_gamepad_event(event.clone()) {
+            event_fn(app, model, loop_event);
+        }
+    }
+
+    if let Some(gamepad_fn) = gamepad_fn {
+        gamepad_fn(app, model, event.clone());
+    }
+
+    // Deliver the event to the window in focus.
+    let window_gamepad_fn = app.focused_window.borrow().and_then(|id| {
+        let windows = app.windows.borrow();
+        windows
+            .get(&id)
+            .and_then(|w| w.user_functions.gamepad.clone())
+    });
+    if let Some(window_gamepad_fn) = window_gamepad_fn {
+        let window_gamepad_fn = window_gamepad_fn
+            .to_fn_ptr::<M>()
+            .expect("unexpected model argument given to window gamepad function");
+        (*window_gamepad_fn)(app, model, event);
+    }
+}
+
+// Deliver any long presses recognised since the last poll to the window gesture functions.
+//
+// Returns whether or not any gestures were delivered.
+fn poll_gestures<M>(app: &App, model: &mut M) -> bool
+where
+    M: 'static,
+{
+    let now = Instant::now();
+    let gestures: Vec<_> = app
+        .gesture_recognizers
+        .borrow_mut()
+        .iter_mut()
+        .flat_map(|(&id, recognizer)| recognizer.update(now).into_iter().map(move |g| (id, g)))
+        .collect();
+    let any = !gestures.is_empty();
+    for (window_id, gesture) in gestures {
+        let gesture_fn = {
+            let windows = app.windows.borrow();
+            windows
+                .get(&window_id)
+                .and_then(|w| w.user_functions.gesture.clone())
+        };
+        if let Some(gesture_fn) = gesture_fn {
+            let gesture_fn = gesture_fn
+                .to_fn_ptr::<M>()
+                .expect("unexpected model argument given to window gesture function");
+            (*gesture_fn)(app, model, gesture);
+        }
+    }
+    any
+}
+
+// Render a single frame to the headless target via the user's default view function.
+fn headless_view<M>(app: &App, model: &M, target: &headless::Target, view: Option<&View<M>>) {
+    let frame_data = &target.frame_data;
+    let raw_frame = RawFrame::new_empty(
+        target.device_queue_pair().clone(),
+        target.id(),
+        target.elapsed_frames(),
+        target.texture_view(),
+        target.format(),
+        target.rect(),
+    );
+
+    // Clear the frame's image before the first `view`.
+    if target.is_invalidated.get() {
+        raw_frame.clear(&frame_data.render.texture_view(), target.clear_color);
+    }
+
+    match view {
+        Some(View::Sketch(view)) => {
+            let frame = Frame::new_empty(raw_frame, &frame_data.render, &frame_data.capture);
+            view(app, frame);
+        }
+        Some(View::WithModel(view)) => {
+            let frame = Frame::new_empty(raw_frame, &frame_data.render, &frame_data.capture);
+            view(app, model, frame);
+        }
+        None => raw_frame.submit(),
+    }
+
+    target.is_invalidated.set(false);
+    target.frame_count.set(target.frame_count.get() + 1);
+}
+
 // Apply an update to the model via the user's function and update the app and loop state
 // accordingly.
+//
+// `now` is the moment at which the current pass of the loop began.
 fn apply_update<M, E>(
     app: &mut App,
     model: &mut M,
     event_fn: Option<EventFn<M, E>>,
     update_fn: Option<UpdateFn<M>>,
     loop_state: &mut LoopState,
+    update: event::Update,
     now: Instant,
 ) where
     M: 'static,
     E: LoopEvent,
 {
+    app.record(RecordedEvent::Update(update));
     // Update the app's durations.
-    let since_last = now.duration_since(loop_state.last_update);
-    let since_start = now.duration_since(loop_state.loop_start);
-    app.duration.since_prev_update = since_last;
-    app.duration.since_start = since_start;
-    app.time = since_start.secs() as _;
-    let update = crate::event::Update {
-        since_start,
-        since_last,
-    };
+    app.duration.since_prev_update = update.since_last;
+    app.duration.since_start = update.since_start;
+    app.time = update.since_start.secs() as _;
+    // Expose the input received since the previous update.
+    app.keys.begin_update();
+    app.mouse.begin_update(now);
     // User event function.
     if let Some(event_fn) = event_fn {
         let event = E::from(update.clone());
@@ -1399,7 +2237,6 @@ fn apply_update<M, E>(
     if let Some(update_fn) = update_fn {
         update_fn(app, model, update);
     }
-    loop_state.last_update = now;
     loop_state.total_updates += 1;
     loop_state.updates_since_event += 1;
     // Request redraw from windows.
@@ -1409,6 +2246,321 @@ fn apply_update<M, E>(
     }
 }
 
+// Deliver the recorded events that are due by `now` preceding the next recorded update and
+// produce that update if it is due.
+//
+// If `now` is `None`, events are delivered regardless of their timestamps. Returns `None` if the
+// app is not replaying or if no update is due. Replaying ends once the recording is exhausted.
+fn next_replayed_update<M, E>(
+    app: &mut App,
+    model: &mut M,
+    event_fn: Option<EventFn<M, E>>,
+    gamepad_fn: Option<GamepadFn<M>>,
+    now: Option<Instant>,
+) -> Option<event::Update>
+where
+    M: 'static,
+    E: LoopEvent,
+{
+    loop {
+        let next = match app.player.borrow_mut().as_mut() {
+            None => return None,
+            Some(player) if player.is_finished() => None,
+            Some(player) => match player.next(now) {
+                None => return None,
+                next => next,
+            },
+        };
+        match next {
+            None => {
+                app.player.borrow_mut().take();
+                return None;
+            }
+            Some(RecordedEvent::Update(update)) => return Some(update),
+            Some(RecordedEvent::ModifiersChanged(mods)) => {
+                app.keys.mods = mods;
+            }
+            Some(RecordedEvent::Gamepad(event)) => {
+                emit_gamepad_event(app, model, event_fn, gamepad_fn, event);
+            }
+            Some(RecordedEvent::WindowEvent { window, event }) => {
+                let window_id = match app.window_creation_order.borrow().get(window) {
+                    Some(&id) => id,
+                    None => continue,
+                };
+                apply_input_state(app, window_id, &event);
+                if let Some(event_fn) = event_fn {
+                    if let Some(event) = E::from_window_event(window_id, event.clone()) {
+                        event_fn(app, model, event);
+                    }
+                }
+                emit_simple_window_event(app, model, window_id, None, event);
+            }
+        }
+    }
+}
+
+// Update the app's mouse and keyboard state in accordance with a replayed input event.
+fn apply_input_state(app: &mut App, window_id: window::Id, event: &event::WindowEvent) {
+    match *event {
+        event::WindowEvent::MouseMoved(pos) => {
+            app.mouse.move_to(window_id, pos);
+        }
+        event::WindowEvent::MousePressed(button) => {
+            app.mouse.press(window_id, button, Instant::now());
+        }
+        event::WindowEvent::MouseReleased(button) => {
+            app.mouse.release(window_id, button);
+        }
+        event::WindowEvent::MouseWheel(delta, _) => {
+            let scale_factor = app
+                .window(window_id)
+                .map(|w| w.tracked_state.scale_factor)
+                .unwrap_or(1.0);
+            app.mouse.scroll(delta, scale_factor);
+        }
+        event::WindowEvent::Touch(ref touch) => {
+            app.touches.apply_event(window_id, touch);
+        }
+        event::WindowEvent::KeyPressed(key) => {
+            app.keys.press(key);
+        }
+        event::WindowEvent::KeyReleased(key) => {
+            app.keys.release(key);
+        }
+        _ => (),
+    }
+}
+
+// Whether or not the given event is live keyboard, mouse, touch or file input for a window.
+fn is_input_winit_event(event: &winit::event::Event<()>) -> bool {
+    match *event {
+        winit::event::Event::WindowEvent { ref event, .. } => match *event {
+            winit::event::WindowEvent::KeyboardInput { .. }
+            | winit::event::WindowEvent::ModifiersChanged(_)
+            | winit::event::WindowEvent::ReceivedCharacter(_)
+            | winit::event::WindowEvent::CursorMoved { .. }
+            | winit::event::WindowEvent::CursorEntered { .. }
+            | winit::event::WindowEvent::CursorLeft { .. }
+            | winit::event::WindowEvent::MouseWheel { .. }
+            | winit::event::WindowEvent::MouseInput { .. }
+            | winit::event::WindowEvent::Touch(_)
+            | winit::event::WindowEvent::TouchpadPressure { .. }
+            | winit::event::WindowEvent::HoveredFile(_)
+            | winit::event::WindowEvent::DroppedFile(_)
+            | winit::event::WindowEvent::HoveredFileCancelled => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// Await the capture of the frame of each window following the latest update of the `Offline` loop
+// mode.
+fn begin_offline_frame(app: &App, loop_state: &mut LoopState) {
+    loop_state.offline_frames += 1;
+    loop_state.offline_awaiting = app
+        .windows
+        .borrow()
+        .iter()
+        .filter(|(_, window)| window.frame_data.is_some())
+        .map(|(&id, _)| id)
+        .collect();
+}
+
+// The moment at which the next replayed event is due, if the app is replaying.
+fn next_replay_due(app: &App) -> Option<Instant> {
+    app.player
+        .borrow()
+        .as_ref()
+        .and_then(|player| player.next_due())
+}
+
+// Produce an update describing the wall-clock time elapsed since the last update.
+fn wall_clock_update(loop_state: &LoopState, now: Instant) -> event::Update {
+    event::Update {
+        since_last: now.duration_since(loop_state.last_update),
+        since_start: now.duration_since(loop_state.loop_start),
+        leftover: Duration::from_secs(0),
+    }
+}
+
+// Produce the next update of the `Offline` loop mode, advancing by exactly one frame interval.
+fn offline_update(loop_state: &LoopState, frame_interval: Duration) -> event::Update {
+    let nth = loop_state.offline_frames as u32;
+    let since_last = match nth {
+        0 => Duration::from_secs(0),
+        _ => frame_interval,
+    };
+    event::Update {
+        since_last,
+        since_start: frame_interval * nth,
+        leftover: Duration::from_secs(0),
+    }
+}
+
+// The path to which the `nth` frame of a headless app is captured in the `Offline` loop mode.
+fn offline_headless_frame_path(directory: &Path, nth: u64) -> PathBuf {
+    let name = format!("{:06}", nth);
+    directory.join(name).with_extension("png")
+}
+
+// The path to which the `nth` frame of the given window is captured in the `Offline` loop mode.
+fn offline_frame_path(
+    directory: &Path,
+    nth: u64,
+    windows: &HashMap<window::Id, Window>,
+    window_id: window::Id,
+) -> PathBuf {
+    let name = match windows.len() {
+        1 => format!("{:06}", nth),
+        _ => {
+            let mut ids: Vec<_> = windows.keys().cloned().collect();
+            ids.sort();
+            let ix = ids.iter().position(|&id| id == window_id).unwrap_or(0);
+            format!("{:06}-{}", nth, ix)
+        }
+    };
+    directory.join(name).with_extension("png")
+}
+
+// Accumulate the time elapsed since the last tick and produce the fixed updates that it allows
+// for in the `Rate` loop mode.
+fn fixed_updates(
+    loop_state: &mut LoopState,
+    now: Instant,
+    update_interval: Duration,
+    max_catch_up: u32,
+) -> Vec<event::Update> {
+    loop_state.accumulator += now.duration_since(loop_state.last_tick);
+    loop_state.last_tick = now;
+    let max_catch_up = max_catch_up.max(1);
+    let interval_nanos = update_interval.as_nanos().max(1);
+    let accumulated_nanos = loop_state.accumulator.as_nanos();
+    let steps = (accumulated_nanos / interval_nanos).min(max_catch_up as u128) as u32;
+    // Discard any whole intervals beyond the catch-up limit.
+    let remaining_nanos = match steps == max_catch_up {
+        true => accumulated_nanos % interval_nanos,
+        false => accumulated_nanos - steps as u128 * interval_nanos,
+    };
+    let leftover = Duration::from_nanos(remaining_nanos as u64);
+    loop_state.accumulator = leftover;
+    (0..steps)
+        .map(|_| {
+            loop_state.fixed_since_start += update_interval;
+            event::Update {
+                since_last: update_interval,
+                since_start: loop_state.fixed_since_start,
+                leftover,
+            }
+        })
+        .collect()
+}
+
+// Deliver a simplified window event to the window-specific user functions.
+//
+// `removed_window` is the window's state if it was removed while processing the event.
+fn emit_simple_window_event<M>(
+    app: &App,
+    model: &mut M,
+    window_id: window::Id,
+    removed_window: Option<&Window>,
+    simple: event::WindowEvent,
+) where
+    M: 'static,
+{
+    // Nannou window events.
+    if let Some(window_event_fn) = {
+        let windows = app.windows.borrow();
+        windows
+            .get(&window_id)
+            .and_then(|w| w.user_functions.event.clone())
+            .or_else(|| {
+                removed_window
+                    .as_ref()
+                    .and_then(|w| w.user_functions.event.clone())
+            })
+    } {
+        let window_event_fn = window_event_fn
+            .to_fn_ptr::<M>()
+            .expect("unexpected model argument given to window event function");
+        (*window_event_fn)(&app, model, simple.clone());
+    }
+
+    // A macro to simplify calling event-specific user functions.
+    macro_rules! call_user_function {
+        ($fn_name:ident $(,$arg:expr)*) => {{
+            if let Some(event_fn) = {
+                let windows = app.windows.borrow();
+                windows
+                    .get(&window_id)
+                    .and_then(|w| w.user_functions.$fn_name.clone())
+                    .or_else(|| {
+                        removed_window
+                            .as_ref()
+                            .and_then(|w| w.user_functions.$fn_name.clone())
+                    })
+            } {
+                let event_fn = event_fn
+                    .to_fn_ptr::<M>()
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "unexpected model argument given to {} function",
+                            stringify!($fn_name),
+                        );
+                    });
+                (*event_fn)(&app, model, $($arg),*);
+            }
+        }};
+    }
+
+    // Check for more specific event functions.
+    match simple {
+        event::WindowEvent::KeyPressed(key) => call_user_function!(key_pressed, key),
+        event::WindowEvent::KeyReleased(key) => call_user_function!(key_released, key),
+        event::WindowEvent::ReceivedCharacter(char) => {
+            call_user_function!(received_character, char)
+        }
+        event::WindowEvent::MouseMoved(pos) => call_user_function!(mouse_moved, pos),
+        event::WindowEvent::MousePressed(button) => {
+            call_user_function!(mouse_pressed, button)
+        }
+        event::WindowEvent::MouseReleased(button) => {
+            call_user_function!(mouse_released, button)
+        }
+        event::WindowEvent::MouseEntered => call_user_function!(mouse_entered),
+        event::WindowEvent::MouseExited => call_user_function!(mouse_exited),
+        event::WindowEvent::MouseWheel(amount, phase) => {
+            call_user_function!(mouse_wheel, amount, phase)
+        }
+        event::WindowEvent::Moved(pos) => call_user_function!(moved, pos),
+        event::WindowEvent::Resized(size) => call_user_function!(resized, size),
+        event::WindowEvent::Touch(touch) => {
+            call_user_function!(touch, touch);
+            let gestures = app
+                .gesture_recognizers
+                .borrow_mut()
+                .entry(window_id)
+                .or_default()
+                .touch(&touch, Instant::now());
+            for gesture in gestures {
+                call_user_function!(gesture, gesture);
+            }
+        }
+        event::WindowEvent::TouchPressure(pressure) => {
+            call_user_function!(touchpad_pressure, pressure)
+        }
+        event::WindowEvent::HoveredFile(path) => call_user_function!(hovered_file, path),
+        event::WindowEvent::HoveredFileCancelled => {
+            call_user_function!(hovered_file_cancelled)
+        }
+        event::WindowEvent::DroppedFile(path) => call_user_function!(dropped_file, path),
+        event::WindowEvent::Focused => call_user_function!(focused),
+        event::WindowEvent::Unfocused => call_user_function!(unfocused),
+        event::WindowEvent::Closed => call_user_function!(closed),
+    }
+}
+
 // Whether or not the given event should toggle fullscreen.
 fn should_toggle_fullscreen(
     winit_event: &winit::event::WindowEvent,
@@ -1488,6 +2640,7 @@ where
         // Returns the `Window` that was removed.
         fn remove_related_window_state(app: &App, window_id: &window::Id) -> Option<Window> {
             app.draw_state.renderers.borrow_mut().remove(window_id);
+            app.gesture_recognizers.borrow_mut().remove(window_id);
             app.windows.borrow_mut().remove(window_id)
         }
 
@@ -1524,6 +2677,14 @@ where
             let tx = |x: geom::scalar::Default| x - win_w as geom::scalar::Default / 2.0;
             let ty = |y: geom::scalar::Default| -(y - win_h as geom::scalar::Default / 2.0);
 
+            // Map positions into the window's canvas coordinates, if it has a canvas.
+            let canvas = app.window(window_id).and_then(|win| win.canvas());
+            let win_size = Vec2::new(win_w, win_h);
+            let to_canvas = |p: Point2| match canvas {
+                Some(canvas) => canvas.to_canvas(win_size, p),
+                None => p,
+            };
+
             // If the window ID has changed, ensure the dimensions are up to date.
             if *app.focused_window.borrow() != Some(window_id) {
                 if app.window(window_id).is_some() {
@@ -1537,32 +2698,47 @@ where
                     let (x, y) = position.to_logical::<f32>(scale_factor).into();
                     let x = tx(x);
                     let y = ty(y);
-                    app.mouse.x = x;
-                    app.mouse.y = y;
-                    app.mouse.window = Some(window_id);
+                    let pos = to_canvas([x, y].into());
+                    app.mouse.move_to(window_id, pos);
                 }
 
-                winit::event::WindowEvent::MouseInput { state, button, .. } => {
-                    match state {
-                        event::ElementState::Pressed => {
-                            let p = app.mouse.position();
-                            app.mouse.buttons.press(button, p);
-                        }
-                        event::ElementState::Released => {
-                            app.mouse.buttons.release(button);
-                        }
+                winit::event::WindowEvent::MouseInput { state, button, .. } => match state {
+                    event::ElementState::Pressed => {
+                        app.mouse.press(window_id, button, Instant::now());
+                    }
+                    event::ElementState::Released => {
+                        app.mouse.release(window_id, button);
                     }
-                    app.mouse.window = Some(window_id);
+                },
+
+                winit::event::WindowEvent::Touch(winit::event::Touch {
+                    phase,
+                    location,
+                    id,
+                    ..
+                }) => {
+                    let (x, y) = location.to_logical::<f32>(scale_factor).into();
+                    let position = to_canvas([tx(x), ty(y)].into());
+                    let touch = event::TouchEvent {
+                        id,
+                        phase,
+                        position,
+                    };
+                    app.touches.apply_event(window_id, &touch);
+                }
+
+                winit::event::WindowEvent::MouseWheel { delta, .. } => {
+                    app.mouse.scroll(delta, scale_factor);
                 }
 
                 winit::event::WindowEvent::KeyboardInput { input, .. } => {
                     if let Some(key) = input.virtual_keycode {
                         match input.state {
                             event::ElementState::Pressed => {
-                                app.keys.down.keys.insert(key);
+                                app.keys.press(key);
                             }
                             event::ElementState::Released => {
-                                app.keys.down.keys.remove(&key);
+                                app.keys.release(key);
                             }
                         }
                     }
@@ -1577,6 +2753,7 @@ where
     if let winit::event::Event::WindowEvent { event, .. } = winit_event {
         if let winit::event::WindowEvent::ModifiersChanged(new_mods) = event {
             app.keys.mods = new_mods.clone();
+            app.record(RecordedEvent::ModifiersChanged(*new_mods));
         }
     }
 
@@ -1625,90 +2802,22 @@ where
                 .unwrap_or((0.0, 0.0, 1.0))
         };
 
-        // If the event can be represented by a simplified nannou event, check for relevant user
-        // functions to be called.
+        // If the event can be represented by a simplified nannou event, record it if necessary and
+        // check for relevant user functions to be called.
         if let Some(simple) =
             event::WindowEvent::from_winit_window_event(event, win_w, win_h, scale_factor)
         {
-            // Nannou window events.
-            if let Some(window_event_fn) = {
-                let windows = app.windows.borrow();
-                windows
-                    .get(&window_id)
-                    .and_then(|w| w.user_functions.event.clone())
-                    .or_else(|| {
-                        removed_window
-                            .as_ref()
-                            .and_then(|w| w.user_functions.event.clone())
-                    })
-            } {
-                let window_event_fn = window_event_fn
-                    .to_fn_ptr::<M>()
-                    .expect("unexpected model argument given to window event function");
-                (*window_event_fn)(&app, model, simple.clone());
-            }
-
-            // A macro to simplify calling event-specific user functions.
-            macro_rules! call_user_function {
-                ($fn_name:ident $(,$arg:expr)*) => {{
-                    if let Some(event_fn) = {
-                        let windows = app.windows.borrow();
-                        windows
-                            .get(&window_id)
-                            .and_then(|w| w.user_functions.$fn_name.clone())
-                            .or_else(|| {
-                                removed_window
-                                    .as_ref()
-                                    .and_then(|w| w.user_functions.$fn_name.clone())
-                            })
-                    } {
-                        let event_fn = event_fn
-                            .to_fn_ptr::<M>()
-                            .unwrap_or_else(|| {
-                                panic!(
-                                    "unexpected model argument given to {} function",
-                                    stringify!($fn_name),
-                                );
-                            });
-                        (*event_fn)(&app, model, $($arg),*);
-                    }
-                }};
-            }
-
-            // Check for more specific event functions.
-            match simple {
-                event::WindowEvent::KeyPressed(key) => call_user_function!(key_pressed, key),
-                event::WindowEvent::KeyReleased(key) => call_user_function!(key_released, key),
-                event::WindowEvent::ReceivedCharacter(char) => {
-                    call_user_function!(received_character, char)
-                }
-                event::WindowEvent::MouseMoved(pos) => call_user_function!(mouse_moved, pos),
-                event::WindowEvent::MousePressed(button) => {
-                    call_user_function!(mouse_pressed, button)
-                }
-                event::WindowEvent::MouseReleased(button) => {
-                    call_user_function!(mouse_released, button)
-                }
-                event::WindowEvent::MouseEntered => call_user_function!(mouse_entered),
-                event::WindowEvent::MouseExited => call_user_function!(mouse_exited),
-                event::WindowEvent::MouseWheel(amount, phase) => {
-                    call_user_function!(mouse_wheel, amount, phase)
-                }
-                event::WindowEvent::Moved(pos) => call_user_function!(moved, pos),
-                event::WindowEvent::Resized(size) => call_user_function!(resized, size),
-                event::WindowEvent::Touch(touch) => call_user_function!(touch, touch),
-                event::WindowEvent::TouchPressure(pressure) => {
-                    call_user_function!(touchpad_pressure, pressure)
-                }
-                event::WindowEvent::HoveredFile(path) => call_user_function!(hovered_file, path),
-                event::WindowEvent::HoveredFileCancelled => {
-                    call_user_function!(hovered_file_cancelled)
+            let simple = match app.window(window_id) {
+                Some(window) => simple.to_canvas(&window),
+                None => simple,
+            };
+            if simple.is_input() {
+                if let Some(window) = app.window_index(window_id) {
+                    let event = simple.clone();
+                    app.record(RecordedEvent::WindowEvent { window, event });
                 }
-                event::WindowEvent::DroppedFile(path) => call_user_function!(dropped_file, path),
-                event::WindowEvent::Focused => call_user_function!(focused),
-                event::WindowEvent::Unfocused => call_user_function!(unfocused),
-                event::WindowEvent::Closed => call_user_function!(closed),
             }
+            emit_simple_window_event(app, model, window_id, removed_window.as_ref(), simple);
         }
     }
 
@@ -0,0 +1,149 @@
+//! Items related to the **Camera** used to project a **Draw**'s geometry onto the output.
+
+use crate::glam::{Mat4, Vec2, Vec3};
+use crate::math::Mat4LookTo;
+
+/// Describes how a **Draw**'s geometry is viewed and projected onto the output attachment.
+///
+/// By default, a **Draw** uses an orthographic projection where one unit is equal to one logical
+/// pixel and the origin is at the centre of the output. Setting a **Camera** via `draw.camera`
+/// replaces this projection for all drawings made with the resulting **Draw** instance.
+///
+/// The camera is stored within the draw **Context**, so different parts of a single frame may be
+/// drawn with different cameras.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera {
+    /// The projection from view space to clip space.
+    pub projection: Projection,
+    /// The transform from world space to view space.
+    ///
+    /// If `None`, a default view is derived from the projection. See `Camera::view_matrix`.
+    pub view: Option<Mat4>,
+}
+
+/// The projection used by a **Camera**.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projection {
+    /// A perspective projection, where the aspect ratio is determined by the output attachment.
+    Perspective {
+        /// The vertical field of view in radians.
+        fov_y: f32,
+        /// The distance to the near clipping plane.
+        near: f32,
+        /// The distance to the far clipping plane.
+        far: f32,
+    },
+    /// An orthographic projection with explicit bounds in view space.
+    Orthographic {
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    },
+}
+
+impl Camera {
+    /// The default vertical field of view used by `Camera::default_perspective`.
+    pub const DEFAULT_FOV_Y: f32 = std::f32::consts::FRAC_PI_4;
+
+    /// A camera with a perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians.
+    pub fn perspective(fov_y: f32, near: f32, far: f32) -> Self {
+        let projection = Projection::Perspective { fov_y, near, far };
+        Camera {
+            projection,
+            view: None,
+        }
+    }
+
+    /// A camera with a perspective projection using the `DEFAULT_FOV_Y` and near and far planes
+    /// that suit scenes measured in logical pixels.
+    pub fn default_perspective() -> Self {
+        Self::perspective(Self::DEFAULT_FOV_Y, 1.0, 10_000.0)
+    }
+
+    /// A camera with an orthographic projection with the given bounds.
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let projection = Projection::Orthographic {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+        };
+        Camera {
+            projection,
+            view: None,
+        }
+    }
+
+    /// Specify the transform from world space to view space.
+    pub fn view(mut self, view: Mat4) -> Self {
+        self.view = Some(view);
+        self
+    }
+
+    /// Position the camera at `eye`, looking toward `target`, oriented via the given `up` vector.
+    pub fn look_at(self, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        self.look_to(eye, target - eye, up)
+    }
+
+    /// Position the camera at `eye`, looking along `dir`, oriented via the given `up` vector.
+    pub fn look_to(self, eye: Vec3, dir: Vec3, up: Vec3) -> Self {
+        self.view(Mat4::look_to_rh(eye, dir, up))
+    }
+
+    /// The transform from world space to view space for an output of the given logical size.
+    ///
+    /// If no view was specified, perspective cameras are placed on the positive z axis looking
+    /// toward the origin at the distance where the `z = 0` plane maps one unit to one logical
+    /// pixel. Orthographic cameras default to the identity view.
+    pub fn view_matrix(&self, output_size: Vec2) -> Mat4 {
+        if let Some(view) = self.view {
+            return view;
+        }
+        match self.projection {
+            Projection::Perspective { fov_y, .. } => {
+                let distance = output_size.y * 0.5 / (fov_y * 0.5).tan();
+                let eye = Vec3::new(0.0, 0.0, distance);
+                Mat4::look_to_rh(eye, -Vec3::Z, Vec3::Y)
+            }
+            Projection::Orthographic { .. } => Mat4::IDENTITY,
+        }
+    }
+
+    /// The transform from view space to clip space for an output of the given logical size.
+    ///
+    /// Depth values are mapped to the range `0.0..=1.0`.
+    pub fn projection_matrix(&self, output_size: Vec2) -> Mat4 {
+        match self.projection {
+            Projection::Perspective { fov_y, near, far } => {
+                let aspect = output_size.x / output_size.y;
+                Mat4::perspective_rh(fov_y, aspect, near, far)
+            }
+            Projection::Orthographic {
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                far,
+            } => Mat4::orthographic_rh(left, right, bottom, top, near, far),
+        }
+    }
+
+    /// The combined view and projection transform for an output of the given logical size.
+    pub fn view_projection(&self, output_size: Vec2) -> Mat4 {
+        self.projection_matrix(output_size) * self.view_matrix(output_size)
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::default_perspective()
+    }
+}
@@ -0,0 +1,110 @@
+//! Items related to the **Canvas**, a fixed logical coordinate system that is fitted to the output.
+
+use crate::geom::{self, Point2};
+use crate::glam::{Mat4, Vec2, Vec3};
+
+/// A fixed logical drawing area that is fitted to the output regardless of its size.
+///
+/// By default, one unit of a **Draw** is equal to one logical pixel of the output, so resizing a
+/// window changes the area that may be drawn to. When a **Canvas** is used, the drawing area
+/// always spans `size` units centred on the origin and is scaled to the output according to the
+/// **Fit** policy. This allows for a sketch to look identical on displays of any resolution.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Canvas {
+    /// The logical size of the canvas.
+    pub size: Vec2,
+    /// How the canvas is fitted to the output.
+    pub fit: Fit,
+}
+
+/// Describes how a **Canvas** is fitted to an output of a different aspect ratio.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Fit {
+    /// Uniformly scale the canvas to fit entirely within the output.
+    ///
+    /// The remaining area of the output is left blank and drawing to it is clipped.
+    Letterbox,
+    /// Scale each axis independently so that the canvas exactly covers the output.
+    Stretch,
+    /// Uniformly scale the canvas to cover the entire output.
+    ///
+    /// The edges of the canvas outside of the output are cropped.
+    Fill,
+}
+
+impl Canvas {
+    /// A canvas of the given logical size using the default **Fit**.
+    pub fn new(w: f32, h: f32) -> Self {
+        Canvas {
+            size: Vec2::new(w, h),
+            fit: Fit::default(),
+        }
+    }
+
+    /// Specify how the canvas is fitted to the output.
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// The rectangle covered by the canvas in canvas coordinates.
+    pub fn rect(&self) -> geom::Rect {
+        geom::Rect::from_wh(self.size)
+    }
+
+    /// The scale applied along each axis when mapping the canvas onto an output of the given
+    /// logical size.
+    pub fn scale(&self, output_size: Vec2) -> Vec2 {
+        if self.size.x <= 0.0 || self.size.y <= 0.0 {
+            return Vec2::ONE;
+        }
+        let scale = output_size / self.size;
+        match self.fit {
+            Fit::Letterbox => Vec2::splat(scale.x.min(scale.y)),
+            Fit::Stretch => scale,
+            Fit::Fill => Vec2::splat(scale.x.max(scale.y)),
+        }
+    }
+
+    /// The area of an output of the given logical size that is covered by the canvas, in output
+    /// coordinates.
+    ///
+    /// When using `Fit::Fill`, this may extend beyond the bounds of the output.
+    pub fn viewport(&self, output_size: Vec2) -> geom::Rect {
+        geom::Rect::from_wh(self.size * self.scale(output_size))
+    }
+
+    /// Map a point in output coordinates to canvas coordinates.
+    pub fn to_canvas(&self, output_size: Vec2, point: Point2) -> Point2 {
+        point / self.scale(output_size)
+    }
+
+    /// Map a point in canvas coordinates to output coordinates.
+    pub fn to_output(&self, output_size: Vec2, point: Point2) -> Point2 {
+        point * self.scale(output_size)
+    }
+
+    /// Map a rectangle in canvas coordinates to output coordinates.
+    pub fn rect_to_output(&self, output_size: Vec2, rect: geom::Rect) -> geom::Rect {
+        let scale = self.scale(output_size);
+        let bl = rect.bottom_left() * scale;
+        let tr = rect.top_right() * scale;
+        geom::Rect::from_corners(bl, tr)
+    }
+
+    /// The transform applied in clip space to a projection of the canvas in order to fit it to an
+    /// output of the given logical size.
+    pub fn clip_transform(&self, output_size: Vec2) -> Mat4 {
+        if output_size.x <= 0.0 || output_size.y <= 0.0 {
+            return Mat4::IDENTITY;
+        }
+        let scale = self.size * self.scale(output_size) / output_size;
+        Mat4::from_scale(Vec3::new(scale.x, scale.y, 1.0))
+    }
+}
+
+impl Default for Fit {
+    fn default() -> Self {
+        Fit::Letterbox
+    }
+}
@@ -2,7 +2,8 @@ use crate::color::IntoLinSrgba;
 use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::{
-    ColorScalar, SetColor, SetDimensions, SetFill, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Gradient, SetColor, SetDashes, SetDimensions, SetFill, SetGradient,
+    SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Draw};
 use crate::geom::{Point2, Point3};
@@ -564,6 +565,44 @@ where
     }
 }
 
+// SetGradient methods
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetGradient + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// Fill with the given gradient rather than a single color.
+    pub fn gradient(self, gradient: Gradient) -> Self {
+        self.map_ty(|ty| ty.gradient(gradient))
+    }
+
+    /// Fill with a gradient that varies along the line from `start` to `end`.
+    ///
+    /// Each stop is a tuple of an offset along the line in the range `0.0..=1.0` and a color.
+    /// The points are described relative to the position and orientation of the drawing.
+    pub fn gradient_linear<I, C>(self, start: Point2, end: Point2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.gradient_linear(start, end, stops))
+    }
+
+    /// Fill with a gradient that varies with the distance from `center`.
+    ///
+    /// Each stop is a tuple of an offset from the centre to the given `radius` in the range
+    /// `0.0..=1.0` and a color. The centre is described relative to the position and orientation
+    /// of the drawing.
+    pub fn gradient_radial<I, C>(self, center: Point2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.gradient_radial(center, radius, stops))
+    }
+}
+
 // SetStroke methods
 
 impl<'a, T> Drawing<'a, T>
@@ -706,3 +745,24 @@ where
         self.map_ty(|ty| ty.stroke_opts(opts))
     }
 }
+
+// SetDashes methods
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetDashes + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// Split the stroke into dashes following the given pattern.
+    ///
+    /// The pattern alternates between the length of a dash and the length of the gap that
+    /// follows it, e.g. `&[10.0, 5.0]`.
+    pub fn dashes(self, pattern: &[f32]) -> Self {
+        self.map_ty(|ty| ty.dashes(pattern))
+    }
+
+    /// The distance into the dash pattern at which the stroke begins.
+    pub fn dash_offset(self, offset: f32) -> Self {
+        self.map_ty(|ty| ty.dash_offset(offset))
+    }
+}
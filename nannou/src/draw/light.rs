@@ -0,0 +1,105 @@
+//! Items related to the **Light**s used to shade geometry drawn with the lit vertex mode.
+
+use crate::color::conv::IntoLinSrgba;
+use crate::color::LinSrgb;
+use crate::draw::properties::ColorScalar;
+use crate::glam::Vec3;
+
+/// A light used to shade geometry drawn with `VertexMode::Lit`.
+///
+/// Lights are stored within the draw **Context**, so different parts of a frame may be lit
+/// differently. If no lights are specified, `Light::default_set` is used.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Light {
+    /// The kind of light and its placement.
+    pub kind: LightKind,
+    /// The color of the light.
+    pub color: LinSrgb,
+    /// A multiplier applied to the color of the light.
+    pub intensity: f32,
+}
+
+/// The kind of a **Light** along with any properties unique to that kind.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightKind {
+    /// Light that uniformly illuminates all geometry regardless of its normal.
+    Ambient,
+    /// A light infinitely far away, shining in the given direction.
+    Directional { direction: Vec3 },
+    /// A light shining from the given position in all directions.
+    ///
+    /// If `range` is `Some`, the light falls off to nothing at the given distance.
+    Point { position: Vec3, range: Option<f32> },
+}
+
+impl Light {
+    /// The maximum number of lights that may affect a single draw context.
+    ///
+    /// Any lights beyond this are ignored by the renderer.
+    pub const MAX: usize = 8;
+
+    /// An ambient light that uniformly illuminates all geometry.
+    pub fn ambient() -> Self {
+        Self::new(LightKind::Ambient)
+    }
+
+    /// A light infinitely far away, shining in the given direction.
+    pub fn directional(direction: Vec3) -> Self {
+        Self::new(LightKind::Directional { direction })
+    }
+
+    /// A light shining in all directions from the given position.
+    pub fn point(position: Vec3) -> Self {
+        let range = None;
+        Self::new(LightKind::Point { position, range })
+    }
+
+    /// The set of lights used when lit geometry is drawn without specifying any lights.
+    ///
+    /// This is a dim ambient light along with a white directional light shining from the top
+    /// left of the viewer into the scene.
+    pub fn default_set() -> [Light; 2] {
+        [
+            Light::ambient().intensity(0.2),
+            Light::directional(Vec3::new(1.0, -1.0, -1.0)),
+        ]
+    }
+
+    fn new(kind: LightKind) -> Self {
+        Light {
+            kind,
+            color: LinSrgb::new(1.0, 1.0, 1.0),
+            intensity: 1.0,
+        }
+    }
+
+    /// Specify the color of the light.
+    ///
+    /// The alpha channel of the given color is ignored.
+    pub fn color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.color = color.into_lin_srgba().color;
+        self
+    }
+
+    /// Specify the multiplier applied to the color of the light.
+    pub fn intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// The distance at which a point light falls off to nothing.
+    ///
+    /// Has no effect on ambient or directional lights.
+    pub fn range(mut self, range: f32) -> Self {
+        if let LightKind::Point {
+            range: ref mut r, ..
+        } = self.kind
+        {
+            *r = Some(range);
+        }
+        self
+    }
+}
@@ -1,25 +1,31 @@
 //! Items related to the custom mesh type used by the `Draw` API.
 
 use crate::geom;
-use crate::mesh::{self, MeshPoints, WithColors, WithIndices, WithTexCoords};
+use crate::mesh::{self, MeshPoints, WithColors, WithIndices, WithNormals, WithTexCoords};
 use std::ops::{Deref, DerefMut};
 
 pub mod builder;
 pub mod vertex;
 
 pub use self::builder::MeshBuilder;
-pub use self::vertex::Vertex;
+pub use self::vertex::{LitVertex, Vertex};
 
 pub type Points = Vec<vertex::Point>;
 pub type Indices = Vec<u32>;
 pub type Colors = Vec<vertex::Color>;
 pub type TexCoords = Vec<vertex::TexCoords>;
+pub type Normals = Vec<vertex::Normal>;
 
 /// The inner mesh type used by the **draw::Mesh**.
-pub type MeshType =
-    WithTexCoords<WithColors<WithIndices<MeshPoints<Points>, Indices>, Colors>, TexCoords>;
+pub type MeshType = WithNormals<
+    WithTexCoords<WithColors<WithIndices<MeshPoints<Points>, Indices>, Colors>, TexCoords>,
+    Normals,
+>;
 
 /// The custom mesh type used internally by the **Draw** API.
+///
+/// The normals channel is used by the lit vertex mode. Vertices pushed without a normal receive
+/// `vertex::default_normal()`.
 #[derive(Clone, Debug)]
 pub struct Mesh {
     mesh: MeshType,
@@ -61,11 +67,21 @@ impl Mesh {
         mesh::TexCoords::tex_coords(self)
     }
 
+    /// The **Mesh**'s vertex normals channel.
+    pub fn normals(&self) -> &[vertex::Normal] {
+        mesh::Normals::normals(self)
+    }
+
     /// Push the given vertex onto the inner channels.
     pub fn push_vertex(&mut self, v: Vertex) {
         mesh::push_vertex(self, v);
     }
 
+    /// Push the given vertex and its normal onto the inner channels.
+    pub fn push_lit_vertex(&mut self, v: LitVertex) {
+        mesh::push_vertex(self, v);
+    }
+
     /// Push the given index onto the inner **Indices** channel.
     pub fn push_index(&mut self, i: u32) {
         mesh::push_index(self, i);
@@ -79,6 +95,14 @@ impl Mesh {
         mesh::extend_vertices(self, vs);
     }
 
+    /// Extend the mesh channels with the given vertices and their normals.
+    pub fn extend_lit_vertices<I>(&mut self, vs: I)
+    where
+        I: IntoIterator<Item = LitVertex>,
+    {
+        mesh::extend_vertices(self, vs);
+    }
+
     /// Extend the **Mesh** indices channel with the given indices.
     pub fn extend_indices<I>(&mut self, is: I)
     where
@@ -97,6 +121,16 @@ impl Mesh {
         self.extend_indices(is);
     }
 
+    /// Extend the **Mesh** with the given vertices, their normals and indices.
+    pub fn extend_lit<V, I>(&mut self, vs: V, is: I)
+    where
+        V: IntoIterator<Item = LitVertex>,
+        I: IntoIterator<Item = u32>,
+    {
+        self.extend_lit_vertices(vs);
+        self.extend_indices(is);
+    }
+
     /// Clear all vertices from the mesh.
     pub fn clear_vertices(&mut self) {
         mesh::clear_vertices(self);
@@ -137,6 +171,8 @@ impl Mesh {
         assert_eq!(points.len(), colors.len());
         assert_eq!(points.len(), tex_coords.len());
         let slices = (tex_coords, (colors, (indices, points)));
+        let normals = vec![vertex::default_normal(); points.len()];
+        let slices = (&normals[..], slices);
         mesh::ExtendFromSlice::extend_from_slice(&mut self.mesh, slices);
     }
 
@@ -199,7 +235,7 @@ impl DerefMut for Mesh {
 impl mesh::GetVertex<u32> for Mesh {
     type Vertex = Vertex;
     fn get_vertex(&self, index: u32) -> Option<Self::Vertex> {
-        mesh::WithTexCoords::get_vertex(&self.mesh, index)
+        mesh::WithTexCoords::get_vertex(&*self.mesh, index)
     }
 }
 
@@ -235,8 +271,23 @@ impl mesh::TexCoords for Mesh {
     }
 }
 
+impl mesh::Normals for Mesh {
+    type Normal = vertex::Normal;
+    type Normals = Normals;
+    fn normals(&self) -> &Self::Normals {
+        self.mesh.normals()
+    }
+}
+
 impl mesh::PushVertex<Vertex> for Mesh {
     fn push_vertex(&mut self, v: Vertex) {
+        let normal = vertex::default_normal();
+        self.mesh.push_vertex(LitVertex { vertex: v, normal });
+    }
+}
+
+impl mesh::PushVertex<LitVertex> for Mesh {
+    fn push_vertex(&mut self, v: LitVertex) {
         self.mesh.push_vertex(v);
     }
 }
@@ -276,4 +327,5 @@ fn test_method_access() {
     mesh::Indices::indices(&mesh);
     mesh::Colors::colors(&mesh);
     mesh::TexCoords::tex_coords(&mesh);
+    mesh::Normals::normals(&mesh);
 }
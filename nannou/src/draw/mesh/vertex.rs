@@ -1,6 +1,6 @@
 use crate::color;
 use crate::geom::{Point2, Point3, Vec3};
-use crate::mesh::vertex::{WithColor, WithTexCoords};
+use crate::mesh::vertex::{WithColor, WithNormal, WithTexCoords};
 
 pub type Point = Point3;
 pub type Color = color::LinSrgba;
@@ -9,9 +9,12 @@ pub type Normal = Vec3;
 pub type ColoredPoint = WithColor<Point, Color>;
 pub type ColoredPoint2 = WithColor<Point2, Color>;
 
-/// The vertex type produced by the **draw::Mesh**'s inner **MeshType**.
+/// The vertex type produced by the **draw::Mesh**.
 pub type Vertex = WithTexCoords<WithColor<Point, Color>, TexCoords>;
 
+/// A **Vertex** along with its normal, as produced by the **draw::Mesh**'s inner **MeshType**.
+pub type LitVertex = WithNormal<Vertex, Normal>;
+
 /// The number of channels in the color type.
 pub const COLOR_CHANNEL_COUNT: usize = 4;
 
@@ -36,6 +39,19 @@ pub fn new(point: Point, color: Color, tex_coords: TexCoords) -> Vertex {
     }
 }
 
+/// Simplified constructor for a **draw::mesh::LitVertex**.
+pub fn new_lit(point: Point, color: Color, tex_coords: TexCoords, normal: Normal) -> LitVertex {
+    WithNormal {
+        vertex: new(point, color, tex_coords),
+        normal,
+    }
+}
+
+/// The default normal, for the case where a vertex is not lit.
+pub fn default_normal() -> Normal {
+    Normal::ZERO
+}
+
 /// Default texture coordinates, for the case where a type is not textured.
 pub fn default_tex_coords() -> TexCoords {
     [0.0; 2].into()
@@ -13,19 +13,29 @@ use std::mem;
 use std::rc::Rc;
 
 pub use self::background::Background;
+pub use self::camera::Camera;
+pub use self::canvas::Canvas;
 pub use self::drawing::{Drawing, DrawingContext};
+pub use self::light::Light;
 use self::mesh::vertex::{Color, TexCoords};
 pub use self::mesh::Mesh;
 use self::primitive::Primitive;
+pub use self::render_target::RenderTarget;
 pub use self::renderer::{Builder as RendererBuilder, Renderer};
+pub use self::svg::Svg;
 pub use self::theme::Theme;
 
 pub mod background;
+pub mod camera;
+pub mod canvas;
 mod drawing;
+pub mod light;
 pub mod mesh;
 pub mod primitive;
 pub mod properties;
+pub mod render_target;
 pub mod renderer;
+pub mod svg;
 pub mod theme;
 
 /// A simple API for drawing 2D and 3D graphics.
@@ -58,7 +68,8 @@ pub struct Draw {
     context: Context,
 }
 
-/// The current **Transform**, alpha **BlendState** and **Scissor** of a **Draw** instance.
+/// The current **Transform**, alpha **BlendState**, **Scissor**, **Camera**, **Canvas** and
+/// **Light**s of a **Draw** instance.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Context {
     pub transform: Mat4,
@@ -68,6 +79,18 @@ pub struct Context {
     // here.
     pub topology: wgpu::PrimitiveTopology,
     pub sampler: wgpu::SamplerDescriptor<'static>,
+    /// The camera used to project drawings onto the output.
+    ///
+    /// If `None`, the default orthographic projection in logical pixels is used.
+    pub camera: Option<Camera>,
+    /// The fixed logical canvas fitted to the output.
+    ///
+    /// If `None`, the canvas of the window being drawn to is used, if any.
+    pub canvas: Option<Canvas>,
+    /// The lights used to shade geometry drawn with the lit vertex mode.
+    ///
+    /// If empty, `Light::default_set` is used.
+    pub lights: Vec<Light>,
 }
 
 /// Commands generated by drawings.
@@ -461,6 +484,58 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance where all drawings will be viewed through the given
+    /// **Camera**.
+    ///
+    /// The camera is stored in the draw context, so different parts of the same frame may be
+    /// drawn with different cameras.
+    pub fn camera(&self, camera: Camera) -> Self {
+        let mut context = self.context.clone();
+        context.camera = Some(camera);
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance that uses the default orthographic projection, where one
+    /// unit is equal to one logical pixel.
+    pub fn default_camera(&self) -> Self {
+        let mut context = self.context.clone();
+        context.camera = None;
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance where all drawings are made within a fixed logical
+    /// **Canvas** that is fitted to the output.
+    ///
+    /// This overrides the canvas of the window being drawn to, if any.
+    pub fn canvas(&self, canvas: Canvas) -> Self {
+        let mut context = self.context.clone();
+        context.canvas = Some(canvas);
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance where lit geometry will also be shaded by the given
+    /// **Light**.
+    ///
+    /// Up to `Light::MAX` lights may affect a single context.
+    pub fn light(&self, light: Light) -> Self {
+        let mut context = self.context.clone();
+        context.lights.push(light);
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance where lit geometry will be shaded by the given set of
+    /// **Light**s, replacing any previously specified lights.
+    ///
+    /// If the set is empty, `Light::default_set` is used.
+    pub fn lights<I>(&self, lights: I) -> Self
+    where
+        I: IntoIterator<Item = Light>,
+    {
+        let mut context = self.context.clone();
+        context.lights = lights.into_iter().collect();
+        self.context(context)
+    }
+
     /// Specify the primitive topology to use within the render pipeline.
     ///
     /// This method is shared between the `line_mode`, `point_mode` and `triangle_mode` methods.
@@ -528,6 +603,36 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Cuboid**.
+    pub fn cuboid(&self) -> Drawing<primitive::Cuboid> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Sphere**.
+    pub fn sphere(&self) -> Drawing<primitive::Sphere> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Cylinder**.
+    pub fn cylinder(&self) -> Drawing<primitive::Cylinder> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Cone**.
+    pub fn cone(&self) -> Drawing<primitive::Cone> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Torus**.
+    pub fn torus(&self) -> Drawing<primitive::Torus> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Plane**.
+    pub fn plane(&self) -> Drawing<primitive::Plane> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing a **Quad**.
     pub fn quad(&self) -> Drawing<primitive::Quad> {
         self.a(Default::default())
@@ -649,6 +754,9 @@ impl Default for Context {
             scissor: Scissor::Full,
             topology: wgpu::RenderPipelineBuilder::DEFAULT_PRIMITIVE_TOPOLOGY,
             sampler: wgpu::SamplerBuilder::new().into_descriptor(),
+            camera: None,
+            canvas: None,
+            lights: vec![],
         }
     }
 }
@@ -3,7 +3,9 @@ use crate::draw::primitive::path;
 use crate::draw::primitive::Line;
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{ColorScalar, SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    ColorScalar, Dashes, SetColor, SetDashes, SetOrientation, SetPosition, SetStroke,
+};
 use crate::draw::{self, Drawing};
 use crate::geom::{pt2, Point2};
 use crate::glam::vec2;
@@ -146,6 +148,12 @@ impl SetPosition for Arrow {
     }
 }
 
+impl SetDashes for Arrow {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.line)
+    }
+}
+
 impl SetColor<ColorScalar> for Arrow {
     fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
         SetColor::rgba_mut(&mut self.line)
@@ -178,31 +186,10 @@ impl draw::renderer::RenderPrimitive for Arrow {
             head_length,
             head_width,
         } = self;
-        let start = line.start.unwrap_or(pt2(0.0, 0.0));
-        let end = line.end.unwrap_or(pt2(0.0, 0.0));
-        if start == end {
-            return draw::renderer::PrimitiveRender::default();
-        }
-
-        // Calculate the arrow head points.
-        let line_w_2 = line.path.opts.line_width * 2.0;
-        let line_w_4 = line_w_2 * 2.0;
-        let head_width = head_width.unwrap_or(line_w_2);
-        let head_length = head_length.unwrap_or(line_w_4);
-        let line_dir = end - start;
-        let line_dir_len = line_dir.length();
-        let tri_len = head_length.min(line_dir_len);
-        let tri_dir_norm = line_dir.normalize() * tri_len;
-        let tri_start = end - tri_dir_norm;
-        let tri_end = end;
-        let line_start = start;
-        let line_end = tri_start;
-        let tri_a = tri_end;
-        let tri_w_dir = vec2(-tri_dir_norm.y, tri_dir_norm.x).normalize() * head_width;
-        let tri_b = tri_start + tri_w_dir;
-        let tri_c = tri_start - tri_w_dir;
-        // The line should only be drawn if there is space after drawing the triangle.
-        let draw_line = line_dir_len > tri_len;
+        let (tri_points, line_points) = match head_and_line(&line, head_length, head_width) {
+            None => return draw::renderer::PrimitiveRender::default(),
+            Some(geometry) => geometry,
+        };
 
         // Determine the transform to apply to all points.
         let global_transform = *ctxt.transform;
@@ -210,7 +197,6 @@ impl draw::renderer::RenderPrimitive for Arrow {
         let transform = global_transform * local_transform;
 
         // Draw the tri.
-        let tri_points = [tri_a, tri_b, tri_c];
         let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
         let close_tri = true;
         let tri_events = lyon::path::iterator::FromPolyline::new(close_tri, tri_points);
@@ -227,16 +213,16 @@ impl draw::renderer::RenderPrimitive for Arrow {
         );
 
         // Draw the line.
-        if draw_line {
-            let line_points = [line_start, line_end];
+        if let Some(line_points) = line_points {
             let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
             let close_line = false;
             let line_events = lyon::path::iterator::FromPolyline::new(close_line, line_points);
+            let options = path::Options::Stroke(line.path.opts);
             path::render_path_events(
-                line_events,
+                path::dashed(line_events, line.path.dashes.as_ref(), &options),
                 line.path.color,
                 transform,
-                path::Options::Stroke(line.path.opts),
+                options,
                 &ctxt.theme,
                 &draw::theme::Primitive::Arrow,
                 &mut ctxt.fill_tessellator,
@@ -249,6 +235,84 @@ impl draw::renderer::RenderPrimitive for Arrow {
     }
 }
 
+impl draw::svg::SvgPrimitive for Arrow {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Arrow {
+            line,
+            head_length,
+            head_width,
+        } = self;
+        let (tri_points, line_points) = match head_and_line(&line, head_length, head_width) {
+            None => return,
+            Some(geometry) => geometry,
+        };
+        let local_transform = line.path.position.transform() * line.path.orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let theme_prim = &draw::theme::Primitive::Arrow;
+
+        let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
+        let tri_events = lyon::path::iterator::FromPolyline::new(true, tri_points);
+        let opts = path::Options::Fill(Default::default());
+        let color = line.path.color;
+        draw::svg::path_events(
+            svg, tri_events, color, transform, &opts, ctxt.theme, theme_prim,
+        );
+
+        if let Some(line_points) = line_points {
+            let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
+            let line_events = lyon::path::iterator::FromPolyline::new(false, line_points);
+            let opts = path::Options::Stroke(line.path.opts);
+            let line_events = path::dashed(line_events, line.path.dashes.as_ref(), &opts);
+            draw::svg::path_events(
+                svg,
+                line_events,
+                color,
+                transform,
+                &opts,
+                ctxt.theme,
+                theme_prim,
+            );
+        }
+    }
+}
+
+// Calculate the points of the arrow head triangle and the line leading up to it.
+//
+// The line is `None` if there is no space left after drawing the head. Returns `None` if the start
+// and end of the arrow are the same.
+fn head_and_line(
+    line: &Line,
+    head_length: Option<f32>,
+    head_width: Option<f32>,
+) -> Option<([Point2; 3], Option<[Point2; 2]>)> {
+    let start = line.start.unwrap_or(pt2(0.0, 0.0));
+    let end = line.end.unwrap_or(pt2(0.0, 0.0));
+    if start == end {
+        return None;
+    }
+    let line_w_2 = line.path.opts.line_width * 2.0;
+    let line_w_4 = line_w_2 * 2.0;
+    let head_width = head_width.unwrap_or(line_w_2);
+    let head_length = head_length.unwrap_or(line_w_4);
+    let line_dir = end - start;
+    let line_dir_len = line_dir.length();
+    let tri_len = head_length.min(line_dir_len);
+    let tri_dir_norm = line_dir.normalize() * tri_len;
+    let tri_start = end - tri_dir_norm;
+    let tri_end = end;
+    let tri_a = tri_end;
+    let tri_w_dir = vec2(-tri_dir_norm.y, tri_dir_norm.x).normalize() * head_width;
+    let tri_b = tri_start + tri_w_dir;
+    let tri_c = tri_start - tri_w_dir;
+    // The line should only be drawn if there is space after drawing the triangle.
+    let line_points = if line_dir_len > tri_len {
+        Some([start, tri_start])
+    } else {
+        None
+    };
+    Some(([tri_a, tri_b, tri_c], line_points))
+}
+
 impl Default for Arrow {
     fn default() -> Self {
         let line = Default::default();
@@ -0,0 +1,132 @@
+use crate::draw::primitive::parametric::{self, ParametricOptions, SetParametric};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+
+/// Properties related to drawing a **Cone**.
+///
+/// The cone is aligned with the y axis with its apex at the top and its base at the bottom.
+/// `segments` describes the number of subdivisions around the y axis and `rings` describes the
+/// number of subdivisions from the apex to the base. The width and depth describe the diameter of
+/// the base along the x and z axes respectively.
+#[derive(Clone, Debug, Default)]
+pub struct Cone {
+    dimensions: dimension::Properties,
+    parametric: ParametricOptions,
+}
+
+/// The drawing context for a **Cone**.
+pub type DrawingCone<'a> = Drawing<'a, Cone>;
+
+// Cone-specific methods.
+
+impl Cone {
+    /// Specify the width and depth of the **Cone**'s base via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// Produce the surface of the cone including its base, centred on the origin.
+    ///
+    /// Each vertex of the resulting mesh has a position, texture coordinates and a normal.
+    pub fn mesh(&self) -> parametric::Mesh {
+        let rx = self.dimensions.x.unwrap_or(100.0) * 0.5;
+        let h = self.dimensions.y.unwrap_or(100.0);
+        let rz = self.dimensions.z.unwrap_or(100.0) * 0.5;
+        let segments = self.parametric.segments_or(32);
+        let rings = self.parametric.rings_or(1);
+        let mut mesh = parametric::Mesh::default();
+        parametric::extend_grid(&mut mesh, segments, rings, |u, v| {
+            let theta = u * std::f32::consts::PI * 2.0;
+            let (cos, sin) = (theta.cos(), theta.sin());
+            let point = Point3::new(cos * rx * v, h * (0.5 - v), sin * rz * v);
+            let normal = Vec3::new(cos * rz * h, rx * rz, sin * rx * h).normalize_or_zero();
+            (point, normal)
+        });
+        parametric::extend_disc(&mut mesh, segments, [rx, rz], h * -0.5, -1.0);
+        mesh
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingCone<'a> {
+    /// Specify the width and depth of the **Cone**'s base via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Cone {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Cone;
+        parametric::render_mesh(self.parametric, &surface, ctxt, &theme_prim, mesh)
+    }
+}
+
+impl draw::svg::SvgPrimitive for Cone {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Cone;
+        parametric::svg_mesh(self.parametric, &surface, ctxt, &theme_prim, svg);
+    }
+}
+
+impl SetOrientation for Cone {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.parametric)
+    }
+}
+
+impl SetPosition for Cone {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.parametric)
+    }
+}
+
+impl SetDimensions for Cone {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Cone {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.parametric)
+    }
+}
+
+impl SetParametric for Cone {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        SetParametric::parametric_options_mut(&mut self.parametric)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Cone> for Primitive {
+    fn from(prim: Cone) -> Self {
+        Primitive::Cone(prim)
+    }
+}
+
+impl Into<Option<Cone>> for Primitive {
+    fn into(self) -> Option<Cone> {
+        match self {
+            Primitive::Cone(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::primitive::path;
+use crate::draw::primitive::polygon::{PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, Dashes, LinSrgba, SetColor, SetDashes, SetDimensions, SetOrientation, SetPosition,
+    SetStroke,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{self, cuboid::Face, Point3};
+use crate::glam::{vec3, Mat4, Vec3};
+use lyon::tessellation::StrokeOptions;
+
+/// The faces of a cuboid in the order in which they are indexed by `Cuboid::face_colors`.
+const FACES: [Face; geom::cuboid::NUM_FACES as usize] = [
+    Face::Back,
+    Face::Right,
+    Face::Top,
+    Face::Front,
+    Face::Bottom,
+    Face::Left,
+];
+
+/// Properties related to drawing a **Cuboid**.
+#[derive(Clone, Debug, Default)]
+pub struct Cuboid {
+    dimensions: dimension::Properties,
+    polygon: PolygonInit,
+    face_colors: [Option<LinSrgba>; geom::cuboid::NUM_FACES as usize],
+}
+
+/// The drawing context for a **Cuboid**.
+pub type DrawingCuboid<'a> = Drawing<'a, Cuboid>;
+
+// Cuboid-specific methods.
+
+impl Cuboid {
+    /// Stroke the edges of each face with the given color, producing a wireframe.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.stroke_color(color)
+    }
+
+    /// Fill the given face with the given color.
+    ///
+    /// Faces without a color of their own are filled with the cuboid's color.
+    pub fn face_color<C>(mut self, face: Face, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let index = FACES
+            .iter()
+            .position(|&f| f == face)
+            .expect("no index for face");
+        self.face_colors[index] = Some(color.into_lin_srgba());
+        self
+    }
+
+    // The geometry of the cuboid centred on the origin.
+    fn cuboid(&self) -> geom::Cuboid {
+        let w = self.dimensions.x.unwrap_or(100.0);
+        let h = self.dimensions.y.unwrap_or(100.0);
+        let d = self.dimensions.z.unwrap_or(100.0);
+        geom::Cuboid::from_xyz_whd(Point3::ZERO, vec3(w, h, d))
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingCuboid<'a> {
+    /// Stroke the edges of each face with the given color, producing a wireframe.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.stroke(color))
+    }
+
+    /// Fill the given face with the given color.
+    ///
+    /// Faces without a color of their own are filled with the cuboid's color.
+    pub fn face_color<C>(self, face: Face, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.face_color(face, color))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Cuboid {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let cuboid = self.cuboid();
+        let Cuboid {
+            polygon,
+            face_colors,
+            ..
+        } = self;
+        let PolygonOptions {
+            position,
+            orientation,
+            no_fill,
+            stroke_color,
+            color,
+            stroke,
+            dashes,
+            ..
+        } = polygon.opts;
+        let theme_prim = draw::theme::Primitive::Cuboid;
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = position.transform() * orientation.transform();
+        let transform = global_transform * local_transform;
+
+        // Fill each face with two triangles.
+        if !no_fill {
+            let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme_prim));
+            let mut push_tri = |tri: geom::Tri<[f32; 3]>, color: LinSrgba| {
+                let start_ix = mesh.points().len() as u32;
+                for p in tri.vertices() {
+                    let p = transform.transform_point3(p.into());
+                    let tex_coords = [0.0, 0.0].into();
+                    mesh.push_vertex(draw::mesh::vertex::new(p, color, tex_coords));
+                }
+                (0..3).for_each(|i| mesh.push_index(start_ix + i));
+            };
+            if face_colors.iter().all(Option::is_none) {
+                cuboid.triangles_iter().for_each(|tri| push_tri(tri, color));
+            } else {
+                for (face, face_color) in FACES.iter().zip(&face_colors) {
+                    let color = face_color.unwrap_or(color);
+                    let quad = cuboid.face_quad(*face);
+                    quad.triangles_iter().for_each(|tri| push_tri(tri, color));
+                }
+            }
+        }
+
+        // Stroke the outline of each face in the plane of that face.
+        if let Some(stroke_opts) = stroke {
+            let opts = path::Options::Stroke(stroke_opts);
+            for &face in FACES.iter() {
+                let (face_transform, points) = face_outline(&cuboid, face);
+                let events = lyon::path::iterator::FromPolyline::closed(points.iter().cloned());
+                path::render_path_events(
+                    path::dashed(events, dashes.as_ref(), &opts),
+                    stroke_color,
+                    transform * face_transform,
+                    opts.clone(),
+                    ctxt.theme,
+                    &theme_prim,
+                    ctxt.fill_tessellator,
+                    ctxt.stroke_tessellator,
+                    mesh,
+                );
+            }
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::SvgPrimitive for Cuboid {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let cuboid = self.cuboid();
+        let Cuboid {
+            polygon,
+            face_colors,
+            ..
+        } = self;
+        let PolygonOptions {
+            position,
+            orientation,
+            no_fill,
+            stroke_color,
+            color,
+            stroke,
+            dashes,
+            ..
+        } = polygon.opts;
+        let theme_prim = draw::theme::Primitive::Cuboid;
+        let local_transform = position.transform() * orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let fill_opts = path::Options::Fill(Default::default());
+        let stroke_opts = stroke.map(path::Options::Stroke);
+        for (&face, face_color) in FACES.iter().zip(&face_colors) {
+            let (face_transform, points) = face_outline(&cuboid, face);
+            let transform = transform * face_transform;
+            let events = || lyon::path::iterator::FromPolyline::closed(points.iter().cloned());
+            if !no_fill {
+                let color = face_color.or(color);
+                let (theme, opts) = (ctxt.theme, &fill_opts);
+                draw::svg::path_events(svg, events(), color, transform, opts, theme, &theme_prim);
+            }
+            if let Some(ref opts) = stroke_opts {
+                let (theme, color) = (ctxt.theme, stroke_color);
+                let events = path::dashed(events(), dashes.as_ref(), opts);
+                draw::svg::path_events(svg, events, color, transform, opts, theme, &theme_prim);
+            }
+        }
+    }
+}
+
+// Produce a transform from a 2D plane to the given face along with the corners of the face
+// within that plane.
+fn face_outline(cuboid: &geom::Cuboid, face: Face) -> (Mat4, [lyon::math::Point; 4]) {
+    let geom::Quad([a, b, _, d]) = cuboid.face_quad(face);
+    let (a, b, d) = (Vec3::from(a), Vec3::from(b), Vec3::from(d));
+    let (u, v) = (b - a, d - a);
+    let (w, h) = (u.length(), v.length());
+    let (u, v) = (u / w, v / h);
+    let centre = a + (b - a) * 0.5 + (d - a) * 0.5;
+    let transform = Mat4::from_cols(
+        u.extend(0.0),
+        v.extend(0.0),
+        u.cross(v).extend(0.0),
+        centre.extend(1.0),
+    );
+    let (hw, hh) = (w * 0.5, h * 0.5);
+    let points = [
+        lyon::math::point(-hw, -hh),
+        lyon::math::point(hw, -hh),
+        lyon::math::point(hw, hh),
+        lyon::math::point(-hw, hh),
+    ];
+    (transform, points)
+}
+
+impl SetOrientation for Cuboid {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Cuboid {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Cuboid {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Cuboid {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Cuboid {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Cuboid {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Cuboid {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversions.
+
+impl From<geom::Cuboid<f32>> for Cuboid {
+    fn from(c: geom::Cuboid<f32>) -> Self {
+        let (xyz, whd) = c.xyz_whd();
+        Self::default().xyz(xyz).whd(whd)
+    }
+}
+
+impl From<Cuboid> for Primitive {
+    fn from(prim: Cuboid) -> Self {
+        Primitive::Cuboid(prim)
+    }
+}
+
+impl Into<Option<Cuboid>> for Primitive {
+    fn into(self) -> Option<Cuboid> {
+        match self {
+            Primitive::Cuboid(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::draw::primitive::parametric::{self, ParametricOptions, SetParametric};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+
+/// Properties related to drawing a **Cylinder**.
+///
+/// The cylinder is aligned with the y axis. `segments` describes the number of subdivisions
+/// around the y axis and `rings` describes the number of subdivisions along its height. The width
+/// and depth describe the diameter of the cylinder along the x and z axes respectively.
+#[derive(Clone, Debug, Default)]
+pub struct Cylinder {
+    dimensions: dimension::Properties,
+    parametric: ParametricOptions,
+}
+
+/// The drawing context for a **Cylinder**.
+pub type DrawingCylinder<'a> = Drawing<'a, Cylinder>;
+
+// Cylinder-specific methods.
+
+impl Cylinder {
+    /// Specify the width and depth of the **Cylinder** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w(side).d(side)
+    }
+
+    /// Produce the surface of the cylinder including its caps, centred on the origin.
+    ///
+    /// Each vertex of the resulting mesh has a position, texture coordinates and a normal.
+    pub fn mesh(&self) -> parametric::Mesh {
+        let rx = self.dimensions.x.unwrap_or(100.0) * 0.5;
+        let h = self.dimensions.y.unwrap_or(100.0);
+        let rz = self.dimensions.z.unwrap_or(100.0) * 0.5;
+        let segments = self.parametric.segments_or(32);
+        let rings = self.parametric.rings_or(1);
+        let mut mesh = parametric::Mesh::default();
+        parametric::extend_grid(&mut mesh, segments, rings, |u, v| {
+            let theta = u * std::f32::consts::PI * 2.0;
+            let (cos, sin) = (theta.cos(), theta.sin());
+            let point = Point3::new(cos * rx, h * (0.5 - v), sin * rz);
+            let normal = Vec3::new(cos * rz, 0.0, sin * rx).normalize_or_zero();
+            (point, normal)
+        });
+        parametric::extend_disc(&mut mesh, segments, [rx, rz], h * 0.5, 1.0);
+        parametric::extend_disc(&mut mesh, segments, [rx, rz], h * -0.5, -1.0);
+        mesh
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingCylinder<'a> {
+    /// Specify the width and depth of the **Cylinder** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Cylinder {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Cylinder;
+        parametric::render_mesh(self.parametric, &surface, ctxt, &theme_prim, mesh)
+    }
+}
+
+impl draw::svg::SvgPrimitive for Cylinder {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Cylinder;
+        parametric::svg_mesh(self.parametric, &surface, ctxt, &theme_prim, svg);
+    }
+}
+
+impl SetOrientation for Cylinder {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.parametric)
+    }
+}
+
+impl SetPosition for Cylinder {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.parametric)
+    }
+}
+
+impl SetDimensions for Cylinder {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Cylinder {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.parametric)
+    }
+}
+
+impl SetParametric for Cylinder {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        SetParametric::parametric_options_mut(&mut self.parametric)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Cylinder> for Primitive {
+    fn from(prim: Cylinder) -> Self {
+        Primitive::Cylinder(prim)
+    }
+}
+
+impl Into<Option<Cylinder>> for Primitive {
+    fn into(self) -> Option<Cylinder> {
+        match self {
+            Primitive::Cylinder(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
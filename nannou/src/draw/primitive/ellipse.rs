@@ -4,7 +4,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    spatial, ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    spatial, ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions,
+    SetGradient, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::Drawing;
 use crate::geom;
@@ -74,17 +75,7 @@ impl draw::renderer::RenderPrimitive for Ellipse {
         let h = maybe_y.map(f32::abs).unwrap_or(100.0);
         match resolution {
             None => {
-                // Determine the transform to apply to all points.
-                let radii = lyon::math::vector(w * 0.5, h * 0.5);
-                if radii.square_length() > 0.0 {
-                    let centre = lyon::math::point(0.0, 0.0);
-                    let mut builder = lyon::path::Path::svg_builder();
-                    let sweep_angle = lyon::math::Angle::radians(std::f32::consts::PI * 2.0);
-                    let x_rotation = lyon::math::Angle::radians(0.0);
-                    let start = lyon::math::point(w * 0.5, 0.0);
-                    builder.move_to(start);
-                    builder.arc(centre, radii, sweep_angle, x_rotation);
-                    let path = builder.build();
+                if let Some(path) = arc_path(w, h) {
                     polygon::render_events_themed(
                         polygon.opts,
                         || (&path).into_iter(),
@@ -112,6 +103,51 @@ impl draw::renderer::RenderPrimitive for Ellipse {
     }
 }
 
+impl draw::svg::SvgPrimitive for Ellipse {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Ellipse {
+            dimensions,
+            polygon,
+            resolution,
+        } = self;
+        let w = dimensions.x.map(f32::abs).unwrap_or(100.0);
+        let h = dimensions.y.map(f32::abs).unwrap_or(100.0);
+        let theme_prim = &draw::theme::Primitive::Ellipse;
+        match resolution {
+            None => {
+                if let Some(path) = arc_path(w, h) {
+                    let events = || (&path).into_iter();
+                    polygon::svg_events_themed(polygon.opts, events, ctxt, theme_prim, svg);
+                }
+            }
+            Some(resolution) => {
+                let rect = geom::Rect::from_w_h(w, h);
+                let ellipse = geom::Ellipse::new(rect, resolution);
+                let points = ellipse.circumference().map(Vec2::from);
+                polygon::svg_points_themed(polygon.opts, points, ctxt, theme_prim, svg);
+            }
+        }
+    }
+}
+
+// Build the path of an ellipse with the given width and height centred on the origin.
+//
+// Returns `None` if the ellipse has no area.
+fn arc_path(w: f32, h: f32) -> Option<lyon::path::Path> {
+    let radii = lyon::math::vector(w * 0.5, h * 0.5);
+    if radii.square_length() <= 0.0 {
+        return None;
+    }
+    let centre = lyon::math::point(0.0, 0.0);
+    let mut builder = lyon::path::Path::svg_builder();
+    let sweep_angle = lyon::math::Angle::radians(std::f32::consts::PI * 2.0);
+    let x_rotation = lyon::math::Angle::radians(0.0);
+    let start = lyon::math::point(w * 0.5, 0.0);
+    builder.move_to(start);
+    builder.arc(centre, radii, sweep_angle, x_rotation);
+    Some(builder.build())
+}
+
 impl SetOrientation for Ellipse {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.polygon)
@@ -136,6 +172,18 @@ impl SetColor<ColorScalar> for Ellipse {
     }
 }
 
+impl SetGradient for Ellipse {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Ellipse {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetStroke for Ellipse {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
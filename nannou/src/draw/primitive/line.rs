@@ -2,7 +2,9 @@ use crate::color::LinSrgba;
 use crate::draw::primitive::path;
 use crate::draw::primitive::{PathStroke, Primitive};
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{ColorScalar, SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    ColorScalar, Dashes, SetColor, SetDashes, SetOrientation, SetPosition, SetStroke,
+};
 use crate::draw::{self, Drawing};
 use crate::geom::{pt2, Point2};
 use lyon::tessellation::StrokeOptions;
@@ -105,6 +107,12 @@ impl SetPosition for Line {
     }
 }
 
+impl SetDashes for Line {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.path)
+    }
+}
+
 impl SetColor<ColorScalar> for Line {
     fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
         SetColor::rgba_mut(&mut self.path)
@@ -148,11 +156,12 @@ impl draw::renderer::RenderPrimitive for Line {
         let local_transform = path.position.transform() * path.orientation.transform();
         let transform = global_transform * local_transform;
 
+        let options = path::Options::Stroke(path.opts);
         path::render_path_events(
-            events,
+            path::dashed(events, path.dashes.as_ref(), &options),
             path.color,
             transform,
-            path::Options::Stroke(path.opts),
+            options,
             &ctxt.theme,
             &draw::theme::Primitive::Line,
             &mut ctxt.fill_tessellator,
@@ -163,3 +172,29 @@ impl draw::renderer::RenderPrimitive for Line {
         draw::renderer::PrimitiveRender::default()
     }
 }
+
+impl draw::svg::SvgPrimitive for Line {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Line { path, start, end } = self;
+        let start = start.unwrap_or(pt2(0.0, 0.0));
+        let end = end.unwrap_or(pt2(0.0, 0.0));
+        if start == end {
+            return;
+        }
+        let points = [start, end];
+        let points = points.iter().cloned().map(|p| p.to_array().into());
+        let events = lyon::path::iterator::FromPolyline::new(false, points);
+        let local_transform = path.position.transform() * path.orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let options = path::Options::Stroke(path.opts);
+        draw::svg::path_events(
+            svg,
+            path::dashed(events, path.dashes.as_ref(), &options),
+            path.color,
+            transform,
+            &options,
+            ctxt.theme,
+            &draw::theme::Primitive::Line,
+        );
+    }
+}
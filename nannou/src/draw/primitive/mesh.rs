@@ -1,10 +1,11 @@
 use crate::color::conv::IntoLinSrgba;
-use crate::draw::mesh::vertex::{self, Point, TexCoords, Vertex};
+use crate::draw::mesh::vertex::{self, Normal, Point, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition};
 use crate::draw::{self, Drawing};
 use crate::geom;
+use crate::mesh;
 use crate::wgpu;
 use std::ops;
 
@@ -59,7 +60,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = t.into();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Texture;
         self.points_inner(
@@ -85,7 +86,7 @@ impl Vertexless {
             let point = p.into();
             let color = c.into_lin_srgba();
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         self.points_inner(inner_mesh, vertices, vertex_mode, None)
@@ -107,7 +108,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         let mut mesh = self.points_inner(inner_mesh, vertices, vertex_mode, None);
@@ -115,6 +116,31 @@ impl Vertexless {
         mesh
     }
 
+    /// Describe the mesh with a sequence of lit points.
+    ///
+    /// Each of the vertices must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn points_lit<I, P, N>(self, inner_mesh: &mut draw::Mesh, points: I) -> Mesh
+    where
+        I: IntoIterator<Item = (P, N)>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        let vertices = points.into_iter().map(|(p, n)| {
+            let point = p.into();
+            let color = vertex::DEFAULT_VERTEX_COLOR;
+            let tex_coords = vertex::default_tex_coords();
+            vertex::new_lit(point, color, tex_coords, n.into())
+        });
+        let vertex_mode = draw::renderer::VertexMode::Lit;
+        let mut mesh = self.points_inner(inner_mesh, vertices, vertex_mode, None);
+        mesh.fill_color = Some(FillColor(None));
+        mesh
+    }
+
     fn points_inner<I>(
         self,
         inner_mesh: &mut draw::Mesh,
@@ -123,12 +149,13 @@ impl Vertexless {
         texture_view: Option<wgpu::TextureView>,
     ) -> Mesh
     where
-        I: Iterator<Item = Vertex>,
+        I: Iterator,
+        draw::Mesh: mesh::PushVertex<I::Item>,
     {
         let v_start = inner_mesh.points().len();
         let i_start = inner_mesh.indices().len();
         for (i, vertex) in vertices.enumerate() {
-            inner_mesh.push_vertex(vertex);
+            mesh::PushVertex::push_vertex(inner_mesh, vertex);
             inner_mesh.push_index((v_start + i) as u32);
         }
         let v_end = inner_mesh.points().len();
@@ -198,6 +225,26 @@ impl Vertexless {
         self.points(inner_mesh, points)
     }
 
+    /// Describe the mesh with a sequence of lit triangles.
+    ///
+    /// Each of the vertices must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn tris_lit<I, P, N>(self, inner_mesh: &mut draw::Mesh, tris: I) -> Mesh
+    where
+        I: IntoIterator<Item = geom::Tri<(P, N)>>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        let points = tris
+            .into_iter()
+            .map(|t| t.map_vertices(|(p, n)| (p.into(), n.into())))
+            .flat_map(geom::Tri::vertices);
+        self.points_lit::<_, Point, Normal>(inner_mesh, points)
+    }
+
     /// Describe the mesh with the given indexed, textured points.
     ///
     /// Each trio of `indices` describes a single triangle made up of colored `points`.
@@ -223,7 +270,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = t.into();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Texture;
         self.indexed_inner(
@@ -258,7 +305,7 @@ impl Vertexless {
             let point = p.into();
             let color = c.into_lin_srgba();
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None)
@@ -279,7 +326,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         let mut mesh = self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None);
@@ -287,6 +334,34 @@ impl Vertexless {
         mesh
     }
 
+    /// Describe the mesh with the given indexed, lit points.
+    ///
+    /// Each trio of `indices` describes a single triangle made up of lit `points`.
+    ///
+    /// Each of the `points` must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn indexed_lit<V, I, P, N>(self, inner_mesh: &mut draw::Mesh, points: V, indices: I) -> Mesh
+    where
+        V: IntoIterator<Item = (P, N)>,
+        I: IntoIterator<Item = usize>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        let vertices = points.into_iter().map(|(p, n)| {
+            let point = p.into();
+            let color = vertex::DEFAULT_VERTEX_COLOR;
+            let tex_coords = vertex::default_tex_coords();
+            vertex::new_lit(point, color, tex_coords, n.into())
+        });
+        let vertex_mode = draw::renderer::VertexMode::Lit;
+        let mut mesh = self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None);
+        mesh.fill_color = Some(FillColor(None));
+        mesh
+    }
+
     fn indexed_inner<V, I>(
         self,
         inner_mesh: &mut draw::Mesh,
@@ -296,12 +371,13 @@ impl Vertexless {
         texture_view: Option<wgpu::TextureView>,
     ) -> Mesh
     where
-        V: IntoIterator<Item = Vertex>,
+        V: IntoIterator,
         I: IntoIterator<Item = usize>,
+        draw::Mesh: mesh::PushVertex<V::Item>,
     {
         let v_start = inner_mesh.points().len();
         let i_start = inner_mesh.indices().len();
-        inner_mesh.extend_vertices(vertices);
+        mesh::extend_vertices(&mut *inner_mesh, vertices);
         inner_mesh.extend_indices(indices.into_iter().map(|ix| ix as u32));
         let v_end = inner_mesh.points().len();
         let i_end = inner_mesh.indices().len();
@@ -479,6 +555,57 @@ impl<'a> Drawing<'a, Vertexless> {
     {
         self.map_ty_with_context(|ty, ctxt| ty.indexed_textured(ctxt.mesh, view, points, indices))
     }
+
+    /// Describe the mesh with a sequence of lit points.
+    ///
+    /// Each of the points must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn points_lit<I, P, N>(self, points: I) -> DrawingMesh<'a>
+    where
+        I: IntoIterator<Item = (P, N)>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_lit(ctxt.mesh, points))
+    }
+
+    /// Describe the mesh with a sequence of lit triangles.
+    ///
+    /// Each of the vertices must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn tris_lit<I, P, N>(self, tris: I) -> DrawingMesh<'a>
+    where
+        I: IntoIterator<Item = geom::Tri<(P, N)>>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.tris_lit(ctxt.mesh, tris))
+    }
+
+    /// Describe the mesh with the given indexed, lit points.
+    ///
+    /// Each trio of `indices` describes a single triangle made up of lit `points`.
+    ///
+    /// Each of the `points` must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    ///
+    /// The mesh is shaded by the lights of the **Draw** context. See `Draw::light`.
+    pub fn indexed_lit<V, I, P, N>(self, points: V, indices: I) -> DrawingMesh<'a>
+    where
+        V: IntoIterator<Item = (P, N)>,
+        I: IntoIterator<Item = usize>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.indexed_lit(ctxt.mesh, points, indices))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Mesh {
@@ -509,33 +636,26 @@ impl draw::renderer::RenderPrimitive for Mesh {
             .map(|i| ctxt.intermediary_mesh.indices()[i])
             .map(|i| new_mesh_vertex_start + i - old_mesh_vertex_start);
 
-        // A small function for transforming a point via the transform matrix.
+        // Small functions for transforming points and normals via the transform matrix.
         let transform_point = |p: geom::Point3| -> geom::Point3 { transform.transform_point3(p) };
+        let normal_transform = transform.inverse().transpose();
+        let transform_normal =
+            |n: Normal| -> Normal { normal_transform.transform_vector3(n).normalize_or_zero() };
 
         // Color the vertices based on whether or not we should fill, then extend the mesh!
-        match fill_color {
-            Some(fill) => {
-                let theme_prim = draw::theme::Primitive::Mesh;
-                let color = fill
-                    .0
-                    .unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme_prim));
-                let vertices = vertex_range.map(|i| {
-                    let point = transform_point(ctxt.intermediary_mesh.points()[i]);
-                    let tex_coords = ctxt.intermediary_mesh.tex_coords()[i];
-                    ((point, color), tex_coords).into()
-                });
-                mesh.extend(vertices, indices);
-            }
-            None => {
-                let vertices = vertex_range.map(|i| {
-                    let point = transform_point(ctxt.intermediary_mesh.points()[i]);
-                    let color = ctxt.intermediary_mesh.colors()[i];
-                    let tex_coords = ctxt.intermediary_mesh.tex_coords()[i];
-                    ((point, color), tex_coords).into()
-                });
-                mesh.extend(vertices, indices);
-            }
-        }
+        let theme_prim = draw::theme::Primitive::Mesh;
+        let fill_color = fill_color.map(|fill| {
+            fill.0
+                .unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme_prim))
+        });
+        let vertices = vertex_range.map(|i| {
+            let point = transform_point(ctxt.intermediary_mesh.points()[i]);
+            let color = fill_color.unwrap_or_else(|| ctxt.intermediary_mesh.colors()[i]);
+            let tex_coords = ctxt.intermediary_mesh.tex_coords()[i];
+            let normal = transform_normal(ctxt.intermediary_mesh.normals()[i]);
+            vertex::new_lit(point, color, tex_coords, normal)
+        });
+        mesh.extend_lit(vertices, indices);
 
         draw::renderer::PrimitiveRender {
             texture_view,
@@ -544,6 +664,50 @@ impl draw::renderer::RenderPrimitive for Mesh {
     }
 }
 
+impl draw::svg::SvgPrimitive for Mesh {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Mesh {
+            orientation,
+            position,
+            index_range,
+            vertex_mode,
+            fill_color,
+            ..
+        } = self;
+
+        // Textured meshes would require reading back texture data from the GPU.
+        if let draw::renderer::VertexMode::Texture = vertex_mode {
+            return;
+        }
+
+        let local_transform = position.transform() * orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let fill_color = fill_color.map(|fill| {
+            fill.0
+                .unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&draw::theme::Primitive::Mesh))
+        });
+
+        // SVG has no per-vertex colors, so each triangle is filled with its average color.
+        let src = ctxt.intermediary_mesh;
+        let indices = &src.indices()[index_range];
+        for tri in indices.chunks_exact(3) {
+            let points = [tri[0], tri[1], tri[2]]
+                .map(|i| transform.transform_point3(src.points()[i as usize]));
+            let color = fill_color.unwrap_or_else(|| {
+                let colors = [tri[0], tri[1], tri[2]].map(|i| src.colors()[i as usize]);
+                let avg = |f: fn(&LinSrgba) -> f32| colors.iter().map(f).sum::<f32>() / 3.0;
+                LinSrgba::new(
+                    avg(|c| c.red),
+                    avg(|c| c.green),
+                    avg(|c| c.blue),
+                    avg(|c| c.alpha),
+                )
+            });
+            svg.triangle(geom::Tri(points), color);
+        }
+    }
+}
+
 impl<I> Iterator for FlattenIndices<I>
 where
     I: Iterator<Item = [usize; 3]>,
@@ -0,0 +1,250 @@
+//! Items shared between the parametric 3D surface primitives, e.g. **Sphere**, **Cylinder**,
+//! **Cone**, **Torus** and **Plane**.
+
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+use crate::geom::{self, Point2, Point3, Vec3};
+use crate::mesh::channel::Channel;
+use crate::mesh::vertex::{WithNormal, WithTexCoords};
+use crate::mesh::{self, MeshPoints, WithIndices, WithNormals};
+
+/// The mesh type produced by the parametric surface primitives.
+///
+/// Each vertex has a position, texture coordinates and a normal.
+pub type Mesh = WithNormals<
+    mesh::WithTexCoords<WithIndices<MeshPoints<Vec<Point3>>, Vec<u32>>, Vec<Point2>>,
+    Vec<Vec3>,
+>;
+
+/// The vertex type yielded by the parametric surface **Mesh**.
+pub type Vertex = WithNormal<WithTexCoords<Point3, Point2>, Vec3>;
+
+/// A type used for specifying the resolution of a parametric surface.
+pub trait SetParametric: Sized {
+    /// Access to the parametric surface options.
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions;
+
+    /// The number of subdivisions around the surface's primary axis.
+    fn segments(mut self, segments: u32) -> Self {
+        self.parametric_options_mut().segments = Some(segments);
+        self
+    }
+
+    /// The number of subdivisions along the surface's secondary axis.
+    fn rings(mut self, rings: u32) -> Self {
+        self.parametric_options_mut().rings = Some(rings);
+        self
+    }
+
+    /// Specify both the number of segments and the number of rings.
+    fn resolution(self, segments: u32, rings: u32) -> Self {
+        self.segments(segments).rings(rings)
+    }
+
+    /// Shade the surface with the lights of the **Draw** context rather than filling it with a
+    /// flat color. See `Draw::light`.
+    fn lit(mut self) -> Self {
+        self.parametric_options_mut().lit = true;
+        self
+    }
+
+    /// Specify the whole set of parametric surface options.
+    fn parametric_options(mut self, opts: ParametricOptions) -> Self {
+        *self.parametric_options_mut() = opts;
+        self
+    }
+}
+
+/// The set of options shared by all parametric surface types.
+#[derive(Clone, Debug, Default)]
+pub struct ParametricOptions {
+    pub position: position::Properties,
+    pub orientation: orientation::Properties,
+    pub color: Option<LinSrgba>,
+    pub segments: Option<u32>,
+    pub rings: Option<u32>,
+    pub lit: bool,
+}
+
+impl ParametricOptions {
+    /// The number of segments, falling back to the given default.
+    pub fn segments_or(&self, default: u32) -> u32 {
+        self.segments.unwrap_or(default).max(1)
+    }
+
+    /// The number of rings, falling back to the given default.
+    pub fn rings_or(&self, default: u32) -> u32 {
+        self.rings.unwrap_or(default).max(1)
+    }
+}
+
+/// Extend the given mesh with a grid of `segments` by `rings` quads.
+///
+/// The given function is called for each vertex with the normalised `u` (along segments) and `v`
+/// (along rings) coordinates and must return the position and normal of the vertex at that
+/// coordinate. The `u` and `v` coordinates are also used as the texture coordinates of the vertex.
+pub fn extend_grid<F>(mesh: &mut Mesh, segments: u32, rings: u32, mut f: F)
+where
+    F: FnMut(f32, f32) -> (Point3, Vec3),
+{
+    let start = mesh::raw_vertex_count(&*mesh) as u32;
+    for r in 0..=rings {
+        let v = r as f32 / rings as f32;
+        for s in 0..=segments {
+            let u = s as f32 / segments as f32;
+            let (point, normal) = f(u, v);
+            let tex_coords = [u, v].into();
+            let vertex = WithTexCoords {
+                vertex: point,
+                tex_coords,
+            };
+            mesh::push_vertex(&mut *mesh, WithNormal { vertex, normal });
+        }
+    }
+    let stride = segments + 1;
+    for r in 0..rings {
+        for s in 0..segments {
+            let a = start + r * stride + s;
+            let b = a + 1;
+            let c = a + stride;
+            let d = c + 1;
+            mesh::extend_indices(&mut *mesh, [a, c, b, b, c, d].iter().cloned());
+        }
+    }
+}
+
+/// Extend the given mesh with a flat disc of the given radius centred at `y`, facing along
+/// `normal_y`.
+///
+/// Used for the caps of the **Cylinder** and **Cone**.
+pub fn extend_disc(mesh: &mut Mesh, segments: u32, radius: [f32; 2], y: f32, normal_y: f32) {
+    let normal = Vec3::new(0.0, normal_y, 0.0);
+    extend_grid(mesh, segments, 1, |u, v| {
+        let theta = u * std::f32::consts::PI * 2.0;
+        // Wind the disc so that it faces outwards.
+        let r = if normal_y > 0.0 { v } else { 1.0 - v };
+        let point = Point3::new(theta.cos() * radius[0] * r, y, theta.sin() * radius[1] * r);
+        (point, normal)
+    });
+}
+
+/// Render the given parametric surface mesh with the given options.
+pub fn render_mesh(
+    opts: ParametricOptions,
+    surface: &Mesh,
+    ctxt: draw::renderer::RenderContext,
+    theme_primitive: &draw::theme::Primitive,
+    mesh: &mut draw::Mesh,
+) -> draw::renderer::PrimitiveRender {
+    let ParametricOptions {
+        position,
+        orientation,
+        color,
+        lit,
+        ..
+    } = opts;
+    let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(theme_primitive));
+    let global_transform = *ctxt.transform;
+    let local_transform = position.transform() * orientation.transform();
+    let transform = global_transform * local_transform;
+    let normal_transform = transform.inverse().transpose();
+    let start = mesh.raw_vertex_count() as u32;
+    let vertices = mesh::raw_vertices(surface).map(|v: Vertex| {
+        let point = transform.transform_point3(v.vertex.vertex);
+        let normal = normal_transform
+            .transform_vector3(v.normal)
+            .normalize_or_zero();
+        draw::mesh::vertex::new_lit(point, color, v.vertex.tex_coords, normal)
+    });
+    let indices = mesh::Indices::indices(surface).channel();
+    mesh.extend_lit(vertices, indices.iter().map(|&i| start + i));
+    if lit {
+        draw::renderer::PrimitiveRender::lit()
+    } else {
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+/// Write the triangles of the given parametric surface mesh to the SVG.
+pub fn svg_mesh(
+    opts: ParametricOptions,
+    surface: &Mesh,
+    ctxt: draw::svg::SvgContext,
+    theme_primitive: &draw::theme::Primitive,
+    svg: &mut draw::Svg,
+) {
+    let ParametricOptions {
+        position,
+        orientation,
+        color,
+        ..
+    } = opts;
+    let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(theme_primitive));
+    let local_transform = position.transform() * orientation.transform();
+    let transform = *ctxt.transform * local_transform;
+    let points = mesh::Points::points(surface).channel();
+    let indices = mesh::Indices::indices(surface).channel();
+    for tri in indices.chunks_exact(3) {
+        let p = |i: u32| transform.transform_point3(points[i as usize]);
+        let tri = geom::Tri([p(tri[0]), p(tri[1]), p(tri[2])]);
+        svg.triangle(tri, color);
+    }
+}
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetParametric + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// The number of subdivisions around the surface's primary axis.
+    pub fn segments(self, segments: u32) -> Self {
+        self.map_ty(|ty| ty.segments(segments))
+    }
+
+    /// The number of subdivisions along the surface's secondary axis.
+    pub fn rings(self, rings: u32) -> Self {
+        self.map_ty(|ty| ty.rings(rings))
+    }
+
+    /// Specify both the number of segments and the number of rings.
+    pub fn resolution(self, segments: u32, rings: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(segments, rings))
+    }
+
+    /// Shade the surface with the lights of the **Draw** context rather than filling it with a
+    /// flat color. See `Draw::light`.
+    pub fn lit(self) -> Self {
+        self.map_ty(|ty| ty.lit())
+    }
+
+    /// Specify the whole set of parametric surface options.
+    pub fn parametric_options(self, opts: ParametricOptions) -> Self {
+        self.map_ty(|ty| ty.parametric_options(opts))
+    }
+}
+
+impl SetOrientation for ParametricOptions {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.orientation)
+    }
+}
+
+impl SetPosition for ParametricOptions {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.position)
+    }
+}
+
+impl SetColor<ColorScalar> for ParametricOptions {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.color)
+    }
+}
+
+impl SetParametric for ParametricOptions {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        self
+    }
+}
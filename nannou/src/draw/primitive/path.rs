@@ -2,9 +2,11 @@ use crate::color::conv::IntoLinSrgba;
 use crate::color::LinSrgba;
 use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
+use crate::draw::properties::gradient::GradientKind;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, SetColor, SetFill, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, SetColor, SetDashes, SetFill, SetGradient, SetOrientation,
+    SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing, DrawingContext};
 use crate::geom::Point2;
@@ -59,6 +61,8 @@ pub struct PathInit;
 pub struct PathOptions<T> {
     pub(crate) opts: T,
     pub(crate) color: Option<LinSrgba>,
+    pub(crate) gradient: Option<Gradient>,
+    pub(crate) dashes: Option<Dashes>,
     pub(crate) position: position::Properties,
     pub(crate) orientation: orientation::Properties,
 }
@@ -79,6 +83,8 @@ pub type PathStroke = PathOptions<StrokeOptions>;
 #[derive(Clone, Debug)]
 pub struct Path {
     color: Option<LinSrgba>,
+    gradient: Option<Gradient>,
+    dashes: Option<Dashes>,
     position: position::Properties,
     orientation: orientation::Properties,
     path_event_src: PathEventSource,
@@ -133,11 +139,15 @@ impl<T> PathOptions<T> {
         let orientation = Default::default();
         let position = Default::default();
         let color = Default::default();
+        let gradient = Default::default();
+        let dashes = Default::default();
         PathOptions {
             opts,
             orientation,
             position,
             color,
+            gradient,
+            dashes,
         }
     }
 }
@@ -191,6 +201,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             PathEventSource::Buffered(start..end),
             self.opts.into_options(),
             draw::renderer::VertexMode::Color,
@@ -307,6 +319,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             path_event_src,
             self.opts.into_options(),
             draw::renderer::VertexMode::Color,
@@ -343,6 +357,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             path_event_src,
             self.opts.into_options(),
             draw::renderer::VertexMode::Texture,
@@ -381,6 +397,375 @@ pub(crate) fn render_path_events<I>(
     }
 }
 
+/// The path events yielded by `dashed`.
+pub(crate) enum Dashed<I> {
+    Solid(I),
+    Dashed(std::vec::IntoIter<PathEvent>),
+}
+
+/// Split the given events into dashes if `options` describes a stroke and the given dashes are
+/// valid, otherwise yield the events unchanged.
+///
+/// Curves are flattened using the stroke tolerance before being split. As with SVG, the dash
+/// pattern restarts at the beginning of each sub-path.
+pub(crate) fn dashed<I>(
+    events: I,
+    dashes: Option<&Dashes>,
+    options: &Options,
+) -> Dashed<I::IntoIter>
+where
+    I: IntoIterator<Item = PathEvent>,
+{
+    match (dashes, options) {
+        (Some(dashes), Options::Stroke(opts)) if dashes.is_valid() => {
+            Dashed::Dashed(dash_events(events, dashes, opts.tolerance).into_iter())
+        }
+        _ => Dashed::Solid(events.into_iter()),
+    }
+}
+
+/// Split the given path events into a separate sub-path for each dash.
+///
+/// Returns the events unchanged other than flattening if the dashes are not valid.
+pub fn dash_events<I>(events: I, dashes: &Dashes, tolerance: f32) -> Vec<PathEvent>
+where
+    I: IntoIterator<Item = PathEvent>,
+{
+    use lyon::path::iterator::PathIterator;
+
+    let mut pattern = dashes.pattern.clone();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_slice(&dashes.pattern);
+    }
+    let flattened = events.into_iter().flattened(tolerance);
+    if !dashes.is_valid() {
+        return flattened.collect();
+    }
+    let total: f32 = pattern.iter().sum();
+
+    let mut dasher = Dasher {
+        pattern: &pattern,
+        events: vec![],
+        index: 0,
+        remaining: 0.0,
+        last: lyon::math::point(0.0, 0.0),
+        dash_start: lyon::math::point(0.0, 0.0),
+    };
+    let mut first = lyon::math::point(0.0, 0.0);
+    for event in flattened {
+        match event {
+            PathEvent::Begin { at } => {
+                first = at;
+                dasher.begin(at, dashes.offset.rem_euclid(total));
+            }
+            PathEvent::Line { to, .. } => dasher.line_to(to),
+            PathEvent::End { close, .. } => {
+                if close {
+                    dasher.line_to(first);
+                }
+                dasher.end();
+            }
+            // Curves are flattened above.
+            PathEvent::Quadratic { to, .. } | PathEvent::Cubic { to, .. } => dasher.line_to(to),
+        }
+    }
+    dasher.events
+}
+
+// State used while splitting a path into dashes.
+struct Dasher<'a> {
+    pattern: &'a [f32],
+    events: Vec<PathEvent>,
+    // The index of the current dash or gap within the pattern.
+    index: usize,
+    // The length remaining in the current dash or gap.
+    remaining: f32,
+    // The position of the pen.
+    last: lyon::math::Point,
+    // The start of the current dash.
+    dash_start: lyon::math::Point,
+}
+
+impl<'a> Dasher<'a> {
+    fn is_dash(&self) -> bool {
+        self.index % 2 == 0
+    }
+
+    // Begin a new sub-path at the given distance into the pattern.
+    fn begin(&mut self, at: lyon::math::Point, mut offset: f32) {
+        self.index = 0;
+        while offset >= self.pattern[self.index] {
+            offset -= self.pattern[self.index];
+            self.index = (self.index + 1) % self.pattern.len();
+        }
+        self.remaining = self.pattern[self.index] - offset;
+        self.last = at;
+        if self.is_dash() {
+            self.begin_dash();
+        }
+    }
+
+    fn begin_dash(&mut self) {
+        self.dash_start = self.last;
+        self.events.push(PathEvent::Begin { at: self.last });
+    }
+
+    fn end(&mut self) {
+        if self.is_dash() {
+            self.events.push(PathEvent::End {
+                last: self.last,
+                first: self.dash_start,
+                close: false,
+            });
+        }
+    }
+
+    fn line_to(&mut self, to: lyon::math::Point) {
+        let from = self.last;
+        let len = (to - from).length();
+        let mut travelled = 0.0;
+        while len - travelled > self.remaining {
+            travelled += self.remaining;
+            let p = from.lerp(to, travelled / len);
+            if self.is_dash() {
+                self.events.push(PathEvent::Line {
+                    from: self.last,
+                    to: p,
+                });
+                self.last = p;
+                self.end();
+            } else {
+                self.last = p;
+            }
+            self.index = (self.index + 1) % self.pattern.len();
+            self.remaining = self.pattern[self.index];
+            if self.is_dash() {
+                self.begin_dash();
+            }
+        }
+        self.remaining -= len - travelled;
+        if self.is_dash() {
+            self.events.push(PathEvent::Line {
+                from: self.last,
+                to,
+            });
+        }
+        self.last = to;
+    }
+}
+
+impl<I> Iterator for Dashed<I>
+where
+    I: Iterator<Item = PathEvent>,
+{
+    type Item = PathEvent;
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            Dashed::Solid(ref mut events) => events.next(),
+            Dashed::Dashed(ref mut events) => events.next(),
+        }
+    }
+}
+
+/// Tessellate the given path events, coloring each vertex by its position within the gradient.
+///
+/// The tessellated triangles are first split along each stop of a linear gradient, or subdivided
+/// for a radial gradient, so that the interpolated vertex colors closely follow the gradient.
+pub(crate) fn render_path_events_gradient<I>(
+    events: I,
+    gradient: &Gradient,
+    transform: Mat4,
+    options: Options,
+    fill_tessellator: &mut lyon::tessellation::FillTessellator,
+    stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    mesh: &mut draw::Mesh,
+) where
+    I: IntoIterator<Item = lyon::path::PathEvent>,
+{
+    // Tessellate into a local mesh first so that we have the untransformed positions.
+    let mut local = draw::Mesh::default();
+    let color = draw::mesh::vertex::DEFAULT_VERTEX_COLOR;
+    let identity = Mat4::IDENTITY;
+    let res = match options {
+        Options::Fill(options) => {
+            let mut mesh_builder =
+                draw::mesh::MeshBuilder::single_color(&mut local, identity, color);
+            fill_tessellator.tessellate(events, &options, &mut mesh_builder)
+        }
+        Options::Stroke(options) => {
+            let mut mesh_builder =
+                draw::mesh::MeshBuilder::single_color(&mut local, identity, color);
+            stroke_tessellator.tessellate(events, &options, &mut mesh_builder)
+        }
+    };
+    if let Err(err) = res {
+        eprintln!("failed to tessellate path: {:?}", err);
+        return;
+    }
+
+    let points = local.points();
+    for tri in local.indices().chunks_exact(3) {
+        let p = |i: usize| points[tri[i] as usize].truncate();
+        let tri = [p(0), p(1), p(2)];
+        match gradient.kind {
+            GradientKind::Linear { .. } => {
+                // Vertex colors are exact within each band between two stops.
+                let mut poly = tri.to_vec();
+                for &(stop, _) in &gradient.stops {
+                    let (below, above) = split_polygon(&poly, |p| gradient.offset(p) - stop);
+                    push_gradient_polygon(&below, gradient, transform, mesh);
+                    poly = above;
+                }
+                push_gradient_polygon(&poly, gradient, transform, mesh);
+            }
+            GradientKind::Radial { center, radius } => {
+                let n = radial_subdivisions(tri, center, radius, gradient, transform);
+                push_gradient_grid(tri, n, gradient, transform, mesh);
+            }
+        }
+    }
+}
+
+// The maximum number of times each edge of a triangle is subdivided for a radial gradient, as
+// well as the number of subdivisions per radius.
+const RADIAL_GRADIENT_SUBDIVISIONS: u32 = 32;
+
+// The smallest length in points of a subdivided edge once transformed to the output, below which
+// further subdivision of a radial gradient has no visible effect.
+const RADIAL_GRADIENT_MIN_EDGE: f32 = 4.0;
+
+// The number of times to subdivide each edge of the given triangle so that the interpolated
+// vertex colors closely follow the given radial gradient.
+//
+// Subdivision is limited by the size of the triangle on the output, and is skipped for triangles
+// that lie entirely beyond the last stop where the color is constant.
+fn radial_subdivisions(
+    tri: [Point2; 3],
+    center: Point2,
+    radius: f32,
+    gradient: &Gradient,
+    transform: Mat4,
+) -> u32 {
+    let last_stop = gradient.stops.last().map(|s| s.0).unwrap_or(1.0);
+    if radius <= 0.0 || distance_to_triangle(center, tri) >= radius * last_stop {
+        return 1;
+    }
+    let longest = |ps: [Point2; 3]| {
+        let [a, b, c] = ps;
+        (b - a).length().max((c - b).length()).max((a - c).length())
+    };
+    let out = |p: Point2| transform.transform_point3(p.extend(0.0)).truncate();
+    let local_n = longest(tri) / (radius / RADIAL_GRADIENT_SUBDIVISIONS as f32);
+    let output_n = longest([out(tri[0]), out(tri[1]), out(tri[2])]) / RADIAL_GRADIENT_MIN_EDGE;
+    (local_n.min(output_n).ceil() as u32).clamp(1, RADIAL_GRADIENT_SUBDIVISIONS)
+}
+
+// The distance from the given point to the nearest point within the given triangle.
+fn distance_to_triangle(p: Point2, tri: [Point2; 3]) -> f32 {
+    let [a, b, c] = tri;
+    let side = |u: Point2, v: Point2| (v - u).perp_dot(p - u);
+    let (ab, bc, ca) = (side(a, b), side(b, c), side(c, a));
+    let inside = (ab >= 0.0 && bc >= 0.0 && ca >= 0.0) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0);
+    if inside {
+        return 0.0;
+    }
+    let to_segment = |u: Point2, v: Point2| {
+        let uv = v - u;
+        let len_squared = uv.length_squared();
+        let t = match len_squared > 0.0 {
+            true => ((p - u).dot(uv) / len_squared).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        p.distance(u + uv * t)
+    };
+    to_segment(a, b).min(to_segment(b, c)).min(to_segment(c, a))
+}
+
+// Subdivide each edge of the given triangle `n` times, pushing the resulting grid of triangles
+// with shared vertices colored via the gradient.
+fn push_gradient_grid(
+    tri: [Point2; 3],
+    n: u32,
+    gradient: &Gradient,
+    transform: Mat4,
+    mesh: &mut draw::Mesh,
+) {
+    let [a, b, c] = tri;
+    let start = mesh.points().len() as u32;
+    for i in 0..=n {
+        for j in 0..=n - i {
+            let p = a + (b - a) * (i as f32 / n as f32) + (c - a) * (j as f32 / n as f32);
+            let point = transform.transform_point3(p.extend(0.0));
+            let color = gradient
+                .color(p)
+                .unwrap_or(draw::mesh::vertex::DEFAULT_VERTEX_COLOR);
+            let tex_coords = draw::mesh::vertex::default_tex_coords();
+            mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+        }
+    }
+    // The index of the vertex in row `i` and column `j`, where row `i` holds `n + 1 - i` vertices.
+    let ix = |i: u32, j: u32| start + i * (n + 1) - i * (i.saturating_sub(1)) / 2 + j;
+    for i in 0..n {
+        for j in 0..n - i {
+            mesh.extend_indices([ix(i, j), ix(i + 1, j), ix(i, j + 1)].iter().cloned());
+            if i + j + 1 < n {
+                let down = [ix(i + 1, j), ix(i + 1, j + 1), ix(i, j + 1)];
+                mesh.extend_indices(down.iter().cloned());
+            }
+        }
+    }
+}
+
+// Split the given convex polygon in two along the line where `f` is zero.
+//
+// Returns the part where `f` is negative followed by the part where `f` is positive.
+fn split_polygon<F>(poly: &[Point2], f: F) -> (Vec<Point2>, Vec<Point2>)
+where
+    F: Fn(Point2) -> f32,
+{
+    let (mut below, mut above) = (vec![], vec![]);
+    for (i, &p) in poly.iter().enumerate() {
+        let q = poly[(i + 1) % poly.len()];
+        let (dp, dq) = (f(p), f(q));
+        if dp <= 0.0 {
+            below.push(p);
+        }
+        if dp >= 0.0 {
+            above.push(p);
+        }
+        if (dp < 0.0 && dq > 0.0) || (dp > 0.0 && dq < 0.0) {
+            let x = p + (q - p) * (dp / (dp - dq));
+            below.push(x);
+            above.push(x);
+        }
+    }
+    (below, above)
+}
+
+// Triangulate the given convex polygon as a fan, coloring each vertex via the gradient.
+fn push_gradient_polygon(
+    poly: &[Point2],
+    gradient: &Gradient,
+    transform: Mat4,
+    mesh: &mut draw::Mesh,
+) {
+    if poly.len() < 3 {
+        return;
+    }
+    let start = mesh.points().len() as u32;
+    for &p in poly {
+        let point = transform.transform_point3(p.extend(0.0));
+        let color = gradient
+            .color(p)
+            .unwrap_or(draw::mesh::vertex::DEFAULT_VERTEX_COLOR);
+        let tex_coords = draw::mesh::vertex::default_tex_coords();
+        mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+    }
+    for i in 1..poly.len() as u32 - 1 {
+        mesh.extend_indices([start, start + i, start + i + 1].iter().cloned());
+    }
+}
+
 pub(crate) fn render_path_points_colored<I>(
     points_colored: I,
     close: bool,
@@ -463,6 +848,7 @@ pub(crate) fn render_path_source(
     // TODO:
     path_src: PathEventSourceIter,
     color: Option<LinSrgba>,
+    gradient: Option<&Gradient>,
     transform: Mat4,
     options: Options,
     theme: &draw::Theme,
@@ -472,17 +858,28 @@ pub(crate) fn render_path_source(
     mesh: &mut draw::Mesh,
 ) {
     match path_src {
-        PathEventSourceIter::Events(events) => render_path_events(
-            events,
-            color,
-            transform,
-            options,
-            theme,
-            theme_prim,
-            fill_tessellator,
-            stroke_tessellator,
-            mesh,
-        ),
+        PathEventSourceIter::Events(events) => match gradient {
+            Some(gradient) if !gradient.stops.is_empty() => render_path_events_gradient(
+                events,
+                gradient,
+                transform,
+                options,
+                fill_tessellator,
+                stroke_tessellator,
+                mesh,
+            ),
+            _ => render_path_events(
+                events,
+                color,
+                transform,
+                options,
+                theme,
+                theme_prim,
+                fill_tessellator,
+                stroke_tessellator,
+                mesh,
+            ),
+        },
         PathEventSourceIter::ColoredPoints { points, close } => render_path_points_colored(
             points,
             close,
@@ -504,6 +901,50 @@ pub(crate) fn render_path_source(
     }
 }
 
+/// Collect the path events described by the given source for SVG export.
+///
+/// SVG paths have a single color, so the colors of colored points are averaged.
+pub(crate) fn svg_path_source(
+    src: &PathEventSource,
+    ctxt: &draw::svg::SvgContext,
+) -> (Vec<PathEvent>, Option<LinSrgba>) {
+    fn polyline<I>(points: I, close: bool) -> Vec<PathEvent>
+    where
+        I: Iterator<Item = Point2>,
+    {
+        let points = points.map(|p| lyon::math::point(p.x, p.y));
+        lyon::path::iterator::FromPolyline::new(close, points).collect()
+    }
+    match *src {
+        PathEventSource::Buffered(ref range) => {
+            (ctxt.path_event_buffer[range.clone()].to_vec(), None)
+        }
+        PathEventSource::ColoredPoints { ref range, close } => {
+            let points = &ctxt.path_points_colored_buffer[range.clone()];
+            let events = polyline(points.iter().map(|&(p, _)| p), close);
+            let color = if points.is_empty() {
+                None
+            } else {
+                let n = points.len() as f32;
+                let (r, g, b, a) = points.iter().fold((0.0, 0.0, 0.0, 0.0), |acc, &(_, c)| {
+                    (
+                        acc.0 + c.red,
+                        acc.1 + c.green,
+                        acc.2 + c.blue,
+                        acc.3 + c.alpha,
+                    )
+                });
+                Some(LinSrgba::new(r / n, g / n, b / n, a / n))
+            };
+            (events, color)
+        }
+        PathEventSource::TexturedPoints { ref range, close } => {
+            let points = &ctxt.path_points_textured_buffer[range.clone()];
+            (polyline(points.iter().map(|&(p, _)| p), close), None)
+        }
+    }
+}
+
 impl draw::renderer::RenderPrimitive for Path {
     fn render_primitive(
         self,
@@ -512,6 +953,8 @@ impl draw::renderer::RenderPrimitive for Path {
     ) -> draw::renderer::PrimitiveRender {
         let Path {
             color,
+            gradient,
+            dashes,
             position,
             orientation,
             path_event_src,
@@ -526,7 +969,7 @@ impl draw::renderer::RenderPrimitive for Path {
         let transform = global_transform * local_transform;
 
         // A function for rendering the path.
-        let render =
+        let mut render =
             |src: PathEventSourceIter,
              theme: &draw::Theme,
              fill_tessellator: &mut lyon::tessellation::FillTessellator,
@@ -534,8 +977,9 @@ impl draw::renderer::RenderPrimitive for Path {
                 render_path_source(
                     src,
                     color,
+                    gradient.as_ref(),
                     transform,
-                    options,
+                    options.clone(),
                     theme,
                     &draw::theme::Primitive::Path,
                     fill_tessellator,
@@ -546,7 +990,8 @@ impl draw::renderer::RenderPrimitive for Path {
 
         match path_event_src {
             PathEventSource::Buffered(range) => {
-                let mut events = ctxt.path_event_buffer[range].iter().cloned();
+                let events = ctxt.path_event_buffer[range].iter().cloned();
+                let mut events = dashed(events, dashes.as_ref(), &options);
                 let src = PathEventSourceIter::Events(&mut events);
                 render(
                     src,
@@ -590,6 +1035,40 @@ impl draw::renderer::RenderPrimitive for Path {
     }
 }
 
+impl draw::svg::SvgPrimitive for Path {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Path {
+            color,
+            gradient,
+            dashes,
+            position,
+            orientation,
+            path_event_src,
+            options,
+            ..
+        } = self;
+        let local_transform = position.transform() * orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let (events, avg_color) = svg_path_source(&path_event_src, &ctxt);
+        let events: Vec<_> = dashed(events, dashes.as_ref(), &options).collect();
+        if let PathEventSource::Buffered(_) = path_event_src {
+            if let Some(gradient) = gradient.filter(|g| !g.stops.is_empty()) {
+                svg.path_gradient(events, &gradient, transform, &options);
+                return;
+            }
+        }
+        draw::svg::path_events(
+            svg,
+            events,
+            color.or(avg_color),
+            transform,
+            &options,
+            ctxt.theme,
+            &draw::theme::Primitive::Path,
+        );
+    }
+}
+
 /// Create a lyon path for the given iterator of colored points.
 pub fn points_colored_to_lyon_path<I>(points_colored: I, close: bool) -> Option<lyon::path::Path>
 where
@@ -656,6 +1135,8 @@ impl Path {
         position: position::Properties,
         orientation: orientation::Properties,
         color: Option<LinSrgba>,
+        gradient: Option<Gradient>,
+        dashes: Option<Dashes>,
         path_event_src: PathEventSource,
         options: Options,
         vertex_mode: draw::renderer::VertexMode,
@@ -663,6 +1144,8 @@ impl Path {
     ) -> Self {
         Path {
             color,
+            gradient,
+            dashes,
             orientation,
             position,
             path_event_src,
@@ -846,6 +1329,18 @@ impl<T> SetColor<ColorScalar> for PathOptions<T> {
     }
 }
 
+impl SetDashes for PathStroke {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.dashes)
+    }
+}
+
+impl<T> SetGradient for PathOptions<T> {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.gradient)
+    }
+}
+
 impl SetOrientation for Path {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.orientation)
@@ -864,6 +1359,18 @@ impl SetColor<ColorScalar> for Path {
     }
 }
 
+impl SetGradient for Path {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.gradient)
+    }
+}
+
+impl SetDashes for Path {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.dashes)
+    }
+}
+
 impl From<PathInit> for Primitive {
     fn from(prim: PathInit) -> Self {
         Primitive::PathInit(prim)
@@ -0,0 +1,110 @@
+use crate::draw::primitive::parametric::{self, ParametricOptions, SetParametric};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+
+/// Properties related to drawing a **Plane**.
+///
+/// The plane lies in the xy plane facing the positive z axis and may be subdivided into a grid of
+/// `segments` columns and `rings` rows, e.g. for displacement or per-vertex lighting.
+#[derive(Clone, Debug, Default)]
+pub struct Plane {
+    dimensions: dimension::Properties,
+    parametric: ParametricOptions,
+}
+
+/// The drawing context for a **Plane**.
+pub type DrawingPlane<'a> = Drawing<'a, Plane>;
+
+// Plane-specific methods.
+
+impl Plane {
+    /// Produce the surface of the plane, centred on the origin.
+    ///
+    /// Each vertex of the resulting mesh has a position, texture coordinates and a normal.
+    pub fn mesh(&self) -> parametric::Mesh {
+        let w = self.dimensions.x.unwrap_or(100.0);
+        let h = self.dimensions.y.unwrap_or(100.0);
+        let segments = self.parametric.segments_or(1);
+        let rings = self.parametric.rings_or(1);
+        let mut mesh = parametric::Mesh::default();
+        parametric::extend_grid(&mut mesh, segments, rings, |u, v| {
+            let point = Point3::new(w * (u - 0.5), h * (0.5 - v), 0.0);
+            (point, Vec3::Z)
+        });
+        mesh
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Plane {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Plane;
+        parametric::render_mesh(self.parametric, &surface, ctxt, &theme_prim, mesh)
+    }
+}
+
+impl draw::svg::SvgPrimitive for Plane {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Plane;
+        parametric::svg_mesh(self.parametric, &surface, ctxt, &theme_prim, svg);
+    }
+}
+
+impl SetOrientation for Plane {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.parametric)
+    }
+}
+
+impl SetPosition for Plane {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.parametric)
+    }
+}
+
+impl SetDimensions for Plane {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Plane {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.parametric)
+    }
+}
+
+impl SetParametric for Plane {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        SetParametric::parametric_options_mut(&mut self.parametric)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Plane> for Primitive {
+    fn from(prim: Plane) -> Self {
+        Primitive::Plane(prim)
+    }
+}
+
+impl Into<Option<Plane>> for Primitive {
+    fn into(self) -> Option<Plane> {
+        match self {
+            Primitive::Plane(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
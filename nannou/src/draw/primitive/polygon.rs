@@ -5,7 +5,8 @@ use crate::draw::primitive::path::{self, PathEventSource};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetGradient, SetOrientation,
+    SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::Point2;
@@ -57,7 +58,9 @@ pub struct PolygonOptions {
     pub no_fill: bool,
     pub stroke_color: Option<LinSrgba>,
     pub color: Option<LinSrgba>,
+    pub gradient: Option<Gradient>,
     pub stroke: Option<StrokeOptions>,
+    pub dashes: Option<Dashes>,
 }
 
 /// A polygon with vertices already submitted.
@@ -190,7 +193,9 @@ pub fn render_events_themed<F, I>(
         no_fill,
         stroke_color,
         color,
+        gradient,
         stroke,
+        dashes,
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -202,20 +207,32 @@ pub fn render_events_themed<F, I>(
     let mut render =
         |opts: path::Options,
          color: Option<LinSrgba>,
+         gradient: Option<&Gradient>,
          theme: &draw::Theme,
          fill_tessellator: &mut lyon::tessellation::FillTessellator,
          stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-            path::render_path_events(
-                events(),
-                color,
-                transform,
-                opts,
-                theme,
-                theme_primitive,
-                fill_tessellator,
-                stroke_tessellator,
-                mesh,
-            )
+            match gradient {
+                Some(gradient) if !gradient.stops.is_empty() => path::render_path_events_gradient(
+                    events(),
+                    gradient,
+                    transform,
+                    opts,
+                    fill_tessellator,
+                    stroke_tessellator,
+                    mesh,
+                ),
+                _ => path::render_path_events(
+                    path::dashed(events(), dashes.as_ref(), &opts),
+                    color,
+                    transform,
+                    opts,
+                    theme,
+                    theme_primitive,
+                    fill_tessellator,
+                    stroke_tessellator,
+                    mesh,
+                ),
+            }
         };
 
     // Do the fill tessellation first.
@@ -224,6 +241,7 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            gradient.as_ref(),
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
@@ -237,6 +255,7 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            None,
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
@@ -262,6 +281,81 @@ pub fn render_points_themed<I>(
     );
 }
 
+/// Export the given polygon outline to the SVG document, falling back to the theme colors.
+pub fn svg_events_themed<F, I>(
+    opts: PolygonOptions,
+    events: F,
+    ctxt: draw::svg::SvgContext,
+    theme_primitive: &draw::theme::Primitive,
+    svg: &mut draw::Svg,
+) where
+    F: Fn() -> I,
+    I: Iterator<Item = lyon::path::PathEvent>,
+{
+    let PolygonOptions {
+        position,
+        orientation,
+        no_fill,
+        stroke_color,
+        color,
+        gradient,
+        stroke,
+        dashes,
+    } = opts;
+    let local_transform = position.transform() * orientation.transform();
+    let transform = *ctxt.transform * local_transform;
+
+    if !no_fill {
+        let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
+        match gradient {
+            Some(ref gradient) if !gradient.stops.is_empty() => {
+                svg.path_gradient(events(), gradient, transform, &opts);
+            }
+            _ => draw::svg::path_events(
+                svg,
+                events(),
+                color,
+                transform,
+                &opts,
+                ctxt.theme,
+                theme_primitive,
+            ),
+        }
+    }
+
+    if let Some(stroke_opts) = stroke {
+        let opts = path::Options::Stroke(stroke_opts);
+        draw::svg::path_events(
+            svg,
+            path::dashed(events(), dashes.as_ref(), &opts),
+            stroke_color,
+            transform,
+            &opts,
+            ctxt.theme,
+            theme_primitive,
+        );
+    }
+}
+
+/// Export the polygon described by the given points to the SVG document.
+pub fn svg_points_themed<I>(
+    opts: PolygonOptions,
+    points: I,
+    ctxt: draw::svg::SvgContext,
+    theme_primitive: &draw::theme::Primitive,
+    svg: &mut draw::Svg,
+) where
+    I: Clone + Iterator<Item = Point2>,
+{
+    svg_events_themed(
+        opts,
+        || lyon::path::iterator::FromPolyline::closed(points.clone().map(|p| p.to_array().into())),
+        ctxt,
+        theme_primitive,
+        svg,
+    );
+}
+
 impl Polygon {
     pub(crate) fn render_themed(
         self,
@@ -278,7 +372,9 @@ impl Polygon {
                     no_fill,
                     stroke_color,
                     color,
+                    gradient,
                     stroke,
+                    dashes,
                 },
             texture_view,
         } = self;
@@ -303,12 +399,14 @@ impl Polygon {
             |src: path::PathEventSourceIter,
              opts: path::Options,
              color: Option<LinSrgba>,
+             gradient: Option<&Gradient>,
              theme: &draw::Theme,
              fill_tessellator: &mut lyon::tessellation::FillTessellator,
              stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
                 path::render_path_source(
                     src,
                     color,
+                    gradient,
                     transform,
                     opts,
                     theme,
@@ -330,6 +428,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -346,6 +445,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -362,6 +462,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -375,12 +476,14 @@ impl Polygon {
             let opts = path::Options::Stroke(stroke_opts);
             match path_event_src {
                 PathEventSource::Buffered(range) => {
-                    let mut events = path_event_buffer[range].iter().cloned();
+                    let events = path_event_buffer[range].iter().cloned();
+                    let mut events = path::dashed(events, dashes.as_ref(), &opts);
                     let src = path::PathEventSourceIter::Events(&mut events);
                     render(
                         src,
                         opts,
                         stroke_color,
+                        None,
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -401,6 +504,7 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        None,
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -416,6 +520,7 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        None,
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -444,6 +549,29 @@ impl draw::renderer::RenderPrimitive for Polygon {
     }
 }
 
+impl draw::svg::SvgPrimitive for Polygon {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Polygon {
+            path_event_src,
+            mut opts,
+            ..
+        } = self;
+        let (events, avg_color) = path::svg_path_source(&path_event_src, &ctxt);
+        opts.color = opts.color.or(avg_color);
+        // Gradients only apply to polygons described by path events or plain points.
+        if !matches!(path_event_src, PathEventSource::Buffered(_)) {
+            opts.gradient = None;
+        }
+        svg_events_themed(
+            opts,
+            || events.iter().cloned(),
+            ctxt,
+            &draw::theme::Primitive::Polygon,
+            svg,
+        );
+    }
+}
+
 impl<'a, T> Drawing<'a, T>
 where
     T: SetPolygon + Into<Primitive>,
@@ -528,6 +656,18 @@ impl SetPolygon for PolygonOptions {
     }
 }
 
+impl SetGradient for PolygonOptions {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.gradient)
+    }
+}
+
+impl SetDashes for PolygonOptions {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.dashes)
+    }
+}
+
 impl SetOrientation for PolygonInit {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.opts.orientation)
@@ -546,6 +686,18 @@ impl SetColor<ColorScalar> for PolygonInit {
     }
 }
 
+impl SetGradient for PolygonInit {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.opts.gradient)
+    }
+}
+
+impl SetDashes for PolygonInit {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.opts.dashes)
+    }
+}
+
 impl SetPolygon for PolygonInit {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.opts)
@@ -576,6 +728,18 @@ impl SetColor<ColorScalar> for Polygon {
     }
 }
 
+impl SetGradient for Polygon {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.opts.gradient)
+    }
+}
+
+impl SetDashes for Polygon {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.opts.dashes)
+    }
+}
+
 impl From<PolygonInit> for Primitive {
     fn from(prim: PolygonInit) -> Self {
         Primitive::PolygonInit(prim)
@@ -3,7 +3,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    spatial, ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    spatial, ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions,
+    SetGradient, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::{self, pt2, Point2};
@@ -54,29 +55,11 @@ impl draw::renderer::RenderPrimitive for Quad {
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
         let Quad {
-            mut quad,
+            quad,
             polygon,
             dimensions,
         } = self;
-
-        // If dimensions were specified, scale the points to those dimensions.
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        if maybe_x.is_some() || maybe_y.is_some() {
-            let cuboid = quad.bounding_rect();
-            let centroid = quad.centroid();
-            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
-            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
-            let scale = vec2(x_scale, y_scale);
-            let (a, b, c, d) = quad.into();
-            let translate = |v: Point2| centroid + ((v - centroid) * scale);
-            let new_a = translate(a);
-            let new_b = translate(b);
-            let new_c = translate(c);
-            let new_d = translate(d);
-            quad = geom::Quad([new_a, new_b, new_c, new_d]);
-        }
-
-        let points = quad.vertices();
+        let points = scale_to_dimensions(quad, &dimensions).vertices();
         polygon::render_points_themed(
             polygon.opts,
             points,
@@ -89,6 +72,43 @@ impl draw::renderer::RenderPrimitive for Quad {
     }
 }
 
+impl draw::svg::SvgPrimitive for Quad {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Quad {
+            quad,
+            polygon,
+            dimensions,
+        } = self;
+        let points = scale_to_dimensions(quad, &dimensions).vertices();
+        polygon::svg_points_themed(
+            polygon.opts,
+            points,
+            ctxt,
+            &draw::theme::Primitive::Quad,
+            svg,
+        );
+    }
+}
+
+// If dimensions were specified, scale the points to those dimensions.
+fn scale_to_dimensions(
+    quad: geom::Quad<Point2>,
+    dimensions: &dimension::Properties,
+) -> geom::Quad<Point2> {
+    let (maybe_x, maybe_y) = (dimensions.x, dimensions.y);
+    if maybe_x.is_none() && maybe_y.is_none() {
+        return quad;
+    }
+    let cuboid = quad.bounding_rect();
+    let centroid = quad.centroid();
+    let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
+    let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
+    let scale = vec2(x_scale, y_scale);
+    let (a, b, c, d) = quad.into();
+    let translate = |v: Point2| centroid + ((v - centroid) * scale);
+    geom::Quad([translate(a), translate(b), translate(c), translate(d)])
+}
+
 impl From<geom::Quad<Point2>> for Quad {
     fn from(quad: geom::Quad<Point2>) -> Self {
         let polygon = Default::default();
@@ -141,6 +161,18 @@ impl SetColor<ColorScalar> for Quad {
     }
 }
 
+impl SetGradient for Quad {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Quad {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetStroke for Quad {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
@@ -3,7 +3,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions, SetGradient,
+    SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom;
@@ -75,6 +76,26 @@ impl draw::renderer::RenderPrimitive for Rect {
     }
 }
 
+impl draw::svg::SvgPrimitive for Rect {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Rect {
+            polygon,
+            dimensions,
+        } = self;
+        let w = dimensions.x.unwrap_or(100.0);
+        let h = dimensions.y.unwrap_or(100.0);
+        let rect = geom::Rect::from_wh([w, h].into());
+        let points = rect.corners().vertices().map(Vec2::from);
+        polygon::svg_points_themed(
+            polygon.opts,
+            points,
+            ctxt,
+            &draw::theme::Primitive::Rect,
+            svg,
+        );
+    }
+}
+
 impl From<geom::Rect<f32>> for Rect {
     fn from(r: geom::Rect<f32>) -> Self {
         let (x, y, w, h) = r.x_y_w_h();
@@ -117,6 +138,18 @@ impl SetColor<ColorScalar> for Rect {
     }
 }
 
+impl SetGradient for Rect {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Rect {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetStroke for Rect {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.polygon)
@@ -0,0 +1,131 @@
+use crate::draw::primitive::parametric::{self, ParametricOptions, SetParametric};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+
+/// Properties related to drawing a **Sphere**.
+///
+/// The sphere is tessellated as a UV sphere, where `segments` describes the number of
+/// subdivisions around the vertical axis and `rings` describes the number of subdivisions from
+/// pole to pole. Specifying unequal dimensions produces an ellipsoid.
+#[derive(Clone, Debug, Default)]
+pub struct Sphere {
+    dimensions: dimension::Properties,
+    parametric: ParametricOptions,
+}
+
+/// The drawing context for a **Sphere**.
+pub type DrawingSphere<'a> = Drawing<'a, Sphere>;
+
+// Sphere-specific methods.
+
+impl Sphere {
+    /// Specify the width, height and depth of the **Sphere** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h_d(side, side, side)
+    }
+
+    /// Produce the surface of the sphere, centred on the origin.
+    ///
+    /// Each vertex of the resulting mesh has a position, texture coordinates and a normal.
+    pub fn mesh(&self) -> parametric::Mesh {
+        let a = self.dimensions.x.unwrap_or(100.0) * 0.5;
+        let b = self.dimensions.y.unwrap_or(100.0) * 0.5;
+        let c = self.dimensions.z.unwrap_or(100.0) * 0.5;
+        let segments = self.parametric.segments_or(32);
+        let rings = self.parametric.rings_or(16);
+        let mut mesh = parametric::Mesh::default();
+        parametric::extend_grid(&mut mesh, segments, rings, |u, v| {
+            let theta = u * std::f32::consts::PI * 2.0;
+            let phi = v * std::f32::consts::PI;
+            let (x, y, z) = (phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin());
+            let point = Point3::new(x * a, y * b, z * c);
+            let normal = Vec3::new(x * b * c, y * a * c, z * a * b).normalize_or_zero();
+            (point, normal)
+        });
+        mesh
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingSphere<'a> {
+    /// Specify the width, height and depth of the **Sphere** via a given **radius**.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Sphere {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Sphere;
+        parametric::render_mesh(self.parametric, &surface, ctxt, &theme_prim, mesh)
+    }
+}
+
+impl draw::svg::SvgPrimitive for Sphere {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Sphere;
+        parametric::svg_mesh(self.parametric, &surface, ctxt, &theme_prim, svg);
+    }
+}
+
+impl SetOrientation for Sphere {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.parametric)
+    }
+}
+
+impl SetPosition for Sphere {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.parametric)
+    }
+}
+
+impl SetDimensions for Sphere {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Sphere {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.parametric)
+    }
+}
+
+impl SetParametric for Sphere {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        SetParametric::parametric_options_mut(&mut self.parametric)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Sphere> for Primitive {
+    fn from(prim: Sphere) -> Self {
+        Primitive::Sphere(prim)
+    }
+}
+
+impl Into<Option<Sphere>> for Primitive {
+    fn into(self) -> Option<Sphere> {
+        match self {
+            Primitive::Sphere(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
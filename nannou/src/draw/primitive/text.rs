@@ -7,6 +7,7 @@ use crate::draw::properties::{
 };
 use crate::draw::{self, theme, Drawing};
 use crate::geom::{self, Point2};
+use crate::glam::Mat4;
 use crate::text::{self, Align, Font, FontSize, Justify, Layout, Scalar, Wrap};
 
 /// Properties related to drawing the **Text** primitive.
@@ -24,6 +25,10 @@ pub struct Style {
     pub color: Option<LinSrgba>,
     pub glyph_colors: Vec<LinSrgba>, // Overrides `color` if non-empty.
     pub layout: text::layout::Builder,
+    pub spans: Vec<text::Span>,
+    // Lays the text out along the path if `Some`.
+    pub path: Option<lyon::path::Path>,
+    pub path_offset: Scalar,
 }
 
 /// The drawing context for the **Text** primitive.
@@ -92,6 +97,19 @@ impl Text {
         self.map_layout(|l| l.font(font))
     }
 
+    /// Specify the fonts used, in order, to display characters that are missing from the `font`.
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        self.map_layout(|l| l.fallback_fonts(fonts))
+    }
+
+    /// Append a font to the end of the fallback chain.
+    pub fn fallback_font(self, font: Font) -> Self {
+        self.map_layout(|l| l.fallback_font(font))
+    }
+
     /// Describe the end along the *x* axis to which the text should be aligned.
     pub fn justify(self, justify: Justify) -> Self {
         self.map_layout(|l| l.justify(justify))
@@ -156,6 +174,33 @@ impl Text {
         self.style.glyph_colors = colors;
         self
     }
+
+    /// Apply the given style to the given range of bytes within the text.
+    pub fn span(mut self, range: std::ops::Range<usize>, style: text::span::Style) -> Self {
+        self.style.spans.push(text::Span::new(range, style));
+        self
+    }
+
+    /// Apply each of the given spans to the text.
+    pub fn spans<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = text::Span>,
+    {
+        self.style.spans.extend(spans);
+        self
+    }
+
+    /// Lay out the text along the given path.
+    pub fn along_path(mut self, path: &geom::Path) -> Self {
+        self.style.path = Some(path.iter().collect());
+        self
+    }
+
+    /// Shift text laid out along a path by the given distance along the path.
+    pub fn path_offset(mut self, offset: Scalar) -> Self {
+        self.style.path_offset = offset;
+        self
+    }
 }
 
 impl<'a> DrawingText<'a> {
@@ -184,6 +229,19 @@ impl<'a> DrawingText<'a> {
         self.map_ty(|ty| ty.font(font))
     }
 
+    /// Specify the fonts used, in order, to display characters that are missing from the `font`.
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = text::Font>,
+    {
+        self.map_ty(|ty| ty.fallback_fonts(fonts))
+    }
+
+    /// Append a font to the end of the fallback chain.
+    pub fn fallback_font(self, font: text::Font) -> Self {
+        self.map_ty(|ty| ty.fallback_font(font))
+    }
+
     /// Build the **Text** with the given **Style**.
     pub fn with_style(self, style: Style) -> Self {
         self.map_ty(|ty| ty.with_style(style))
@@ -257,6 +315,42 @@ impl<'a> DrawingText<'a> {
 
         self.map_ty(|ty| ty.glyph_colors(glyph_colors))
     }
+
+    /// Apply the given style to the given range of bytes within the text.
+    ///
+    /// Properties that the style leaves unspecified fall back to those of the drawing. Where spans
+    /// overlap, the properties of later spans take precedence. Colors specified via
+    /// `glyph_colors` take precedence over span colors.
+    pub fn span(self, range: std::ops::Range<usize>, style: text::span::Style) -> Self {
+        self.map_ty(|ty| ty.span(range, style))
+    }
+
+    /// Apply each of the given spans to the text.
+    ///
+    /// See the `span` method for details.
+    pub fn spans<I>(self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = text::Span>,
+    {
+        self.map_ty(|ty| ty.spans(spans))
+    }
+
+    /// Lay out the text along the given path, rotating each glyph to follow the path's tangent.
+    ///
+    /// Each line is aligned along the path according to the text's justification and subsequent
+    /// lines are stacked beneath the path. Lines are still wrapped to the drawing's width, so
+    /// `no_line_wrap` is often desirable. The path is relative to the drawing's position and
+    /// orientation. Underlines and strikethroughs are not drawn along paths.
+    pub fn along_path(self, path: &geom::Path) -> Self {
+        self.map_ty(|ty| ty.along_path(path))
+    }
+
+    /// Shift text laid out along a path by the given distance along the path.
+    ///
+    /// Animating the offset scrolls the text along the path. See `along_path` for details.
+    pub fn path_offset(self, offset: text::Scalar) -> Self {
+        self.map_ty(|ty| ty.path_offset(offset))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Text {
@@ -274,6 +368,9 @@ impl draw::renderer::RenderPrimitive for Text {
             color,
             glyph_colors,
             layout,
+            spans,
+            path,
+            path_offset,
         } = style;
         let layout = layout.build();
         let (maybe_x, maybe_y, maybe_z) = (
@@ -291,18 +388,118 @@ impl draw::renderer::RenderPrimitive for Text {
         let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
 
         let text_str = &ctxt.text_buffer[text.clone()];
-        let text = text::text(text_str).layout(&layout).build(rect);
-
-        // Queue the glyphs to be cached
-        let font_id = text::font::id(text.font());
-        let positioned_glyphs: Vec<_> = text
-            .rt_glyphs(
-                ctxt.output_attachment_size,
-                ctxt.output_attachment_scale_factor,
-            )
-            .collect();
-        for glyph in positioned_glyphs.iter() {
-            ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
+        let text = text::text(text_str)
+            .layout(&layout)
+            .spans(spans)
+            .build(rect);
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = spatial.position.transform() * spatial.orientation.transform();
+        let transform = global_transform * local_transform;
+
+        // Glyph colors take precedence over span colors, which take precedence over `color`.
+        let glyph_color = |run: &text::Run, shaped: &text::shape::Glyph| {
+            glyph_colors
+                .get(shaped.char)
+                .cloned()
+                .or(run.color)
+                .unwrap_or(color)
+        };
+
+        // Distance field glyphs are independent of the output resolution, so rather than being
+        // snapped to output pixels, they are positioned by their origin within the text.
+        if let draw::renderer::GlyphCacheMode::Sdf = ctxt.glyph_cache.mode {
+            let mut glyphs = vec![];
+            match path {
+                None => {
+                    for run in text.runs() {
+                        for shaped in run.glyphs.iter() {
+                            let x = run.rect.left() + shaped.position.x;
+                            let y = run.rect.bottom() + shaped.position.y;
+                            let origin = Mat4::from_translation([x, y, 0.0].into());
+                            glyphs.push((run, shaped, origin));
+                        }
+                    }
+                }
+                Some(ref path) => {
+                    for placement in text.glyphs_along_path(path, path_offset) {
+                        glyphs.push((placement.run, placement.glyph, placement.transform()));
+                    }
+                }
+            }
+            let ids: Vec<_> = glyphs
+                .iter()
+                .map(|&(run, shaped, _)| (&run.font, shaped.id))
+                .collect();
+            let entries = match ctxt.glyph_cache.sdf_entries(&ids) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    eprintln!("failed to cache glyph distance fields: {}", err);
+                    vec![]
+                }
+            };
+            for ((run, shaped, g_transform), entry) in glyphs.into_iter().zip(entries) {
+                let entry = match entry {
+                    Some(entry) => entry,
+                    None => continue,
+                };
+                let scale = text::pt_to_px(run.font_size) / text::sdf::SIZE;
+                let rect = geom::Rect::from_corners(
+                    entry.bounds.bottom_left() * scale,
+                    entry.bounds.top_right() * scale,
+                );
+                let uv_bl = [entry.uv_rect.min.x, entry.uv_rect.max.y].into();
+                let uv_tr = [entry.uv_rect.max.x, entry.uv_rect.min.y].into();
+                let g_color = glyph_color(run, shaped);
+                push_quad(mesh, transform * g_transform, rect, g_color, uv_bl, uv_tr);
+            }
+            if path.is_none() {
+                push_decorations(mesh, transform, &text, color);
+            }
+            return draw::renderer::PrimitiveRender::sdf_text();
+        }
+
+        // Queue the glyphs of each run to be cached, along with their font, color and the
+        // transform applied to their quad.
+        let mut positioned_glyphs = vec![];
+        match path {
+            None => {
+                for run in text.runs() {
+                    let font_id = text::font::id(&run.font);
+                    let glyphs = run.rt_glyphs(
+                        ctxt.output_attachment_size,
+                        ctxt.output_attachment_scale_factor,
+                    );
+                    for (shaped, glyph) in glyphs {
+                        ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
+                        let g_color = glyph_color(run, shaped);
+                        positioned_glyphs.push((font_id, glyph, g_color, Mat4::IDENTITY));
+                    }
+                }
+            }
+            // Glyphs laid out along a path are cached upright at the origin. Their quads are then
+            // moved into place and rotated.
+            Some(ref path) => {
+                let scale_factor = ctxt.output_attachment_scale_factor;
+                let origin = text::rt::point(
+                    ctxt.output_attachment_size.x / 2.0 * scale_factor,
+                    ctxt.output_attachment_size.y / 2.0 * scale_factor,
+                );
+                for placement in text.glyphs_along_path(path, path_offset) {
+                    let run = placement.run;
+                    let font_id = text::font::id(&run.font);
+                    let scale = text::f32_pt_to_scale(run.font_size as f32 * scale_factor);
+                    let glyph = run
+                        .font
+                        .glyph(placement.glyph.id)
+                        .scaled(scale)
+                        .positioned(origin);
+                    ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
+                    let g_color = glyph_color(run, placement.glyph);
+                    positioned_glyphs.push((font_id, glyph, g_color, placement.transform()));
+                }
+            }
         }
 
         // Cache the enqueued glyphs within the pixel buffer.
@@ -340,11 +537,6 @@ impl draw::renderer::RenderPrimitive for Text {
             }
         }
 
-        // Determine the transform to apply to all points.
-        let global_transform = *ctxt.transform;
-        let local_transform = spatial.position.transform() * spatial.orientation.transform();
-        let transform = global_transform * local_transform;
-
         // A function for converting RustType rects to nannou rects.
         let scale_factor = ctxt.output_attachment_scale_factor;
         let (out_w, out_h) = ctxt.output_attachment_size.into();
@@ -357,62 +549,154 @@ impl draw::renderer::RenderPrimitive for Text {
             geom::Rect::from_corners([l, b].into(), [r, t].into())
         };
 
-        // Skips non-rendered colors (e.g. due to line breaks),
-        //   assuming LineInfos are ordered by ascending character position.
-        let glyph_colors_iter = text
-            .line_infos()
-            .iter()
-            .flat_map(|li| li.char_range())
-            .take_while(|&i| i < glyph_colors.len())
-            .map(|i| &glyph_colors[i])
-            // Repeat `color` if more glyphs than glyph_colors
-            .chain(std::iter::repeat(&color));
-
         // Extend the mesh with a rect for each displayed glyph.
-        for (g, g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
-            if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), &g)
+        for (font_id, g, g_color, g_transform) in positioned_glyphs.iter() {
+            if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), g)
             {
                 let rect = to_nannou_rect(screen_rect);
+                let uv_bl = [uv_rect.min.x, uv_rect.max.y].into();
+                let uv_tr = [uv_rect.max.x, uv_rect.min.y].into();
+                let transform = transform * *g_transform;
+                push_quad(mesh, transform, rect, *g_color, uv_bl, uv_tr);
+            }
+        }
 
-                // Create a mesh-compatible vertex from the position and tex_coords.
-                let v = |p: Point2, tex_coords: [f32; 2]| -> draw::mesh::Vertex {
-                    let p = transform.transform_point3([p.x, p.y, 0.0].into());
-                    let point = draw::mesh::vertex::Point::from(p);
-                    draw::mesh::vertex::new(point, g_color.to_owned(), tex_coords.into())
-                };
+        if path.is_none() {
+            push_decorations(mesh, transform, &text, color);
+        }
+
+        draw::renderer::PrimitiveRender::text()
+    }
+}
 
-                // The sides of the UV rect.
-                let uv_l = uv_rect.min.x;
-                let uv_t = uv_rect.min.y;
-                let uv_r = uv_rect.max.x;
-                let uv_b = uv_rect.max.y;
-
-                // Insert the vertices.
-                let bottom_left = v(rect.bottom_left(), [uv_l, uv_b]);
-                let bottom_right = v(rect.bottom_right(), [uv_r, uv_b]);
-                let top_left = v(rect.top_left(), [uv_l, uv_t]);
-                let top_right = v(rect.top_right(), [uv_r, uv_t]);
-                let start_ix = mesh.points().len() as u32;
-                mesh.push_vertex(top_left);
-                mesh.push_vertex(bottom_left);
-                mesh.push_vertex(bottom_right);
-                mesh.push_vertex(top_right);
-
-                // Now the indices.
-                let tl_ix = start_ix;
-                let bl_ix = start_ix + 1;
-                let br_ix = start_ix + 2;
-                let tr_ix = start_ix + 3;
-                mesh.push_index(tl_ix);
-                mesh.push_index(bl_ix);
-                mesh.push_index(br_ix);
-                mesh.push_index(tl_ix);
-                mesh.push_index(br_ix);
-                mesh.push_index(tr_ix);
+// Extend the mesh with a solid rect for each underline and strikethrough.
+//
+// Negative texture coordinates indicate to the shader that the glyph cache should be ignored.
+fn push_decorations(mesh: &mut draw::Mesh, transform: Mat4, text: &text::Text, color: LinSrgba) {
+    let solid_uv = Point2::new(-1.0, -1.0);
+    for (run, rect) in text.decorations() {
+        let d_color = run.color.unwrap_or(color);
+        push_quad(mesh, transform, rect, d_color, solid_uv, solid_uv);
+    }
+}
+
+// Extend the mesh with a quad covering the given rect.
+//
+// The given texture coordinates map to the bottom left and top right corners of the rect.
+fn push_quad(
+    mesh: &mut draw::Mesh,
+    transform: Mat4,
+    rect: geom::Rect,
+    color: LinSrgba,
+    uv_bl: Point2,
+    uv_tr: Point2,
+) {
+    // Create a mesh-compatible vertex from the position and tex_coords.
+    let v = |p: Point2, tex_coords: Point2| -> draw::mesh::Vertex {
+        let p = transform.transform_point3([p.x, p.y, 0.0].into());
+        let point = draw::mesh::vertex::Point::from(p);
+        draw::mesh::vertex::new(point, color, tex_coords.into())
+    };
+
+    // Insert the vertices.
+    let bottom_left = v(rect.bottom_left(), uv_bl);
+    let bottom_right = v(rect.bottom_right(), [uv_tr.x, uv_bl.y].into());
+    let top_left = v(rect.top_left(), [uv_bl.x, uv_tr.y].into());
+    let top_right = v(rect.top_right(), uv_tr);
+    let start_ix = mesh.points().len() as u32;
+    mesh.push_vertex(top_left);
+    mesh.push_vertex(bottom_left);
+    mesh.push_vertex(bottom_right);
+    mesh.push_vertex(top_right);
+
+    // Now the indices.
+    let tl_ix = start_ix;
+    let bl_ix = start_ix + 1;
+    let br_ix = start_ix + 2;
+    let tr_ix = start_ix + 3;
+    mesh.push_index(tl_ix);
+    mesh.push_index(bl_ix);
+    mesh.push_index(br_ix);
+    mesh.push_index(tl_ix);
+    mesh.push_index(br_ix);
+    mesh.push_index(tr_ix);
+}
+
+impl draw::svg::SvgPrimitive for Text {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let Text {
+            spatial,
+            style,
+            text,
+        } = self;
+        let Style {
+            color,
+            glyph_colors,
+            layout,
+            spans,
+            path,
+            path_offset,
+        } = style;
+        let layout = layout.build();
+        let w = spatial.dimensions.x.unwrap_or(200.0);
+        let h = spatial.dimensions.y.unwrap_or(200.0);
+        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
+        let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
+        let text_str = &ctxt.text_buffer[text];
+        let text = text::text(text_str)
+            .layout(&layout)
+            .spans(spans)
+            .build(rect);
+
+        let local_transform = spatial.position.transform() * spatial.orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+
+        // Glyphs are exported as filled outlines so that the result does not depend on the fonts
+        // installed on the viewing machine.
+        let opts = draw::primitive::path::Options::Fill(Default::default());
+        let glyph_color = |run: &text::Run, shaped: &text::shape::Glyph| {
+            glyph_colors
+                .get(shaped.char)
+                .cloned()
+                .or(run.color)
+                .unwrap_or(color)
+        };
+        match path {
+            None => {
+                for run in text.runs() {
+                    for shaped in run.glyphs.iter() {
+                        let (glyph, glyph_rect) = run.glyph_rect(shaped);
+                        let events = match text::glyph::path_events(glyph) {
+                            None => continue,
+                            Some(events) => events,
+                        };
+                        let g_color = glyph_color(run, shaped);
+                        let offset = Mat4::from_translation(glyph_rect.bottom_left().extend(0.0));
+                        svg.path(events, g_color, transform * offset, &opts);
+                    }
+                }
+            }
+            Some(ref path) => {
+                for placement in text.glyphs_along_path(path, path_offset) {
+                    let (glyph, _) = placement.run.glyph_rect(placement.glyph);
+                    let events = match text::glyph::path_events(glyph) {
+                        None => continue,
+                        Some(events) => events,
+                    };
+                    let g_color = glyph_color(placement.run, placement.glyph);
+                    svg.path(events, g_color, transform * placement.transform(), &opts);
+                }
             }
         }
 
-        draw::renderer::PrimitiveRender::text()
+        // Underlines and strikethroughs are exported as filled rects.
+        let decorations = text.decorations().filter(|_| path.is_none());
+        for (run, rect) in decorations {
+            let d_color = run.color.unwrap_or(color);
+            let points = rect.corners_iter().map(|[x, y]| lyon::math::point(x, y));
+            let events = lyon::path::iterator::FromPolyline::closed(points);
+            svg.path(events, d_color, transform, &opts);
+        }
     }
 }
 
@@ -0,0 +1,134 @@
+use crate::draw::primitive::parametric::{self, ParametricOptions, SetParametric};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+
+/// Properties related to drawing a **Torus**.
+///
+/// The torus lies in the xz plane, surrounding the y axis. `segments` describes the number of
+/// subdivisions around the y axis and `rings` describes the number of subdivisions around the
+/// tube. The width and depth describe the outer diameter of the torus while the height describes
+/// the diameter of the tube.
+#[derive(Clone, Debug, Default)]
+pub struct Torus {
+    dimensions: dimension::Properties,
+    parametric: ParametricOptions,
+}
+
+/// The drawing context for a **Torus**.
+pub type DrawingTorus<'a> = Drawing<'a, Torus>;
+
+// Torus-specific methods.
+
+impl Torus {
+    /// Specify the dimensions of the **Torus** via the radius from its centre to the centre of
+    /// the tube and the radius of the tube itself.
+    pub fn radii(self, radius: f32, tube_radius: f32) -> Self {
+        let side = (radius + tube_radius) * 2.0;
+        self.w_h_d(side, tube_radius * 2.0, side)
+    }
+
+    /// Produce the surface of the torus, centred on the origin.
+    ///
+    /// Each vertex of the resulting mesh has a position, texture coordinates and a normal.
+    pub fn mesh(&self) -> parametric::Mesh {
+        let tube = self.dimensions.y.unwrap_or(30.0) * 0.5;
+        let rx = self.dimensions.x.unwrap_or(100.0) * 0.5 - tube;
+        let rz = self.dimensions.z.unwrap_or(100.0) * 0.5 - tube;
+        let segments = self.parametric.segments_or(32);
+        let rings = self.parametric.rings_or(16);
+        let mut mesh = parametric::Mesh::default();
+        parametric::extend_grid(&mut mesh, segments, rings, |u, v| {
+            let theta = u * std::f32::consts::PI * 2.0;
+            let phi = v * std::f32::consts::PI * 2.0;
+            let (cos, sin) = (theta.cos(), theta.sin());
+            let normal = Vec3::new(cos * phi.cos(), phi.sin(), sin * phi.cos());
+            let centre = Point3::new(cos * rx, 0.0, sin * rz);
+            (centre + normal * tube, normal)
+        });
+        mesh
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingTorus<'a> {
+    /// Specify the dimensions of the **Torus** via the radius from its centre to the centre of
+    /// the tube and the radius of the tube itself.
+    pub fn radii(self, radius: f32, tube_radius: f32) -> Self {
+        self.map_ty(|ty| ty.radii(radius, tube_radius))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Torus {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Torus;
+        parametric::render_mesh(self.parametric, &surface, ctxt, &theme_prim, mesh)
+    }
+}
+
+impl draw::svg::SvgPrimitive for Torus {
+    fn svg_primitive(self, ctxt: draw::svg::SvgContext, svg: &mut draw::Svg) {
+        let surface = self.mesh();
+        let theme_prim = draw::theme::Primitive::Torus;
+        parametric::svg_mesh(self.parametric, &surface, ctxt, &theme_prim, svg);
+    }
+}
+
+impl SetOrientation for Torus {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.parametric)
+    }
+}
+
+impl SetPosition for Torus {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.parametric)
+    }
+}
+
+impl SetDimensions for Torus {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Torus {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.parametric)
+    }
+}
+
+impl SetParametric for Torus {
+    fn parametric_options_mut(&mut self) -> &mut ParametricOptions {
+        SetParametric::parametric_options_mut(&mut self.parametric)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Torus> for Primitive {
+    fn from(prim: Torus) -> Self {
+        Primitive::Torus(prim)
+    }
+}
+
+impl Into<Option<Torus>> for Primitive {
+    fn into(self) -> Option<Torus> {
+        match self {
+            Primitive::Torus(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
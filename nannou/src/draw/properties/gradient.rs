@@ -0,0 +1,161 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::properties::{ColorScalar, LinSrgba};
+use crate::geom::Point2;
+
+/// Nodes that support filling with a gradient.
+///
+/// Gradients are described within the local coordinate space of the primitive, i.e. relative to
+/// its position and orientation.
+pub trait SetGradient: Sized {
+    /// Provide a mutable reference to the gradient field.
+    fn gradient_mut(&mut self) -> &mut Option<Gradient>;
+
+    /// Fill with the given gradient rather than a single color.
+    fn gradient(mut self, gradient: Gradient) -> Self {
+        *self.gradient_mut() = Some(gradient);
+        self
+    }
+
+    /// Fill with a gradient that varies along the line from `start` to `end`.
+    ///
+    /// Each stop is a tuple of an offset along the line in the range `0.0..=1.0` and a color.
+    fn gradient_linear<I, C>(self, start: Point2, end: Point2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.gradient(Gradient::linear(start, end, stops))
+    }
+
+    /// Fill with a gradient that varies with the distance from `center`.
+    ///
+    /// Each stop is a tuple of an offset from the centre to the given `radius` in the range
+    /// `0.0..=1.0` and a color.
+    fn gradient_radial<I, C>(self, center: Point2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.gradient(Gradient::radial(center, radius, stops))
+    }
+}
+
+/// A gradient used to color the vertices of a tessellated primitive by their position.
+///
+/// Colors are interpolated between stops in linear space. Points that lie before the first stop
+/// or after the last stop take the color of the nearest stop.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    /// The shape of the gradient.
+    pub kind: GradientKind,
+    /// The offset and color of each stop, sorted by offset.
+    pub stops: Vec<(f32, LinSrgba)>,
+}
+
+/// The shape of a **Gradient**.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// Colors vary along the line from `start` to `end`.
+    Linear { start: Point2, end: Point2 },
+    /// Colors vary with the distance from `center`, reaching the last stop at `radius`.
+    Radial { center: Point2, radius: f32 },
+}
+
+impl Gradient {
+    /// A gradient that varies along the line from `start` to `end`.
+    pub fn linear<I, C>(start: Point2, end: Point2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        Self::new(GradientKind::Linear { start, end }, stops)
+    }
+
+    /// A gradient that varies with the distance from `center`.
+    pub fn radial<I, C>(center: Point2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        Self::new(GradientKind::Radial { center, radius }, stops)
+    }
+
+    fn new<I, C>(kind: GradientKind, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let mut stops: Vec<_> = stops
+            .into_iter()
+            .map(|(offset, color)| (offset.clamp(0.0, 1.0), color.into_lin_srgba()))
+            .collect();
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("gradient stop offset was NaN"));
+        Gradient { kind, stops }
+    }
+
+    /// The offset of the given point along the gradient.
+    ///
+    /// Offsets of `0.0` and `1.0` correspond to the start and end of the gradient respectively.
+    pub fn offset(&self, point: Point2) -> f32 {
+        match self.kind {
+            GradientKind::Linear { start, end } => {
+                let dir = end - start;
+                let len_squared = dir.length_squared();
+                if len_squared == 0.0 {
+                    return 0.0;
+                }
+                (point - start).dot(dir) / len_squared
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius <= 0.0 {
+                    return 1.0;
+                }
+                (point - center).length() / radius
+            }
+        }
+    }
+
+    /// The color of the gradient at the given offset.
+    ///
+    /// Returns `None` if the gradient has no stops.
+    pub fn color_at_offset(&self, offset: f32) -> Option<LinSrgba> {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return None,
+        };
+        if offset <= first.0 {
+            return Some(first.1);
+        }
+        if offset >= last.0 {
+            return Some(last.1);
+        }
+        // The first stop is always before `offset` and the last is always after.
+        let ix = self
+            .stops
+            .iter()
+            .position(|s| s.0 > offset)
+            .unwrap_or(self.stops.len() - 1);
+        let (a, b) = (self.stops[ix - 1], self.stops[ix]);
+        let t = (offset - a.0) / (b.0 - a.0);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Some(LinSrgba::new(
+            lerp(a.1.red, b.1.red),
+            lerp(a.1.green, b.1.green),
+            lerp(a.1.blue, b.1.blue),
+            lerp(a.1.alpha, b.1.alpha),
+        ))
+    }
+
+    /// The color of the gradient at the given point.
+    ///
+    /// Returns `None` if the gradient has no stops.
+    pub fn color(&self, point: Point2) -> Option<LinSrgba> {
+        self.color_at_offset(self.offset(point))
+    }
+}
+
+impl SetGradient for Option<Gradient> {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        self
+    }
+}
@@ -156,3 +156,54 @@ impl SetStroke for Option<StrokeOptions> {
         self.get_or_insert_with(Default::default)
     }
 }
+
+/// Nodes whose strokes may be split into a repeating pattern of dashes.
+pub trait SetDashes: Sized {
+    /// Provide a mutable reference to the dashes field.
+    fn dashes_mut(&mut self) -> &mut Option<Dashes>;
+
+    /// Split the stroke into dashes following the given pattern.
+    ///
+    /// The pattern alternates between the length of a dash and the length of the gap that
+    /// follows it, e.g. `&[10.0, 5.0]`. As with SVG's `stroke-dasharray`, a pattern with an odd
+    /// number of lengths is repeated to yield an even number of lengths.
+    fn dashes(mut self, pattern: &[f32]) -> Self {
+        self.dashes_mut()
+            .get_or_insert_with(Default::default)
+            .pattern = pattern.to_vec();
+        self
+    }
+
+    /// The distance into the dash pattern at which the stroke begins.
+    fn dash_offset(mut self, offset: f32) -> Self {
+        self.dashes_mut()
+            .get_or_insert_with(Default::default)
+            .offset = offset;
+        self
+    }
+}
+
+/// A repeating pattern of dashes used to split a stroke.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dashes {
+    /// Alternating dash and gap lengths.
+    pub pattern: Vec<f32>,
+    /// The distance into the pattern at which the stroke begins.
+    pub offset: f32,
+}
+
+impl Dashes {
+    /// Whether or not the pattern describes any dashes.
+    ///
+    /// Patterns that are empty, contain negative lengths or sum to zero are ignored, in which case
+    /// the stroke is drawn solid.
+    pub fn is_valid(&self) -> bool {
+        !self.pattern.iter().any(|&len| len < 0.0) && self.pattern.iter().sum::<f32>() > 0.0
+    }
+}
+
+impl SetDashes for Option<Dashes> {
+    fn dashes_mut(&mut self) -> &mut Option<Dashes> {
+        self
+    }
+}
@@ -0,0 +1,270 @@
+//! Items related to the **RenderTarget**, an offscreen texture to which a **Draw** may be rendered.
+
+use crate::draw::{self, Draw};
+use crate::frame::Frame;
+use crate::wgpu;
+use crate::window::Window;
+
+/// An offscreen texture to which **Draw** instances may be rendered.
+///
+/// The **RenderTarget** owns its texture, a **draw::Renderer** targeting it and, if more than one
+/// MSAA sample is requested, a multisampled texture that is resolved to the main texture after
+/// each render.
+///
+/// The resolved texture may be drawn within another **Draw** via `draw.texture(&target)` or read
+/// back to the CPU via `capture`. Note that a target may not be drawn to itself within the same
+/// render. Feedback effects should instead alternate between two targets.
+#[derive(Debug)]
+pub struct RenderTarget {
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    msaa_texture: Option<(wgpu::Texture, wgpu::TextureView)>,
+    renderer: draw::Renderer,
+    scale_factor: f32,
+}
+
+/// A type used for building a **RenderTarget**.
+#[derive(Debug)]
+pub struct Builder {
+    size: [u32; 2],
+    format: wgpu::TextureFormat,
+    msaa_samples: u32,
+    scale_factor: f32,
+    renderer: draw::RendererBuilder,
+}
+
+impl Builder {
+    /// The default size of the target's texture in pixels.
+    pub const DEFAULT_SIZE: [u32; 2] = [1024, 1024];
+    /// The default texture format, matching that of the **Frame**'s intermediary texture.
+    pub const DEFAULT_FORMAT: wgpu::TextureFormat = Frame::TEXTURE_FORMAT;
+    /// By default, no multisampling is performed.
+    pub const DEFAULT_MSAA_SAMPLES: u32 = 1;
+    /// The default number of pixels per **Draw** unit.
+    pub const DEFAULT_SCALE_FACTOR: f32 = 1.0;
+
+    /// Begin building a new **RenderTarget**.
+    pub fn new() -> Self {
+        Builder {
+            size: Self::DEFAULT_SIZE,
+            format: Self::DEFAULT_FORMAT,
+            msaa_samples: Self::DEFAULT_MSAA_SAMPLES,
+            scale_factor: Self::DEFAULT_SCALE_FACTOR,
+            renderer: draw::RendererBuilder::new(),
+        }
+    }
+
+    /// The size of the target's texture in pixels.
+    pub fn size(mut self, size: [u32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The color format of the target's texture.
+    pub fn format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// The number of samples per pixel used for multisample anti-aliasing.
+    ///
+    /// If greater than `1`, an additional multisampled texture is rendered to and then resolved
+    /// to the target's texture.
+    pub fn msaa_samples(mut self, msaa_samples: u32) -> Self {
+        self.msaa_samples = msaa_samples;
+        self
+    }
+
+    /// The number of pixels per **Draw** unit.
+    ///
+    /// A target with a scale factor of `2.0` and a size of `[800, 600]` may be drawn to as though
+    /// it were `400x300` points in size, matching the behaviour of a window on a high DPI display.
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Specify the builder used to create the target's **draw::Renderer**.
+    pub fn renderer(mut self, renderer: draw::RendererBuilder) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// Build the **RenderTarget**.
+    pub fn build(self, device: &wgpu::Device) -> RenderTarget {
+        let Builder {
+            size,
+            format,
+            msaa_samples,
+            scale_factor,
+            renderer,
+        } = self;
+        let (texture, texture_view, msaa_texture) =
+            create_textures(device, size, format, msaa_samples);
+        let renderer = renderer.build(device, size, scale_factor, msaa_samples.max(1), format);
+        RenderTarget {
+            texture,
+            texture_view,
+            msaa_texture,
+            renderer,
+            scale_factor,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderTarget {
+    /// Begin building a new **RenderTarget**.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// The resolved texture containing the result of the most recent render.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// A view of the resolved texture.
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// The size of the target's texture in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// The color format of the target's texture.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.texture.format()
+    }
+
+    /// The number of samples per pixel used for multisample anti-aliasing.
+    pub fn msaa_samples(&self) -> u32 {
+        self.msaa_texture
+            .as_ref()
+            .map(|(tex, _)| tex.sample_count())
+            .unwrap_or(1)
+    }
+
+    /// The number of pixels per **Draw** unit.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// The renderer used to render **Draw** instances to the target.
+    pub fn renderer_mut(&mut self) -> &mut draw::Renderer {
+        &mut self.renderer
+    }
+
+    /// Resize the target's textures to the given size in pixels.
+    ///
+    /// The contents of the target are lost if the size changes.
+    pub fn resize(&mut self, device: &wgpu::Device, size: [u32; 2]) {
+        if size == self.size() {
+            return;
+        }
+        let format = self.format();
+        let msaa_samples = self.msaa_samples();
+        let (texture, texture_view, msaa_texture) =
+            create_textures(device, size, format, msaa_samples);
+        self.texture = texture;
+        self.texture_view = texture_view;
+        self.msaa_texture = msaa_texture;
+    }
+
+    /// Encode a render pass drawing the given **Draw** to the target, resolving the multisampled
+    /// texture if necessary.
+    pub fn encode_render_pass(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        draw: &Draw,
+    ) {
+        let size = self.size();
+        let (attachment, resolve_target) = match self.msaa_texture {
+            None => (&self.texture_view, None),
+            Some((_, ref msaa_view)) => (msaa_view, Some(&self.texture_view)),
+        };
+        self.renderer.encode_render_pass(
+            device,
+            encoder,
+            draw,
+            self.scale_factor,
+            size,
+            attachment,
+            resolve_target,
+        );
+    }
+
+    /// Render the given **Draw** to the target and submit the commands to the window's queue.
+    pub fn render(&mut self, window: &Window, draw: &Draw) {
+        let device = window.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("nannou_render_target"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        self.encode_render_pass(device, &mut encoder, draw);
+        window.queue().submit(Some(encoder.finish()));
+    }
+
+    /// Encode a capture of the target's resolved texture.
+    ///
+    /// The returned snapshot may only be read once the commands encoded to `encoder` have been
+    /// submitted.
+    pub fn capture(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        capturer: &wgpu::TextureCapturer,
+    ) -> wgpu::TextureSnapshot {
+        capturer.capture(device, encoder, &self.texture)
+    }
+}
+
+impl wgpu::ToTextureView for RenderTarget {
+    fn to_texture_view(&self) -> wgpu::TextureView {
+        self.texture_view.clone()
+    }
+}
+
+fn create_textures(
+    device: &wgpu::Device,
+    size: [u32; 2],
+    format: wgpu::TextureFormat,
+    msaa_samples: u32,
+) -> (
+    wgpu::Texture,
+    wgpu::TextureView,
+    Option<(wgpu::Texture, wgpu::TextureView)>,
+) {
+    let texture = wgpu::TextureBuilder::new()
+        .size(size)
+        .format(format)
+        .usage(
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
+        )
+        .build(device);
+    let texture_view = texture.view().build();
+    let msaa_texture = match msaa_samples {
+        0 | 1 => None,
+        _ => {
+            let texture = wgpu::TextureBuilder::new()
+                .size(size)
+                .format(format)
+                .sample_count(msaa_samples)
+                .usage(wgpu::TextureUsages::RENDER_ATTACHMENT)
+                .build(device);
+            let texture_view = texture.view().build();
+            Some((texture, texture_view))
+        }
+    };
+    (texture, texture_view, msaa_texture)
+}
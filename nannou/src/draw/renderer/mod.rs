@@ -49,14 +49,36 @@ pub struct RenderContext<'a> {
 }
 
 pub struct GlyphCache {
+    /// The way in which glyphs are rasterized into the cache.
+    pub mode: GlyphCacheMode,
     /// Tracks glyphs and their location within the cache.
     pub cache: text::GlyphCache<'static>,
+    /// Tracks glyph distance fields and their location within the cache.
+    ///
+    /// Only used when the `mode` is `GlyphCacheMode::Sdf`.
+    pub sdf_atlas: text::sdf::Atlas,
     /// The buffer used to store the pixels of the glyphs.
     pub pixel_buffer: Vec<u8>,
     /// Will be set to `true` after the cache has been updated if the texture requires re-uploading.
     pub requires_upload: bool,
 }
 
+/// The way in which glyphs are rasterized into the **GlyphCache**.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum GlyphCacheMode {
+    /// Rasterize the coverage of each glyph at the size at which it appears on the output.
+    ///
+    /// This produces the sharpest results for upright text, however glyphs blur when scaled up
+    /// or rotated by the transform of the **Draw** instance.
+    Bitmap,
+    /// Rasterize each glyph once as a signed distance field from which the outline is
+    /// reconstructed in the fragment shader.
+    ///
+    /// Text remains crisp under any scale or 3D transform at the cost of slightly rounded
+    /// corners. See the `text::sdf` module for details.
+    Sdf,
+}
+
 /// A top-level indicator of whether or not
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(u32)]
@@ -68,7 +90,30 @@ pub enum VertexMode {
     /// A special mode used by the text primitive.
     ///
     /// Uses the color values, but multiplies the alpha by the glyph cache texture's red value.
+    /// Vertices with negative texture coordinates ignore the glyph cache, allowing for solid
+    /// quads such as underlines to be drawn alongside glyphs.
     Text = 2,
+    /// Use the color values shaded by the lights of the draw context.
+    ///
+    /// Requires that vertices are submitted with normals.
+    Lit = 3,
+    /// The text mode used when glyphs are cached as signed distance fields.
+    ///
+    /// Uses the color values, but multiplies the alpha by the coverage reconstructed from the
+    /// distance stored in the glyph cache texture's red value. Negative texture coordinates are
+    /// treated as in `Text` mode.
+    SdfText = 4,
+}
+
+/// The result of tessellating the contents of a **Draw** instance without a GPU.
+///
+/// See the [**tessellate**](./fn.tessellate.html) function.
+#[derive(Clone, Debug, Default)]
+pub struct Tessellation {
+    /// The tessellated vertices and indices of all primitives.
+    pub mesh: draw::Mesh,
+    /// The way in which each vertex in `mesh` should be coloured, one per vertex.
+    pub vertex_modes: Vec<VertexMode>,
 }
 
 /// A helper type aimed at simplifying the rendering of nannou primitives via wgpu.
@@ -93,11 +138,16 @@ pub struct Renderer {
     texture_bind_groups: HashMap<BindGroupId, wgpu::BindGroup>,
     output_color_format: wgpu::TextureFormat,
     sample_count: u32,
-    scale_factor: f32,
+    canvas: Option<draw::Canvas>,
     render_commands: Vec<RenderCommand>,
     mesh: draw::Mesh,
     vertex_mode_buffer: Vec<VertexMode>,
+    // One set of uniforms per camera or lighting change, uploaded at `uniforms_stride` offsets.
+    uniforms: Vec<Uniforms>,
     uniform_buffer: wgpu::Buffer,
+    uniform_buffer_capacity: usize,
+    // The uniform data currently within the `uniform_buffer`, used to avoid redundant uploads.
+    uploaded_uniforms: Vec<u8>,
 }
 
 /// A type aimed at simplifying construction of a `draw::Renderer`.
@@ -107,11 +157,14 @@ pub struct Builder {
     pub glyph_cache_size: [u32; 2],
     pub glyph_cache_scale_tolerance: f32,
     pub glyph_cache_position_tolerance: f32,
+    pub glyph_cache_mode: GlyphCacheMode,
 }
 
 /// Commands that map to wgpu encodable commands.
 #[derive(Debug)]
 enum RenderCommand {
+    /// Change the uniforms for a new camera.
+    SetUniforms(usize),
     /// Change pipeline for the new blend mode and topology.
     SetPipeline(PipelineId),
     /// Change bind group for a new image.
@@ -148,8 +201,29 @@ struct Uniforms {
     /// - y is transformed from (-half_logical_win_h, half_logical_win_h) to (1, -1).
     /// - z is transformed from (-max_logical_win_side, max_logical_win_side) to (0, 1).
     proj: Mat4,
+    /// The sum of all ambient lights.
+    ambient: [f32; 4],
+    /// The number of non-ambient lights in `lights`.
+    light_count: u32,
+    _padding: [u32; 3],
+    lights: [LightUniforms; draw::Light::MAX],
+}
+
+/// A directional or point light as laid out within the **Uniforms**.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+struct LightUniforms {
+    /// The direction of a directional light with `w = 0.0`, or the position of a point light with
+    /// `w = 1.0`.
+    position: [f32; 4],
+    /// The color multiplied by the intensity, with `w` set to the range of a point light.
+    color: [f32; 4],
 }
 
+// The offset alignment required between dynamically offset uniforms. 256 is the largest alignment
+// permitted by the WebGPU spec for `min_uniform_buffer_offset_alignment`.
+const UNIFORMS_ALIGNMENT: usize = 256;
+
 type SamplerId = u64;
 type BindGroupId = (SamplerId, wgpu::TextureViewId);
 type BlendId = u64;
@@ -175,16 +249,22 @@ impl RenderPrimitive for draw::Primitive {
     fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
         match self {
             draw::Primitive::Arrow(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cone(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cuboid(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cylinder(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Mesh(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Path(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Plane(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Polygon(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Tri(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Ellipse(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Quad(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Sphere(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Text(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Texture(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Torus(prim) => prim.render_primitive(ctxt, mesh),
             _ => PrimitiveRender::default(),
         }
     }
@@ -193,6 +273,7 @@ impl RenderPrimitive for draw::Primitive {
 impl fmt::Debug for GlyphCache {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("GlyphCache")
+            .field("mode", &self.mode)
             .field("cache", &self.cache.dimensions())
             .field("pixel_buffer", &self.pixel_buffer.len())
             .field("requires_upload", &self.requires_upload)
@@ -223,6 +304,14 @@ impl PrimitiveRender {
     pub fn text() -> Self {
         Self::vertex_mode(VertexMode::Text)
     }
+
+    pub fn sdf_text() -> Self {
+        Self::vertex_mode(VertexMode::SdfText)
+    }
+
+    pub fn lit() -> Self {
+        Self::vertex_mode(VertexMode::Lit)
+    }
 }
 
 impl Builder {
@@ -236,6 +325,8 @@ impl Builder {
     /// The default position tolerance for the glyph cache.
     pub const DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE: f32 =
         Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE;
+    /// The default way in which glyphs are rasterized into the glyph cache.
+    pub const DEFAULT_GLYPH_CACHE_MODE: GlyphCacheMode = Renderer::DEFAULT_GLYPH_CACHE_MODE;
 
     /// Begin building a new **draw::Renderer**.
     pub fn new() -> Self {
@@ -244,6 +335,7 @@ impl Builder {
             glyph_cache_size: Self::DEFAULT_GLYPH_CACHE_SIZE,
             glyph_cache_scale_tolerance: Self::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
             glyph_cache_position_tolerance: Self::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+            glyph_cache_mode: Self::DEFAULT_GLYPH_CACHE_MODE,
         }
     }
 
@@ -283,6 +375,17 @@ impl Builder {
         self
     }
 
+    /// Specify the way in which glyphs are rasterized into the glyph cache.
+    ///
+    /// `GlyphCacheMode::Sdf` allows for text that remains crisp when scaled up or rotated in 3D,
+    /// e.g. beneath a zooming camera. Each glyph's distance field occupies a fixed area of the
+    /// cache regardless of font size, so text using many distinct glyphs may require a larger
+    /// `glyph_cache_size`.
+    pub fn glyph_cache_mode(mut self, mode: GlyphCacheMode) -> Self {
+        self.glyph_cache_mode = mode;
+        self
+    }
+
     /// Build the **draw::Renderer** ready to target an output attachment of the given descriptor.
     pub fn build_from_texture_descriptor(
         self,
@@ -319,12 +422,18 @@ impl Builder {
             self.glyph_cache_size,
             self.glyph_cache_scale_tolerance,
             self.glyph_cache_position_tolerance,
+            self.glyph_cache_mode,
         )
     }
 }
 
 impl GlyphCache {
-    fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
+    fn new(
+        size: [u32; 2],
+        scale_tolerance: f32,
+        position_tolerance: f32,
+        mode: GlyphCacheMode,
+    ) -> Self {
         let [w, h] = size;
         let cache = text::GlyphCache::builder()
             .dimensions(w, h)
@@ -332,14 +441,69 @@ impl GlyphCache {
             .position_tolerance(position_tolerance)
             .build()
             .into();
+        let sdf_atlas = text::sdf::Atlas::new(w, h);
         let pixel_buffer = vec![0u8; w as usize * h as usize];
         let requires_upload = false;
         GlyphCache {
+            mode,
             cache,
+            sdf_atlas,
             pixel_buffer,
             requires_upload,
         }
     }
+
+    /// The distance field atlas entry for the given glyph, writing the glyph's field to the pixel
+    /// buffer if it is not yet cached.
+    pub fn sdf_entry(
+        &mut self,
+        font: &text::Font,
+        id: text::GlyphId,
+    ) -> Result<Option<text::sdf::Entry>, text::sdf::AtlasFull> {
+        let GlyphCache {
+            ref mut sdf_atlas,
+            ref mut pixel_buffer,
+            ref mut requires_upload,
+            ..
+        } = *self;
+        let (atlas_w, _) = sdf_atlas.dimensions();
+        sdf_atlas.get_or_insert(font, id, |rect, data| {
+            let width = rect.width() as usize;
+            for (row, src) in data.chunks(width).enumerate() {
+                let dst_ix = (rect.min.y as usize + row) * atlas_w as usize + rect.min.x as usize;
+                pixel_buffer[dst_ix..dst_ix + width].copy_from_slice(src);
+            }
+            *requires_upload = true;
+        })
+    }
+
+    /// The distance field atlas entries for the given glyphs, writing the fields of any glyphs
+    /// that are not yet cached to the pixel buffer.
+    ///
+    /// If the atlas is full, it is cleared and the given glyphs are cached again. This matches the
+    /// behaviour of the bitmap glyph cache when caching a queue of glyphs. An error is only
+    /// returned if the given glyphs alone do not fit within the atlas.
+    pub fn sdf_entries(
+        &mut self,
+        glyphs: &[(&text::Font, text::GlyphId)],
+    ) -> Result<Vec<Option<text::sdf::Entry>>, text::sdf::AtlasFull> {
+        let entries = glyphs
+            .iter()
+            .map(|&(font, id)| self.sdf_entry(font, id))
+            .collect();
+        if let Ok(entries) = entries {
+            return Ok(entries);
+        }
+        self.sdf_atlas.clear();
+        for byte in self.pixel_buffer.iter_mut() {
+            *byte = 0;
+        }
+        self.requires_upload = true;
+        glyphs
+            .iter()
+            .map(|&(font, id)| self.sdf_entry(font, id))
+            .collect()
+    }
 }
 
 impl Renderer {
@@ -351,6 +515,8 @@ impl Renderer {
     pub const DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE: f32 = 0.1;
     /// The default position tolerance for the glyph cache.
     pub const DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE: f32 = 0.1;
+    /// The default way in which glyphs are rasterized into the glyph cache.
+    pub const DEFAULT_GLYPH_CACHE_MODE: GlyphCacheMode = GlyphCacheMode::Bitmap;
     /// The texture format of the inner glyph cache.
     pub const GLYPH_CACHE_TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
     /// The index format used to index into vertices.
@@ -376,12 +542,14 @@ impl Renderer {
         glyph_cache_size: [u32; 2],
         glyph_cache_scale_tolerance: f32,
         glyph_cache_position_tolerance: f32,
+        glyph_cache_mode: GlyphCacheMode,
     ) -> Self {
         // Construct the glyph cache.
         let glyph_cache = GlyphCache::new(
             glyph_cache_size,
             glyph_cache_scale_tolerance,
             glyph_cache_position_tolerance,
+            glyph_cache_mode,
         );
 
         // Load shader modules.
@@ -415,12 +583,14 @@ impl Renderer {
         let default_texture_view = default_texture.view().build();
 
         // Initial uniform buffer values. These will be overridden on draw.
-        let uniforms = create_uniforms(output_attachment_size, output_scale_factor);
-        let contents = uniforms_as_bytes(&uniforms);
+        let uniforms =
+            create_uniforms(output_attachment_size, output_scale_factor, None, None, &[]);
+        let uniform_buffer_capacity = 1;
+        let contents = uniforms_slice_as_bytes(&[uniforms], uniform_buffer_capacity);
         let usage = wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST;
         let uniform_buffer = device.create_buffer_init(&wgpu::BufferInitDescriptor {
             label: Some("nannou Renderer uniform_buffer"),
-            contents,
+            contents: &contents,
             usage,
         });
 
@@ -454,6 +624,8 @@ impl Renderer {
         let render_commands = vec![];
         let mesh = Default::default();
         let vertex_mode_buffer = vec![];
+        let uniforms = vec![];
+        let uploaded_uniforms = contents;
 
         Self {
             vs_mod,
@@ -474,19 +646,35 @@ impl Renderer {
             pipelines,
             output_color_format,
             sample_count,
-            scale_factor: output_scale_factor,
+            canvas: None,
             render_commands,
             mesh,
             vertex_mode_buffer,
+            uniforms,
             uniform_buffer,
+            uniform_buffer_capacity,
+            uploaded_uniforms,
         }
     }
 
+    /// The **Canvas** used for drawings that do not specify their own.
+    pub fn canvas(&self) -> Option<draw::Canvas> {
+        self.canvas
+    }
+
+    /// Specify the **Canvas** used for drawings that do not specify their own.
+    ///
+    /// `Draw::to_frame` sets this to the canvas of the window being drawn to.
+    pub fn set_canvas(&mut self, canvas: Option<draw::Canvas>) {
+        self.canvas = canvas;
+    }
+
     /// Clear all pending render commands vertex data.
     pub fn clear(&mut self) {
         self.render_commands.clear();
         self.mesh.clear();
         self.vertex_mode_buffer.clear();
+        self.uniforms.clear();
     }
 
     /// Generate a list of `RenderCommand`s from the given **Draw** instance and prepare any
@@ -530,6 +718,7 @@ impl Renderer {
         let px_to_pt = |s: u32| s as f32 / scale_factor;
         let pt_to_px = |s: f32| (s * scale_factor).round() as u32;
         let full_rect = Rect::from_w_h(px_to_pt(w_px), px_to_pt(h_px));
+        let output_size = full_rect.wh();
 
         let window_to_scissor = |v: Vec2| -> [u32; 2] {
             let x = map_range(v.x, full_rect.left(), full_rect.right(), 0u32, w_px);
@@ -537,162 +726,159 @@ impl Renderer {
             [x, y]
         };
 
-        // TODO: Store these in `Renderer`.
-        let mut fill_tessellator = FillTessellator::new();
-        let mut stroke_tessellator = StrokeTessellator::new();
-
         // Keep track of context changes.
-        let mut curr_ctxt = draw::Context::default();
         let mut new_pipeline_ids = HashMap::new();
         let mut curr_start_index = 0;
         let mut new_tex_views = HashMap::new();
         let mut new_tex_sampler_combos = HashMap::new();
         // Track whether new commands are required.
+        let mut curr_uniforms = None;
         let mut curr_pipeline_id = None;
         let mut curr_scissor = None;
         let mut curr_tex_sampler_id = None;
 
-        // Collect all draw commands to avoid borrow errors.
-        let draw_cmds: Vec<_> = draw.drain_commands().collect();
-        let draw_state = draw.state.borrow_mut();
-        let intermediary_state = draw_state.intermediary_state.borrow();
-        for cmd in draw_cmds {
-            match cmd {
-                draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt,
-                draw::DrawCommand::Primitive(prim) => {
-                    // Track the prev index and vertex counts.
-                    let prev_index_count = self.mesh.indices().len() as u32;
-                    let prev_vert_count = self.mesh.vertex_count();
-
-                    // Info required during rendering.
-                    let ctxt = RenderContext {
-                        intermediary_mesh: &intermediary_state.intermediary_mesh,
-                        path_event_buffer: &intermediary_state.path_event_buffer,
-                        path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
-                        path_points_textured_buffer: &intermediary_state
-                            .path_points_textured_buffer,
-                        text_buffer: &intermediary_state.text_buffer,
-                        theme: &draw_state.theme,
-                        transform: &curr_ctxt.transform,
-                        fill_tessellator: &mut fill_tessellator,
-                        stroke_tessellator: &mut stroke_tessellator,
-                        glyph_cache: &mut self.glyph_cache,
-                        output_attachment_size: Vec2::new(px_to_pt(w_px), px_to_pt(h_px)),
-                        output_attachment_scale_factor: scale_factor,
-                    };
-
-                    // Render the primitive.
-                    let render = prim.render_primitive(ctxt, &mut self.mesh);
-
-                    // If the mesh indices are unchanged, there's nothing to be drawn.
-                    if prev_index_count == self.mesh.indices().len() as u32 {
-                        assert_eq!(
-                            prev_vert_count,
-                            self.mesh.vertex_count(),
-                            "vertices were submitted during `render` without submitting indices",
-                        );
-                        continue;
+        let Renderer {
+            ref mut glyph_cache,
+            ref mut mesh,
+            ref mut vertex_mode_buffer,
+            ref mut render_commands,
+            uniforms: ref mut uniforms_buffer,
+            ref default_texture_view,
+            canvas: renderer_canvas,
+            ..
+        } = *self;
+        let curr_ctxt = render_primitives(
+            draw,
+            glyph_cache,
+            output_attachment_size,
+            scale_factor,
+            mesh,
+            vertex_mode_buffer,
+            |ctxt, render, prev_index_count| {
+                // Retrieve the current texture view and texture view ID. These are necessary
+                // for producing the current pipeline and bind group IDs. Also ensure we have
+                // an entry for them in our map.
+                let tex_view = match render.texture_view {
+                    Some(tex_view) => tex_view,
+                    None => default_texture_view.clone(),
+                };
+                let tex_view_id = tex_view.id();
+                let texture_sample_type = tex_view.sample_type();
+                new_tex_views.insert(tex_view_id, tex_view);
+
+                // Determine the new current bind group layout ID, pipeline ID, bind group ID
+                // and scissor required for drawing this primitive.
+                let new_pipeline_id = {
+                    let color_id = blend_component_hash(&ctxt.blend.color);
+                    let alpha_id = blend_component_hash(&ctxt.blend.alpha);
+                    let topology = ctxt.topology;
+                    PipelineId {
+                        color_id,
+                        alpha_id,
+                        topology,
+                        texture_sample_type,
                     }
-
-                    // Retrieve the current texture view and texture view ID. These are necessary
-                    // for producing the current pipeline and bind group IDs. Also ensure we have
-                    // an entry for them in our map.
-                    let tex_view = match render.texture_view {
-                        Some(tex_view) => tex_view,
-                        None => self.default_texture_view.clone(),
-                    };
-                    let tex_view_id = tex_view.id();
-                    let texture_sample_type = tex_view.sample_type();
-                    new_tex_views.insert(tex_view_id, tex_view);
-
-                    // Determine the new current bind group layout ID, pipeline ID, bind group ID
-                    // and scissor required for drawing this primitive.
-                    let new_pipeline_id = {
-                        let color_id = blend_component_hash(&curr_ctxt.blend.color);
-                        let alpha_id = blend_component_hash(&curr_ctxt.blend.alpha);
-                        let topology = curr_ctxt.topology;
-                        PipelineId {
-                            color_id,
-                            alpha_id,
-                            topology,
-                            texture_sample_type,
-                        }
-                    };
-                    let new_bind_group_id = {
-                        let sampler_id = sampler_descriptor_hash(&curr_ctxt.sampler);
-                        (sampler_id, tex_view_id)
-                    };
-                    let new_scissor = curr_ctxt.scissor;
-
-                    // Determine which have changed and in turn which require submitting new
-                    // commands.
-                    let pipeline_changed = Some(new_pipeline_id) != curr_pipeline_id;
-                    let bind_group_changed = Some(new_bind_group_id) != curr_tex_sampler_id;
-                    let scissor_changed = Some(new_scissor) != curr_scissor;
-
-                    // If we require submitting a scissor, pipeline or bind group command, first
-                    // draw whatever pending vertices we have collected so far. If there have been
-                    // no graphics yet, this will do nothing.
-                    if scissor_changed || pipeline_changed || bind_group_changed {
-                        push_draw_cmd(
-                            &mut curr_start_index,
-                            prev_index_count,
-                            &mut self.render_commands,
-                        );
+                };
+                let new_bind_group_id = {
+                    let sampler_id = sampler_descriptor_hash(&ctxt.sampler);
+                    (sampler_id, tex_view_id)
+                };
+                let canvas = ctxt.canvas.or(renderer_canvas);
+                let new_scissor = (ctxt.scissor, canvas);
+
+                // Determine which have changed and in turn which require submitting new
+                // commands.
+                let uniforms_changed = match curr_uniforms {
+                    Some((ref camera, ref uniforms_canvas, ref lights)) => {
+                        *camera != ctxt.camera
+                            || *uniforms_canvas != canvas
+                            || *lights != ctxt.lights
                     }
+                    None => true,
+                };
+                let pipeline_changed = Some(new_pipeline_id) != curr_pipeline_id;
+                let bind_group_changed = Some(new_bind_group_id) != curr_tex_sampler_id;
+                let scissor_changed = Some(new_scissor) != curr_scissor;
+
+                // If we require submitting a uniforms, scissor, pipeline or bind group command,
+                // first draw whatever pending vertices we have collected so far. If there have
+                // been no graphics yet, this will do nothing.
+                if uniforms_changed || scissor_changed || pipeline_changed || bind_group_changed {
+                    push_draw_cmd(&mut curr_start_index, prev_index_count, render_commands);
+                }
 
-                    // If necessary, push a new pipeline command.
-                    if pipeline_changed {
-                        curr_pipeline_id = Some(new_pipeline_id);
-                        let color_blend = curr_ctxt.blend.color.clone();
-                        let alpha_blend = curr_ctxt.blend.alpha.clone();
-                        let sampler_filtering = wgpu::sampler_filtering(&curr_ctxt.sampler);
-                        new_pipeline_ids.insert(
-                            new_pipeline_id,
-                            (color_blend, alpha_blend, sampler_filtering),
-                        );
-                        let cmd = RenderCommand::SetPipeline(new_pipeline_id);
-                        self.render_commands.push(cmd);
-                    }
+                // If necessary, push new uniforms for the camera and lights.
+                if uniforms_changed {
+                    let uniforms = create_uniforms(
+                        output_attachment_size,
+                        scale_factor,
+                        ctxt.camera.as_ref(),
+                        canvas.as_ref(),
+                        &ctxt.lights,
+                    );
+                    curr_uniforms = Some((ctxt.camera, canvas, ctxt.lights.clone()));
+                    let cmd = RenderCommand::SetUniforms(uniforms_buffer.len());
+                    uniforms_buffer.push(uniforms);
+                    render_commands.push(cmd);
+                }
 
-                    // If necessary, push a new bind group command.
-                    if bind_group_changed {
-                        curr_tex_sampler_id = Some(new_bind_group_id);
-                        new_tex_sampler_combos.insert(new_bind_group_id, new_pipeline_id);
-                        let cmd = RenderCommand::SetBindGroup(new_bind_group_id);
-                        self.render_commands.push(cmd);
-                    }
+                // If necessary, push a new pipeline command.
+                if pipeline_changed {
+                    curr_pipeline_id = Some(new_pipeline_id);
+                    let color_blend = ctxt.blend.color.clone();
+                    let alpha_blend = ctxt.blend.alpha.clone();
+                    let sampler_filtering = wgpu::sampler_filtering(&ctxt.sampler);
+                    new_pipeline_ids.insert(
+                        new_pipeline_id,
+                        (color_blend, alpha_blend, sampler_filtering),
+                    );
+                    let cmd = RenderCommand::SetPipeline(new_pipeline_id);
+                    render_commands.push(cmd);
+                }
 
-                    // If necessary, push a new scissor command.
-                    if scissor_changed {
-                        curr_scissor = Some(new_scissor);
-                        let rect = match curr_ctxt.scissor {
-                            draw::Scissor::Full => full_rect,
-                            draw::Scissor::Rect(rect) => full_rect
-                                .overlap(rect)
-                                .unwrap_or(geom::Rect::from_w_h(0.0, 0.0)),
-                            draw::Scissor::NoOverlap => geom::Rect::from_w_h(0.0, 0.0),
-                        };
-                        let [left, bottom] = window_to_scissor(rect.bottom_left().into());
-                        let (width, height) = rect.w_h();
-                        let (width, height) = (pt_to_px(width), pt_to_px(height));
-                        let scissor = Scissor {
-                            left,
-                            bottom,
-                            width,
-                            height,
-                        };
-                        let cmd = RenderCommand::SetScissor(scissor);
-                        self.render_commands.push(cmd);
-                    }
+                // If necessary, push a new bind group command.
+                if bind_group_changed {
+                    curr_tex_sampler_id = Some(new_bind_group_id);
+                    new_tex_sampler_combos.insert(new_bind_group_id, new_pipeline_id);
+                    let cmd = RenderCommand::SetBindGroup(new_bind_group_id);
+                    render_commands.push(cmd);
+                }
 
-                    // Extend the vertex mode channel.
-                    let mode = render.vertex_mode;
-                    let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
-                    self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+                // If necessary, push a new scissor command.
+                if scissor_changed {
+                    curr_scissor = Some(new_scissor);
+                    // Drawing is limited to the area of the output covered by the canvas and
+                    // scissor rects are specified in canvas coordinates.
+                    let bounds = match canvas {
+                        None => Some(full_rect),
+                        Some(canvas) => full_rect.overlap(canvas.viewport(output_size)),
+                    };
+                    let to_output = |rect: geom::Rect| match canvas {
+                        None => rect,
+                        Some(canvas) => canvas.rect_to_output(output_size, rect),
+                    };
+                    let rect = match ctxt.scissor {
+                        draw::Scissor::Full => bounds,
+                        draw::Scissor::Rect(rect) => {
+                            bounds.and_then(|bounds| bounds.overlap(to_output(rect)))
+                        }
+                        draw::Scissor::NoOverlap => None,
+                    };
+                    let rect = rect.unwrap_or(geom::Rect::from_w_h(0.0, 0.0));
+                    let [left, bottom] = window_to_scissor(rect.bottom_left().into());
+                    let (width, height) = rect.w_h();
+                    let (width, height) = (pt_to_px(width), pt_to_px(height));
+                    let scissor = Scissor {
+                        left,
+                        bottom,
+                        width,
+                        height,
+                    };
+                    let cmd = RenderCommand::SetScissor(scissor);
+                    render_commands.push(cmd);
                 }
-            }
-        }
+            },
+        );
 
         // Insert the final draw command if there is still some drawing to be done.
         push_draw_cmd(
@@ -789,14 +975,17 @@ impl Renderer {
             ref glyph_cache_texture,
             ref mut depth_texture,
             ref mut depth_texture_view,
-            ref uniform_bind_group,
+            ref uniform_bind_group_layout,
+            ref mut uniform_bind_group,
             ref text_bind_group,
             ref texture_bind_groups,
             ref mesh,
             ref vertex_mode_buffer,
             ref mut render_commands,
-            ref uniform_buffer,
-            scale_factor: ref mut old_scale_factor,
+            ref uniforms,
+            ref mut uniform_buffer,
+            ref mut uniform_buffer_capacity,
+            ref mut uploaded_uniforms,
             ..
         } = *self;
 
@@ -847,6 +1036,7 @@ impl Renderer {
         let colors_bytes = colors_as_bytes(mesh.colors());
         let tex_coords_bytes = tex_coords_as_bytes(mesh.tex_coords());
         let modes_bytes = vertex_modes_as_bytes(vertex_mode_buffer);
+        let normals_bytes = normals_as_bytes(mesh.normals());
         let indices_bytes = indices_as_bytes(mesh.indices());
         let point_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer point_buffer"),
@@ -868,27 +1058,44 @@ impl Renderer {
             contents: modes_bytes,
             usage: vertex_usage,
         });
+        let normal_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer normal_buffer"),
+            contents: normals_bytes,
+            usage: vertex_usage,
+        });
         let index_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer index_buffer"),
             contents: indices_bytes,
             usage: wgpu::BufferUsages::INDEX,
         });
 
-        // If the scale factor or window size has changed, update the uniforms for vertex scaling.
-        if *old_scale_factor != scale_factor || output_attachment_size != depth_size {
-            *old_scale_factor = scale_factor;
-            // Upload uniform data for vertex scaling.
-            let uniforms = create_uniforms(output_attachment_size, scale_factor);
-            let uniforms_size = std::mem::size_of::<Uniforms>() as wgpu::BufferAddress;
-            let uniforms_bytes = uniforms_as_bytes(&uniforms);
-            let usage = wgpu::BufferUsages::COPY_SRC;
+        // Grow the uniform buffer if this frame uses more cameras than it has room for.
+        if uniforms.len() > *uniform_buffer_capacity {
+            *uniform_buffer_capacity = uniforms.len().next_power_of_two();
+            let size = (*uniform_buffer_capacity * uniforms_stride()) as wgpu::BufferAddress;
+            *uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("nannou Renderer uniform_buffer"),
+                size,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            *uniform_bind_group =
+                create_uniform_bind_group(device, uniform_bind_group_layout, uniform_buffer);
+            uploaded_uniforms.clear();
+        }
+
+        // If the cameras, lights, scale factor or size have changed, upload the uniform data for
+        // each camera used during the frame.
+        let uniforms_bytes = uniforms_slice_as_bytes(uniforms, uniforms.len());
+        if !uploaded_uniforms.starts_with(&uniforms_bytes) {
+            let uniforms_size = uniforms_bytes.len() as wgpu::BufferAddress;
             let new_uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
                 label: Some("nannou Renderer uniform_buffer"),
-                contents: uniforms_bytes,
-                usage,
+                contents: &uniforms_bytes,
+                usage: wgpu::BufferUsages::COPY_SRC,
             });
-            // Copy new uniform buffer state.
             encoder.copy_buffer_to_buffer(&new_uniform_buffer, 0, uniform_buffer, 0, uniforms_size);
+            *uploaded_uniforms = uniforms_bytes;
         }
 
         // Encode the render pass.
@@ -900,14 +1107,19 @@ impl Renderer {
         render_pass.set_vertex_buffer(1, color_buffer.slice(..));
         render_pass.set_vertex_buffer(2, tex_coords_buffer.slice(..));
         render_pass.set_vertex_buffer(3, mode_buffer.slice(..));
+        render_pass.set_vertex_buffer(4, normal_buffer.slice(..));
 
-        // Set the uniform and text bind groups here.
-        render_pass.set_bind_group(0, uniform_bind_group, &[]);
+        // Set the text bind group here. Uniforms are set per camera via the render commands.
         render_pass.set_bind_group(1, text_bind_group, &[]);
 
         // Follow the render commands.
         for cmd in render_commands.drain(..) {
             match cmd {
+                RenderCommand::SetUniforms(index) => {
+                    let offset = (index * uniforms_stride()) as wgpu::DynamicOffset;
+                    render_pass.set_bind_group(0, &*uniform_bind_group, &[offset]);
+                }
+
                 RenderCommand::SetPipeline(id) => {
                     let pipeline = &pipelines[&id];
                     render_pass.set_pipeline(pipeline);
@@ -988,6 +1200,40 @@ impl Renderer {
     }
 }
 
+/// Tessellate the contents of the given **Draw** into a mesh without building any wgpu objects.
+///
+/// Primitives are rendered via the same `RenderPrimitive` implementations used by the
+/// **Renderer**, so the resulting mesh matches the geometry that would be submitted to the GPU.
+/// This is useful for testing generative geometry on machines without a GPU.
+///
+/// The `output_attachment_size` (in physical pixels) and `scale_factor` are required for laying
+/// out text. Text glyphs are rasterized into a glyph cache of the default size.
+///
+/// Note that the given **Draw** instance will be *drained* of its commands.
+pub fn tessellate(
+    draw: &draw::Draw,
+    output_attachment_size: [u32; 2],
+    scale_factor: f32,
+) -> Tessellation {
+    let mut glyph_cache = GlyphCache::new(
+        Renderer::DEFAULT_GLYPH_CACHE_SIZE,
+        Renderer::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
+        Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+        Renderer::DEFAULT_GLYPH_CACHE_MODE,
+    );
+    let mut tessellation = Tessellation::default();
+    render_primitives(
+        draw,
+        &mut glyph_cache,
+        output_attachment_size,
+        scale_factor,
+        &mut tessellation.mesh,
+        &mut tessellation.vertex_modes,
+        |_, _, _| (),
+    );
+    tessellation
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self::new()
@@ -1007,6 +1253,88 @@ impl DerefMut for GlyphCache {
     }
 }
 
+// Render the primitives of the given **Draw** into the `mesh`, adding one vertex mode per new
+// vertex to `vertex_modes`.
+//
+// `primitive_rendered` is called for each primitive that submits indices with the context that it
+// was drawn with, its render info and the number of indices in the mesh prior to rendering it.
+//
+// Returns the context that was current at the end of the draw commands.
+//
+// Note that the given **Draw** instance will be *drained* of its commands.
+fn render_primitives<F>(
+    draw: &draw::Draw,
+    glyph_cache: &mut GlyphCache,
+    output_attachment_size: [u32; 2],
+    scale_factor: f32,
+    mesh: &mut draw::Mesh,
+    vertex_modes: &mut Vec<VertexMode>,
+    mut primitive_rendered: F,
+) -> draw::Context
+where
+    F: FnMut(&draw::Context, PrimitiveRender, u32),
+{
+    let [w_px, h_px] = output_attachment_size;
+    let px_to_pt = |s: u32| s as f32 / scale_factor;
+
+    // TODO: Store these in `Renderer`.
+    let mut fill_tessellator = FillTessellator::new();
+    let mut stroke_tessellator = StrokeTessellator::new();
+    let mut curr_ctxt = draw::Context::default();
+
+    // Collect all draw commands to avoid borrow errors.
+    let draw_cmds: Vec<_> = draw.drain_commands().collect();
+    let draw_state = draw.state.borrow();
+    let intermediary_state = draw_state.intermediary_state.borrow();
+    for cmd in draw_cmds {
+        match cmd {
+            draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt,
+            draw::DrawCommand::Primitive(prim) => {
+                // Track the prev index and vertex counts.
+                let prev_index_count = mesh.indices().len() as u32;
+                let prev_vert_count = mesh.vertex_count();
+
+                // Info required during rendering.
+                let ctxt = RenderContext {
+                    intermediary_mesh: &intermediary_state.intermediary_mesh,
+                    path_event_buffer: &intermediary_state.path_event_buffer,
+                    path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+                    path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+                    text_buffer: &intermediary_state.text_buffer,
+                    theme: &draw_state.theme,
+                    transform: &curr_ctxt.transform,
+                    fill_tessellator: &mut fill_tessellator,
+                    stroke_tessellator: &mut stroke_tessellator,
+                    glyph_cache,
+                    output_attachment_size: Vec2::new(px_to_pt(w_px), px_to_pt(h_px)),
+                    output_attachment_scale_factor: scale_factor,
+                };
+
+                // Render the primitive.
+                let render = prim.render_primitive(ctxt, mesh);
+
+                // If the mesh indices are unchanged, there's nothing to be drawn.
+                if prev_index_count == mesh.indices().len() as u32 {
+                    assert_eq!(
+                        prev_vert_count,
+                        mesh.vertex_count(),
+                        "vertices were submitted during `render` without submitting indices",
+                    );
+                    continue;
+                }
+
+                // Extend the vertex mode channel.
+                let mode = render.vertex_mode;
+                let new_vs = mesh.points().len() - vertex_modes.len();
+                vertex_modes.extend((0..new_vs).map(|_| mode));
+
+                primitive_rendered(&curr_ctxt, render, prev_index_count);
+            }
+        }
+    }
+    curr_ctxt
+}
+
 fn create_depth_texture(
     device: &wgpu::Device,
     size: [u32; 2],
@@ -1021,12 +1349,74 @@ fn create_depth_texture(
         .build(device)
 }
 
-fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
-    let right = img_w as f32 * 0.5 / scale_factor;
+fn create_uniforms(
+    output_attachment_size: [u32; 2],
+    scale_factor: f32,
+    camera: Option<&draw::Camera>,
+    canvas: Option<&draw::Canvas>,
+    lights: &[draw::Light],
+) -> Uniforms {
+    let proj = create_proj(output_attachment_size, scale_factor, camera, canvas);
+    let default_lights = draw::Light::default_set();
+    let lights = if lights.is_empty() {
+        &default_lights[..]
+    } else {
+        lights
+    };
+    let mut ambient = [0.0; 4];
+    let mut light_uniforms = [LightUniforms::default(); draw::Light::MAX];
+    let mut light_count = 0;
+    for light in lights {
+        let c = light.color * light.intensity;
+        let color = [c.red, c.green, c.blue];
+        let (position, range) = match light.kind {
+            draw::light::LightKind::Ambient => {
+                (0..3).for_each(|i| ambient[i] += color[i]);
+                continue;
+            }
+            draw::light::LightKind::Directional { direction } => (direction.extend(0.0), 0.0),
+            draw::light::LightKind::Point { position, range } => {
+                (position.extend(1.0), range.unwrap_or(0.0))
+            }
+        };
+        if light_count == draw::Light::MAX {
+            continue;
+        }
+        light_uniforms[light_count] = LightUniforms {
+            position: position.into(),
+            color: [color[0], color[1], color[2], range],
+        };
+        light_count += 1;
+    }
+    Uniforms {
+        proj,
+        ambient,
+        light_count: light_count as u32,
+        _padding: [0; 3],
+        lights: light_uniforms,
+    }
+}
+
+fn create_proj(
+    [img_w, img_h]: [u32; 2],
+    scale_factor: f32,
+    camera: Option<&draw::Camera>,
+    canvas: Option<&draw::Canvas>,
+) -> Mat4 {
+    // When drawing to a canvas, project the canvas and then fit the result to the output.
+    let output_size = Vec2::new(img_w as f32, img_h as f32) / scale_factor;
+    let (size, fit) = match canvas {
+        None => (output_size, Mat4::IDENTITY),
+        Some(canvas) => (canvas.size, canvas.clip_transform(output_size)),
+    };
+    if let Some(camera) = camera {
+        return fit * camera.view_projection(size);
+    }
+    let right = size.x * 0.5;
     let left = -right;
-    let top = img_h as f32 * 0.5 / scale_factor;
+    let top = size.y * 0.5;
     let bottom = -top;
-    let far = std::cmp::max(img_w, img_h) as f32 / scale_factor;
+    let far = size.x.max(size.y);
     let near = -far;
     let proj = Mat4::orthographic_rh_gl(left, right, bottom, top, near, far);
     // By default, ortho scales z values to the range -1.0 to 1.0. We want to scale and translate
@@ -1034,14 +1424,12 @@ fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
     // TODO: Can possibly solve this more easily by using `Mat4::orthographic_rh` above instead.
     let trans = Mat4::from_translation(Vec3::Z);
     let scale = Mat4::from_scale([1.0, 1.0, 0.5].into());
-    let proj = scale * trans * proj;
-    let proj = proj.into();
-    Uniforms { proj }
+    fit * scale * trans * proj
 }
 
 fn create_uniform_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
     wgpu::BindGroupLayoutBuilder::new()
-        .uniform_buffer(wgpu::ShaderStages::VERTEX, false)
+        .uniform_buffer(wgpu::ShaderStages::VERTEX_FRAGMENT, true)
         .build(device)
 }
 
@@ -1131,6 +1519,7 @@ fn create_render_pipeline(
             &wgpu::vertex_attr_array![2 => Float32x2],
         )
         .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
+        .add_vertex_buffer::<draw::mesh::vertex::Normal>(&wgpu::vertex_attr_array![4 => Float32x3])
         .depth_format(depth_format)
         .sample_count(sample_count)
         .color_blend(color_blend)
@@ -1169,6 +1558,23 @@ fn uniforms_as_bytes(uniforms: &Uniforms) -> &[u8] {
     unsafe { wgpu::bytes::from(uniforms) }
 }
 
+// The size of **Uniforms** rounded up to the `UNIFORMS_ALIGNMENT`.
+fn uniforms_stride() -> usize {
+    let size = std::mem::size_of::<Uniforms>();
+    (size + UNIFORMS_ALIGNMENT - 1) / UNIFORMS_ALIGNMENT * UNIFORMS_ALIGNMENT
+}
+
+// Lay out the given uniforms at `uniforms_stride` byte offsets, padded to `capacity` slots.
+fn uniforms_slice_as_bytes(uniforms: &[Uniforms], capacity: usize) -> Vec<u8> {
+    let stride = uniforms_stride();
+    let mut bytes = vec![0u8; capacity * stride];
+    for (chunk, uniforms) in bytes.chunks_mut(stride).zip(uniforms) {
+        let uniforms_bytes = uniforms_as_bytes(uniforms);
+        chunk[..uniforms_bytes.len()].copy_from_slice(uniforms_bytes);
+    }
+    bytes
+}
+
 fn points_as_bytes(data: &[draw::mesh::vertex::Point]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
@@ -1181,6 +1587,10 @@ fn tex_coords_as_bytes(data: &[draw::mesh::vertex::TexCoords]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
 
+fn normals_as_bytes(data: &[draw::mesh::vertex::Normal]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
 fn vertex_modes_as_bytes(data: &[VertexMode]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
@@ -2,6 +2,21 @@ struct FragmentOutput {
     [[location(0)]] color: vec4<f32>;
 };
 
+struct Light {
+    position: vec4<f32>;
+    color: vec4<f32>;
+};
+
+[[block]]
+struct Data {
+    proj: mat4x4<f32>;
+    ambient: vec4<f32>;
+    light_count: u32;
+    lights: [[stride(32)]] array<Light, 8>;
+};
+
+[[group(0), binding(0)]]
+var<uniform> uniforms: Data;
 [[group(1), binding(0)]]
 var text_sampler: sampler;
 [[group(1), binding(1)]]
@@ -11,15 +26,54 @@ var tex_sampler: sampler;
 [[group(2), binding(1)]]
 var tex: texture_2d<f32>;
 
+// Accumulate the light arriving at the given surface point from all lights.
+//
+// A light with a `position.w` of `0.0` is directional, where `position.xyz` is the direction in
+// which the light shines. Otherwise it is a point light, where `color.w` is its range, or `0.0`
+// if the light does not fall off.
+fn lighting(normal: vec3<f32>, world_pos: vec3<f32>) -> vec3<f32> {
+    let n: vec3<f32> = normalize(normal);
+    var total: vec3<f32> = uniforms.ambient.xyz;
+    var i: u32 = u32(0);
+    loop {
+        if (i >= uniforms.light_count) {
+            break;
+        }
+        let light: Light = uniforms.lights[i];
+        if (light.position.w == 0.0) {
+            let l: vec3<f32> = normalize(-light.position.xyz);
+            total = total + light.color.xyz * max(dot(n, l), 0.0);
+        } else {
+            let to_light: vec3<f32> = light.position.xyz - world_pos;
+            let dist: f32 = length(to_light);
+            var attenuation: f32 = 1.0;
+            if (light.color.w > 0.0) {
+                let falloff: f32 = clamp(1.0 - dist / light.color.w, 0.0, 1.0);
+                attenuation = falloff * falloff;
+            }
+            let l: vec3<f32> = to_light / max(dist, 0.0001);
+            total = total + light.color.xyz * max(dot(n, l), 0.0) * attenuation;
+        }
+        continuing {
+            i = i + u32(1);
+        }
+    }
+    return total;
+}
+
 [[stage(fragment)]]
 fn main(
     [[location(0)]] color: vec4<f32>,
     [[location(1)]] tex_coords: vec2<f32>,
     [[location(2)]] mode: u32,
+    [[location(3)]] normal: vec3<f32>,
+    [[location(4)]] world_pos: vec3<f32>,
 ) -> FragmentOutput {
     let tex_color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
     let text_color: vec4<f32> = textureSample(text, text_sampler, tex_coords);
     let text_alpha: f32 = text_color.x;
+    // The screen-space rate of change of the distance field, used to anti-alias SDF text.
+    let text_dist_width: f32 = max(fwidth(text_color.x), 0.0001);
     var out_color: vec4<f32>;
     if (mode == u32(0)) {
         out_color = color;
@@ -28,9 +82,29 @@ fn main(
             out_color = tex_color;
         } else {
             if (mode == u32(2)) {
-                out_color = vec4<f32>(color.xyz, color.w * text_alpha);
+                // Negative tex coords indicate a solid text decoration, e.g. an underline.
+                if (tex_coords.x < 0.0) {
+                    out_color = color;
+                } else {
+                    out_color = vec4<f32>(color.xyz, color.w * text_alpha);
+                }
             } else {
-                out_color = vec4<f32>(1.0, 0.0, 0.0, 1.0);
+                if (mode == u32(3)) {
+                    out_color = vec4<f32>(color.xyz * lighting(normal, world_pos), color.w);
+                } else {
+                    if (mode == u32(4)) {
+                        if (tex_coords.x < 0.0) {
+                            out_color = color;
+                        } else {
+                            // A distance of 0.5 lies on the outline of the glyph.
+                            let w: f32 = text_dist_width * 0.7;
+                            let a: f32 = smoothStep(0.5 - w, 0.5 + w, text_alpha);
+                            out_color = vec4<f32>(color.xyz, color.w * a);
+                        }
+                    } else {
+                        out_color = vec4<f32>(1.0, 0.0, 0.0, 1.0);
+                    }
+                }
             }
         }
     }
@@ -1,12 +1,22 @@
+struct Light {
+    position: vec4<f32>;
+    color: vec4<f32>;
+};
+
 [[block]]
 struct Data {
     proj: mat4x4<f32>;
+    ambient: vec4<f32>;
+    light_count: u32;
+    lights: [[stride(32)]] array<Light, 8>;
 };
 
 struct VertexOutput {
     [[location(0)]] color: vec4<f32>;
     [[location(1)]] tex_coords: vec2<f32>;
     [[location(2)]] mode: u32;
+    [[location(3)]] normal: vec3<f32>;
+    [[location(4)]] world_pos: vec3<f32>;
     [[builtin(position)]] pos: vec4<f32>;
 };
 
@@ -19,7 +29,8 @@ fn main(
     [[location(1)]] color: vec4<f32>,
     [[location(2)]] tex_coords: vec2<f32>,
     [[location(3)]] mode: u32,
+    [[location(4)]] normal: vec3<f32>,
 ) -> VertexOutput {
     let out_pos: vec4<f32> = uniforms.proj * vec4<f32>(position, 1.0);
-    return VertexOutput(color, tex_coords, mode, out_pos);
+    return VertexOutput(color, tex_coords, mode, normal, position, out_pos);
 }
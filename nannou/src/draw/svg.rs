@@ -0,0 +1,461 @@
+//! Items related to exporting the contents of a **Draw** instance as an SVG document.
+//!
+//! Unlike the **Renderer**, the SVG backend does not require a GPU. Each recorded **Primitive** is
+//! converted to one or more SVG elements describing its outline along with its fill and stroke
+//! styles. This makes it possible to produce plotter-ready vector output from the same sketch
+//! that targets the window.
+
+use crate::color::{LinSrgba, Srgba};
+use crate::draw;
+use crate::draw::mesh::vertex::{Color, TexCoords};
+use crate::draw::primitive::path;
+use crate::draw::properties::gradient::{Gradient, GradientKind};
+use crate::geom::{self, Point2};
+use crate::glam::{Mat4, Vec2};
+use lyon::path::PathEvent;
+use lyon::tessellation::{FillRule, LineCap, LineJoin};
+use std::fmt::{self, Write};
+use std::io;
+use std::path::Path;
+
+/// Draw API primitives that may be exported to an SVG document.
+pub trait SvgPrimitive {
+    /// Write self into the given SVG document.
+    fn svg_primitive(self, ctxt: SvgContext, svg: &mut Svg);
+}
+
+/// The context provided to primitives to assist with the SVG export process.
+pub struct SvgContext<'a> {
+    pub transform: &'a Mat4,
+    pub intermediary_mesh: &'a draw::Mesh,
+    pub path_event_buffer: &'a [PathEvent],
+    pub path_points_colored_buffer: &'a [(Point2, Color)],
+    pub path_points_textured_buffer: &'a [(Point2, TexCoords)],
+    pub text_buffer: &'a str,
+    pub theme: &'a draw::Theme,
+}
+
+/// An SVG document produced from the commands of a **Draw** instance.
+///
+/// Coordinates are converted from nannou's centred, y-up space into SVG's top-left, y-down space
+/// using the document size.
+#[derive(Clone, Debug)]
+pub struct Svg {
+    size: Vec2,
+    background: Option<LinSrgba>,
+    defs: String,
+    body: String,
+    clip_count: usize,
+    clip_group_open: bool,
+    gradient_count: usize,
+}
+
+impl Svg {
+    /// Create a new, empty SVG document of the given size in logical points.
+    pub fn new(size: Vec2) -> Self {
+        Svg {
+            size,
+            background: None,
+            defs: String::new(),
+            body: String::new(),
+            clip_count: 0,
+            clip_group_open: false,
+            gradient_count: 0,
+        }
+    }
+
+    /// Create a new SVG document of the given size and fill it with the contents of `draw`.
+    ///
+    /// Note that the given **Draw** instance will be *drained* of its commands.
+    pub fn from_draw(draw: &draw::Draw, size: Vec2) -> Self {
+        let mut svg = Self::new(size);
+        svg.fill(draw);
+        svg
+    }
+
+    /// The size of the document in logical points.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Convert the commands of the given **Draw** instance into SVG elements and append them to
+    /// the document.
+    ///
+    /// The **Context** transform is applied to all points. A **Scissor** is converted to a
+    /// `clipPath`. Blend modes, primitive topology and samplers have no SVG equivalent and are
+    /// ignored.
+    ///
+    /// Note that the given **Draw** instance will be *drained* of its commands.
+    pub fn fill(&mut self, draw: &draw::Draw) {
+        let mut curr_ctxt = draw::Context::default();
+        let draw_cmds: Vec<_> = draw.drain_commands().collect();
+        let draw_state = draw.state.borrow();
+        if let Some(bg) = draw_state.background_color {
+            self.background = Some(bg);
+        }
+        let intermediary_state = draw_state.intermediary_state.borrow();
+        for cmd in draw_cmds {
+            match cmd {
+                draw::DrawCommand::Context(ctxt) => {
+                    if ctxt.scissor != curr_ctxt.scissor {
+                        self.set_scissor(ctxt.scissor);
+                    }
+                    curr_ctxt = ctxt;
+                }
+                draw::DrawCommand::Primitive(prim) => {
+                    if let draw::Scissor::NoOverlap = curr_ctxt.scissor {
+                        continue;
+                    }
+                    let ctxt = SvgContext {
+                        transform: &curr_ctxt.transform,
+                        intermediary_mesh: &intermediary_state.intermediary_mesh,
+                        path_event_buffer: &intermediary_state.path_event_buffer,
+                        path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+                        path_points_textured_buffer: &intermediary_state
+                            .path_points_textured_buffer,
+                        text_buffer: &intermediary_state.text_buffer,
+                        theme: &draw_state.theme,
+                    };
+                    prim.svg_primitive(ctxt, self);
+                }
+            }
+        }
+        self.close_clip_group();
+    }
+
+    /// Append a path element described by the given events.
+    ///
+    /// The `transform` is applied to every point. If `options` describes a stroke, the stroke
+    /// width is scaled by the transform's 2D scale.
+    pub fn path<I>(&mut self, events: I, color: LinSrgba, transform: Mat4, options: &path::Options)
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let (paint, opacity) = svg_color(color);
+        self.path_inner(events, &paint, opacity, transform, options);
+    }
+
+    /// Append a path element described by the given events, painted with the given gradient.
+    ///
+    /// The gradient is described in the same coordinate space as the events and is in turn
+    /// transformed by `transform`.
+    pub fn path_gradient<I>(
+        &mut self,
+        events: I,
+        gradient: &Gradient,
+        transform: Mat4,
+        options: &path::Options,
+    ) where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let id = self.gradient_def(gradient, transform);
+        let paint = format!("url(#{})", id);
+        self.path_inner(events, &paint, 1.0, transform, options);
+    }
+
+    // Append a path element with the given fill or stroke paint and opacity.
+    fn path_inner<I>(
+        &mut self,
+        events: I,
+        paint: &str,
+        opacity: f32,
+        transform: Mat4,
+        options: &path::Options,
+    ) where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let d = self.path_data(events, transform);
+        if d.is_empty() {
+            return;
+        }
+        let mut elem = format!("<path d=\"{}\"", d);
+        match *options {
+            path::Options::Fill(ref opts) => {
+                write_paint_attrs(&mut elem, "fill", paint, opacity);
+                let rule = match opts.fill_rule {
+                    FillRule::EvenOdd => "evenodd",
+                    FillRule::NonZero => "nonzero",
+                };
+                write!(elem, " fill-rule=\"{}\" stroke=\"none\"", rule).unwrap();
+            }
+            path::Options::Stroke(ref opts) => {
+                let width = opts.line_width * transform_scale(&transform);
+                let cap = match opts.start_cap {
+                    LineCap::Butt => "butt",
+                    LineCap::Square => "square",
+                    LineCap::Round => "round",
+                };
+                let join = match opts.line_join {
+                    LineJoin::Miter => "miter",
+                    LineJoin::MiterClip => "miter-clip",
+                    LineJoin::Round => "round",
+                    LineJoin::Bevel => "bevel",
+                };
+                elem.push_str(" fill=\"none\"");
+                write_paint_attrs(&mut elem, "stroke", paint, opacity);
+                write!(
+                    elem,
+                    " stroke-width=\"{}\" stroke-linecap=\"{}\" stroke-linejoin=\"{}\" \
+                     stroke-miterlimit=\"{}\"",
+                    width, cap, join, opts.miter_limit,
+                )
+                .unwrap();
+            }
+        }
+        elem.push_str("/>\n");
+        self.body.push_str(&elem);
+    }
+
+    /// Append a filled triangle with the given points.
+    ///
+    /// Used for exporting meshes, which have no outline to speak of.
+    pub fn triangle(&mut self, tri: geom::Tri<geom::Point3>, color: LinSrgba) {
+        let mut elem = String::from("<polygon points=\"");
+        for (i, p) in tri.vertices().enumerate() {
+            let [x, y] = self.to_svg_point(p.truncate());
+            if i > 0 {
+                elem.push(' ');
+            }
+            write!(elem, "{},{}", x, y).unwrap();
+        }
+        elem.push('"');
+        write_fill_attrs(&mut elem, color);
+        elem.push_str(" stroke=\"none\"/>\n");
+        self.body.push_str(&elem);
+    }
+
+    /// Write the document to the file at the given path.
+    pub fn save<P>(&self, path: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        std::fs::write(path, self.to_string())
+    }
+
+    // Convert a point in nannou's logical coordinate space to SVG's coordinate space.
+    fn to_svg_point(&self, p: Vec2) -> [f32; 2] {
+        [p.x + self.size.x * 0.5, self.size.y * 0.5 - p.y]
+    }
+
+    // Produce the `d` attribute for the given path events.
+    fn path_data<I>(&self, events: I, transform: Mat4) -> String
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let mut d = String::new();
+        let pt = |d: &mut String, p: lyon::math::Point| {
+            let p = transform.transform_point3([p.x, p.y, 0.0].into());
+            let [x, y] = self.to_svg_point(p.truncate());
+            write!(d, " {} {}", x, y).unwrap();
+        };
+        for event in events {
+            match event {
+                PathEvent::Begin { at } => {
+                    d.push('M');
+                    pt(&mut d, at);
+                }
+                PathEvent::Line { to, .. } => {
+                    d.push_str(" L");
+                    pt(&mut d, to);
+                }
+                PathEvent::Quadratic { ctrl, to, .. } => {
+                    d.push_str(" Q");
+                    pt(&mut d, ctrl);
+                    pt(&mut d, to);
+                }
+                PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    d.push_str(" C");
+                    pt(&mut d, ctrl1);
+                    pt(&mut d, ctrl2);
+                    pt(&mut d, to);
+                }
+                PathEvent::End { close, .. } => {
+                    if close {
+                        d.push_str(" Z");
+                    }
+                    d.push(' ');
+                }
+            }
+        }
+        d.trim_end().to_string()
+    }
+
+    // Write the given gradient to the defs, returning its ID.
+    //
+    // The gradient is described in the local space of `transform`, so the transform from that space
+    // into SVG's coordinate space is applied via `gradientTransform`.
+    fn gradient_def(&mut self, gradient: &Gradient, transform: Mat4) -> String {
+        let id = format!("gradient{}", self.gradient_count);
+        self.gradient_count += 1;
+        let (x, y, w) = (transform.x_axis, transform.y_axis, transform.w_axis);
+        let matrix = [
+            x.x,
+            -x.y,
+            y.x,
+            -y.y,
+            w.x + self.size.x * 0.5,
+            self.size.y * 0.5 - w.y,
+        ];
+        let (tag, attrs) = match gradient.kind {
+            GradientKind::Linear { start, end } => (
+                "linearGradient",
+                format!(
+                    "x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+                    start.x, start.y, end.x, end.y
+                ),
+            ),
+            GradientKind::Radial { center, radius } => (
+                "radialGradient",
+                format!("cx=\"{}\" cy=\"{}\" r=\"{}\"", center.x, center.y, radius),
+            ),
+        };
+        writeln!(
+            self.defs,
+            "<{} id=\"{}\" gradientUnits=\"userSpaceOnUse\" color-interpolation=\"linearRGB\" \
+             gradientTransform=\"matrix({} {} {} {} {} {})\" {}>",
+            tag, id, matrix[0], matrix[1], matrix[2], matrix[3], matrix[4], matrix[5], attrs,
+        )
+        .unwrap();
+        for &(offset, color) in &gradient.stops {
+            let (hex, a) = svg_color(color);
+            write!(
+                self.defs,
+                "<stop offset=\"{}\" stop-color=\"{}\"",
+                offset, hex
+            )
+            .unwrap();
+            if a < 1.0 {
+                write!(self.defs, " stop-opacity=\"{}\"", a).unwrap();
+            }
+            self.defs.push_str("/>\n");
+        }
+        writeln!(self.defs, "</{}>", tag).unwrap();
+        id
+    }
+
+    // Close the current clip group, if any, and begin a new one for the given scissor.
+    fn set_scissor(&mut self, scissor: draw::Scissor) {
+        self.close_clip_group();
+        if let draw::Scissor::Rect(rect) = scissor {
+            let id = self.clip_count;
+            self.clip_count += 1;
+            let [x, y] = self.to_svg_point(rect.top_left());
+            writeln!(
+                self.defs,
+                "<clipPath id=\"clip{}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/></clipPath>",
+                id,
+                x,
+                y,
+                rect.w(),
+                rect.h(),
+            )
+            .unwrap();
+            writeln!(self.body, "<g clip-path=\"url(#clip{})\">", id).unwrap();
+            self.clip_group_open = true;
+        }
+    }
+
+    fn close_clip_group(&mut self) {
+        if self.clip_group_open {
+            self.body.push_str("</g>\n");
+            self.clip_group_open = false;
+        }
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [w, h] = self.size.to_array();
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+            w, h, w, h,
+        )?;
+        if !self.defs.is_empty() {
+            writeln!(f, "<defs>\n{}</defs>", self.defs)?;
+        }
+        if let Some(bg) = self.background {
+            let mut elem = format!("<rect width=\"{}\" height=\"{}\"", w, h);
+            write_fill_attrs(&mut elem, bg);
+            writeln!(f, "{}/>", elem)?;
+        }
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+impl SvgPrimitive for draw::Primitive {
+    fn svg_primitive(self, ctxt: SvgContext, svg: &mut Svg) {
+        match self {
+            draw::Primitive::Arrow(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Cone(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Cuboid(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Cylinder(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Mesh(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Path(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Plane(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Polygon(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Tri(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Ellipse(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Quad(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Rect(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Sphere(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Line(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Text(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Torus(prim) => prim.svg_primitive(ctxt, svg),
+            // Textures require reading back pixels from the GPU and are not exported.
+            _ => (),
+        }
+    }
+}
+
+/// Append a path element for the given events, falling back to the theme color if necessary.
+///
+/// This is the SVG equivalent of the renderer's `path::render_path_events`.
+pub(crate) fn path_events<I>(
+    svg: &mut Svg,
+    events: I,
+    color: Option<LinSrgba>,
+    transform: Mat4,
+    options: &path::Options,
+    theme: &draw::Theme,
+    theme_prim: &draw::theme::Primitive,
+) where
+    I: IntoIterator<Item = PathEvent>,
+{
+    let color = color.unwrap_or_else(|| match *options {
+        path::Options::Fill(_) => theme.fill_lin_srgba(theme_prim),
+        path::Options::Stroke(_) => theme.stroke_lin_srgba(theme_prim),
+    });
+    svg.path(events, color, transform, options);
+}
+
+// The factor by which the given transform scales lengths in the xy plane.
+fn transform_scale(transform: &Mat4) -> f32 {
+    let x = transform.x_axis;
+    let y = transform.y_axis;
+    (x.x * y.y - y.x * x.y).abs().sqrt()
+}
+
+// Convert a linear color to an SVG hex color and opacity.
+fn svg_color(color: LinSrgba) -> (String, f32) {
+    let (r, g, b, a) = Srgba::from_linear(color).into();
+    let to_u8 = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let hex = format!("#{:02x}{:02x}{:02x}", to_u8(r), to_u8(g), to_u8(b));
+    (hex, a)
+}
+
+fn write_fill_attrs(elem: &mut String, color: LinSrgba) {
+    let (hex, a) = svg_color(color);
+    write_paint_attrs(elem, "fill", &hex, a);
+}
+
+// Write the paint for the given attribute, e.g. `fill` or `stroke`, along with its opacity.
+fn write_paint_attrs(elem: &mut String, attr: &str, paint: &str, opacity: f32) {
+    write!(elem, " {}=\"{}\"", attr, paint).unwrap();
+    if opacity < 1.0 {
+        write!(elem, " {}-opacity=\"{}\"", attr, opacity).unwrap();
+    }
+}
@@ -24,16 +24,21 @@ pub struct Color {
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Primitive {
     Arrow,
+    Cone,
     Cuboid,
+    Cylinder,
     Ellipse,
     Line,
     Mesh,
     Path,
+    Plane,
     Polygon,
     Quad,
     Rect,
+    Sphere,
     Text,
     Texture,
+    Torus,
     Tri,
 }
 
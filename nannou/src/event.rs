@@ -10,6 +10,7 @@ use crate::geom::{self, Point2};
 use crate::glam::Vec2;
 use crate::window;
 use crate::App;
+use serde_derive::{Deserialize, Serialize};
 use std::path::PathBuf;
 use winit;
 
@@ -22,19 +23,44 @@ pub use winit::event::{
 pub trait LoopEvent: 'static + From<Update> {
     /// Produce a loop event from the given winit event.
     fn from_winit_event<'a, T>(_: &winit::event::Event<'a, T>, _: &App) -> Option<Self>;
+
+    /// Produce a loop event from the given simplified window event.
+    ///
+    /// This is used to deliver events replayed from a **Recording**. By default, `None` is
+    /// returned and replayed window events are only delivered to window-specific functions.
+    fn from_window_event(_id: window::Id, _event: WindowEvent) -> Option<Self> {
+        None
+    }
+
+    /// Produce a loop event from the given gamepad event.
+    ///
+    /// By default, `None` is returned and gamepad events are only delivered to the gamepad
+    /// functions and the `app.gamepads` state.
+    fn from_gamepad_event(_event: GamepadEvent) -> Option<Self> {
+        None
+    }
 }
 
 /// Update event, emitted on each pass of an application loop.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Update {
     /// The duration since the last update was emitted.
     ///
     /// The first update's delta will be the time since the given `model` function returned.
+    ///
+    /// In the `LoopMode::Rate` mode this is always equal to the `update_interval`.
     pub since_last: std::time::Duration,
     /// The duration since the start of the app loop.
     ///
     /// Specifically, this is the duration of time since the given `model` function returned.
+    ///
+    /// In the `LoopMode::Rate` mode this is the sum of the intervals of all updates so far.
     pub since_start: std::time::Duration,
+    /// The time that has accumulated but is yet to be consumed by an update.
+    ///
+    /// This is always zero unless the app is running in the `LoopMode::Rate` mode, in which case
+    /// it is less than the `update_interval`. See `alpha` for this as a fraction of the interval.
+    pub leftover: std::time::Duration,
 }
 
 /// The default application **Event** type.
@@ -65,10 +91,93 @@ pub enum Event {
     Suspended,
     /// The application has been awakened.
     Resumed,
+
+    /// A gamepad was connected or disconnected, or one of its buttons or axes changed.
+    ///
+    /// Gamepad events are only emitted when nannou is built with the `gamepad` feature.
+    Gamepad(GamepadEvent),
+}
+
+/// Uniquely identifies a gamepad for as long as it remains connected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct GamepadId(pub usize);
+
+/// An event emitted by a single gamepad.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GamepadEvent {
+    /// The gamepad that emitted the event.
+    pub id: GamepadId,
+    /// The kind of event that occurred.
+    pub kind: GamepadEventKind,
+}
+
+/// The kinds of events that may be emitted by a gamepad.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GamepadEventKind {
+    /// The gamepad with the given name was connected.
+    ///
+    /// Gamepads that are already connected when the app starts emit this event before the first
+    /// update.
+    Connected { name: String },
+    /// The gamepad was disconnected.
+    Disconnected,
+    /// The given button was pressed.
+    ButtonPressed(GamepadButton),
+    /// The given button was released.
+    ButtonReleased(GamepadButton),
+    /// The value of an analog button such as a trigger changed, in the range `0.0..=1.0`.
+    ButtonChanged(GamepadButton, f32),
+    /// The value of the given axis changed, in the range `-1.0..=1.0`.
+    ///
+    /// Positive `y` points upwards, matching the window coordinate system.
+    AxisChanged(GamepadAxis, f32),
+}
+
+/// The buttons of a gamepad, named after their position on a standard controller layout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GamepadButton {
+    /// The bottom action button, e.g. `A` on Xbox and `Cross` on PlayStation controllers.
+    South,
+    /// The right action button, e.g. `B` on Xbox and `Circle` on PlayStation controllers.
+    East,
+    /// The top action button, e.g. `Y` on Xbox and `Triangle` on PlayStation controllers.
+    North,
+    /// The left action button, e.g. `X` on Xbox and `Square` on PlayStation controllers.
+    West,
+    C,
+    Z,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Unknown,
+}
+
+/// The analog axes of a gamepad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    LeftZ,
+    RightStickX,
+    RightStickY,
+    RightZ,
+    DPadX,
+    DPadY,
+    Unknown,
 }
 
 /// The event associated with a touch at a single point.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TouchEvent {
     /// The unique ID associated with this touch, e.g. useful for distinguishing between fingers.
     pub id: u64,
@@ -79,9 +188,12 @@ pub struct TouchEvent {
 }
 
 /// Pressure on a touch pad.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TouchpadPressure {
     /// The unique ID associated with the device that emitted this event.
+    ///
+    /// Device IDs are not serialized. Deserialized events are given a dummy ID.
+    #[serde(skip, default = "dummy_device_id")]
     pub device_id: winit::event::DeviceId,
     /// The amount of pressure applied.
     pub pressure: f32,
@@ -110,7 +222,7 @@ pub struct AxisMotion {
 /// - positive `x` points to the right, negative `x` points to the left.
 /// - positive `y` points upwards, negative `y` points downwards.
 /// - positive `z` points into the screen, negative `z` points out of the screen.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum WindowEvent {
     /// The window has been moved to a new position.
     Moved(Point2),
@@ -174,7 +286,63 @@ pub enum WindowEvent {
     Closed,
 }
 
+impl Update {
+    /// The fraction of an `update_interval` that has accumulated but is yet to be consumed by an
+    /// update, in the range `0.0..1.0`.
+    ///
+    /// Useful for interpolating between the previous and current state of a simulation when
+    /// drawing. This is always `0.0` unless the app is running in the `LoopMode::Rate` mode.
+    pub fn alpha(&self) -> f32 {
+        let interval = self.since_last.as_secs_f64();
+        match interval > 0.0 {
+            true => (self.leftover.as_secs_f64() / interval) as f32,
+            false => 0.0,
+        }
+    }
+}
+
 impl WindowEvent {
+    /// Whether or not the event was produced by user input, i.e. the keyboard, mouse, touch or
+    /// dropped files, rather than by a change in the state of the window itself.
+    ///
+    /// Only input events are captured when recording via `app::Builder::record`.
+    pub fn is_input(&self) -> bool {
+        match *self {
+            WindowEvent::KeyPressed(_)
+            | WindowEvent::KeyReleased(_)
+            | WindowEvent::ReceivedCharacter(_)
+            | WindowEvent::MouseMoved(_)
+            | WindowEvent::MousePressed(_)
+            | WindowEvent::MouseReleased(_)
+            | WindowEvent::MouseEntered
+            | WindowEvent::MouseExited
+            | WindowEvent::MouseWheel(_, _)
+            | WindowEvent::HoveredFile(_)
+            | WindowEvent::DroppedFile(_)
+            | WindowEvent::HoveredFileCancelled
+            | WindowEvent::Touch(_)
+            | WindowEvent::TouchPressure(_) => true,
+            WindowEvent::Moved(_)
+            | WindowEvent::Resized(_)
+            | WindowEvent::Focused
+            | WindowEvent::Unfocused
+            | WindowEvent::Closed => false,
+        }
+    }
+
+    // Map the positions of mouse and touch events from the coordinates of the given window to
+    // the coordinates of its canvas.
+    pub(crate) fn to_canvas(self, window: &window::Window) -> Self {
+        match self {
+            WindowEvent::MouseMoved(pos) => WindowEvent::MouseMoved(window.to_canvas(pos)),
+            WindowEvent::Touch(mut touch) => {
+                touch.position = window.to_canvas(touch.position);
+                WindowEvent::Touch(touch)
+            }
+            event => event,
+        }
+    }
+
     /// Produce a simplified, new-user-friendly version of the given `winit::event::WindowEvent`.
     ///
     /// This strips rarely needed technical information from the event type such as information
@@ -319,7 +487,8 @@ impl LoopEvent for Event {
         let event = match event {
             winit::event::Event::WindowEvent { window_id, event } => {
                 let windows = app.windows.borrow();
-                let (win_w, win_h, scale_factor) = match windows.get(&window_id) {
+                let window = windows.get(&window_id);
+                let (win_w, win_h, scale_factor) = match window {
                     None => (0.0, 0.0, 1.0), // The window was likely closed, these will be ignored.
                     Some(window) => {
                         let sf = window.tracked_state.scale_factor;
@@ -329,7 +498,12 @@ impl LoopEvent for Event {
                     }
                 };
                 let simple =
-                    WindowEvent::from_winit_window_event(event, win_w, win_h, scale_factor);
+                    WindowEvent::from_winit_window_event(event, win_w, win_h, scale_factor).map(
+                        |simple| match window {
+                            Some(window) => simple.to_canvas(window),
+                            None => simple,
+                        },
+                    );
                 Event::WindowEvent {
                     id: window_id.clone(),
                     simple,
@@ -351,6 +525,17 @@ impl LoopEvent for Event {
         };
         Some(event)
     }
+
+    /// Wrap the given replayed window event in an `Event::WindowEvent`.
+    fn from_window_event(id: window::Id, event: WindowEvent) -> Option<Self> {
+        let simple = Some(event);
+        Some(Event::WindowEvent { id, simple })
+    }
+
+    /// Wrap the given gamepad event in an `Event::Gamepad`.
+    fn from_gamepad_event(event: GamepadEvent) -> Option<Self> {
+        Some(Event::Gamepad(event))
+    }
 }
 
 impl From<Update> for Event {
@@ -358,3 +543,8 @@ impl From<Update> for Event {
         Event::Update(update)
     }
 }
+
+// Device IDs cannot be serialized, so deserialized events are given a dummy ID.
+fn dummy_device_id() -> winit::event::DeviceId {
+    unsafe { winit::event::DeviceId::dummy() }
+}
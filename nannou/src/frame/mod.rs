@@ -3,12 +3,14 @@
 use crate::color::IntoLinSrgba;
 use crate::wgpu;
 use std::ops;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::Duration;
 
+pub mod post;
 pub mod raw;
 
+pub use self::post::{Effect, PostProcess};
 pub use self::raw::RawFrame;
 
 /// A **Frame** to which the user can draw graphics before it is presented to the display.
@@ -33,6 +35,8 @@ pub struct RenderData {
     size: [u32; 2],
     // For writing the intermediary linear sRGBA texture to the swap chain texture.
     texture_reshaper: wgpu::TextureReshaper,
+    // For applying the window's post-processing effects before writing to the swap chain texture.
+    pub(crate) post_processor: Mutex<post::PostProcessor>,
 }
 
 /// Data related to the capturing of a frame.
@@ -105,17 +109,38 @@ impl<'swap_chain> Frame<'swap_chain> {
             );
         }
 
+        // Apply any post-processing effects, producing the texture to be presented.
+        let mut post_processor = render_data
+            .post_processor
+            .lock()
+            .expect("failed to lock `post_processor`");
+        let post_target = {
+            let device = raw_frame.device_queue_pair().device();
+            let mut encoder = raw_frame.command_encoder();
+            post_processor.encode(
+                device,
+                &mut *encoder,
+                &render_data.intermediary_lin_srgba.texture_view,
+            )
+        };
+        let (output_texture, texture_reshaper) = match post_target {
+            Some(target) => (&target.texture, &target.reshaper),
+            None => (
+                &render_data.intermediary_lin_srgba.texture,
+                &render_data.texture_reshaper,
+            ),
+        };
+
         // Check to see if the user specified capturing the frame.
         let mut snapshot_capture = None;
         if let Ok(mut guard) = capture_data.next_frame_path.lock() {
             if let Some(path) = guard.take() {
                 let device = raw_frame.device_queue_pair().device();
                 let mut encoder = raw_frame.command_encoder();
-                let snapshot = capture_data.texture_capturer.capture(
-                    device,
-                    &mut *encoder,
-                    &render_data.intermediary_lin_srgba.texture,
-                );
+                let snapshot =
+                    capture_data
+                        .texture_capturer
+                        .capture(device, &mut *encoder, output_texture);
                 snapshot_capture = Some((path, snapshot));
             }
         }
@@ -126,10 +151,9 @@ impl<'swap_chain> Frame<'swap_chain> {
         // two triangles and a fragment shader.
         {
             let mut encoder = raw_frame.command_encoder();
-            render_data
-                .texture_reshaper
-                .encode_render_pass(raw_frame.swap_chain_texture(), &mut *encoder);
+            texture_reshaper.encode_render_pass(raw_frame.swap_chain_texture(), &mut *encoder);
         }
+        drop(post_processor);
 
         // Submit all commands on the device queue.
         raw_frame.submit_inner();
@@ -259,7 +283,8 @@ impl<'swap_chain> Frame<'swap_chain> {
     /// Before submission, the frame does the following:
     ///
     /// - If the frame's intermediary linear sRGBA texture is multisampled, resolve it.
-    /// - Write the intermediary linear sRGBA image to the swap chain texture.
+    /// - Apply the window's post-processing effects, if any.
+    /// - Write the resulting linear sRGBA image to the swap chain texture.
     ///
     /// It can sometimes be useful to submit the **Frame** before `view` completes in order to read
     /// the frame's texture back to the CPU (e.g. for screen shots, recordings, etc).
@@ -275,6 +300,19 @@ impl CaptureData {
             texture_capturer: wgpu::TextureCapturer::new(Some(max_jobs), timeout),
         }
     }
+
+    // Capture the next frame to the given path, creating the parent directory if necessary.
+    pub(crate) fn capture_next_frame(&self, path: &Path) {
+        let dir = path.parent().expect("capture_frame path has no directory");
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir).expect("failed to create `capture_frame` directory");
+        }
+        let mut next_frame_path = self
+            .next_frame_path
+            .lock()
+            .expect("failed to lock `capture_next_frame_path`");
+        *next_frame_path = Some(path.to_path_buf());
+    }
 }
 
 impl RenderData {
@@ -289,6 +327,7 @@ impl RenderData {
         swap_chain_dims: [u32; 2],
         swap_chain_format: wgpu::TextureFormat,
         msaa_samples: u32,
+        post_process: PostProcess,
     ) -> Self {
         let intermediary_lin_srgba =
             create_intermediary_lin_srgba(device, swap_chain_dims, msaa_samples);
@@ -302,9 +341,12 @@ impl RenderData {
             swap_chain_sample_count,
             swap_chain_format,
         );
+        let post_processor =
+            post::PostProcessor::new(post_process, swap_chain_dims, swap_chain_format);
         RenderData {
             intermediary_lin_srgba,
             texture_reshaper,
+            post_processor: Mutex::new(post_processor),
             size: swap_chain_dims,
             msaa_samples,
         }
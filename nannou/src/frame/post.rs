@@ -0,0 +1,577 @@
+//! Items related to post-processing the image of a **Frame** before it is presented.
+//!
+//! A window's **PostProcess** stack is an ordered list of fullscreen **Effect**s. After the
+//! **view** function returns, each effect is applied in turn to the frame's resolved intermediary
+//! linear sRGBA image before the result is written to the swapchain texture. The frame's own
+//! image is left untouched, so drawings that persist between frames are not affected.
+//!
+//! Set the stack for a window via `window::Builder::post_process` or `Window::set_post_process`.
+
+use crate::color::LinSrgb;
+use crate::frame::Frame;
+use crate::wgpu::{self, util::DeviceExt};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// An ordered list of fullscreen effects applied to each **Frame** of a window.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PostProcess {
+    /// The effects in the order in which they are applied.
+    pub effects: Vec<Effect>,
+}
+
+/// A single fullscreen effect within a **PostProcess** stack.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Effect {
+    /// A gaussian blur with the given radius in pixels.
+    Blur { radius: f32 },
+    /// Light brighter than the `threshold` luminance is blurred by `radius` pixels and added back
+    /// to the image, scaled by `intensity`.
+    Bloom {
+        threshold: f32,
+        intensity: f32,
+        radius: f32,
+    },
+    /// Adjust the exposure (in stops), contrast and saturation of the image and multiply it by the
+    /// given tint.
+    ColorGrade {
+        exposure: f32,
+        contrast: f32,
+        saturation: f32,
+        tint: LinSrgb,
+    },
+    /// Fade the image toward `color` beyond the given `radius` from the centre, where `1.0` is the
+    /// distance to the middle of each edge.
+    Vignette {
+        radius: f32,
+        softness: f32,
+        intensity: f32,
+        color: LinSrgb,
+    },
+    /// Offset the red and blue channels away from the centre by up to `amount` pixels.
+    ChromaticAberration { amount: f32 },
+    /// A user provided fragment shader. See **Shader** for the expected interface.
+    Custom { shader: Shader, params: [f32; 8] },
+}
+
+/// The source of the fragment shader used by a custom **Effect**.
+///
+/// The shader's fragment entry point must be named `main` and receives the texture coordinates
+/// of the fragment at location `0`. The following bindings are available within group `0`:
+///
+/// - `0`: a `texture_2d<f32>` containing the image produced by the previous effect.
+/// - `1`: a filtering `sampler` that clamps to the edge.
+/// - `2`: a uniform buffer with the layout below, where `params0` and `params1` hold the effect's
+///   eight `params`.
+/// - `3`: a `texture_2d<f32>` containing the image before the effect began. For custom effects
+///   this is the same image as binding `0`.
+///
+/// ```wgsl
+/// [[block]]
+/// struct Uniforms {
+///     resolution: vec2<f32>;
+///     texel: vec2<f32>;
+///     params0: vec4<f32>;
+///     params1: vec4<f32>;
+/// };
+/// ```
+///
+/// GLSL shaders may be used by compiling them to SPIR-V, e.g. via `glslc`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Shader {
+    /// WGSL source code.
+    Wgsl(Cow<'static, str>),
+    /// SPIR-V bytes.
+    #[cfg(feature = "spirv")]
+    Spirv(Cow<'static, [u8]>),
+}
+
+impl PostProcess {
+    /// An empty post-processing stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether or not the stack contains any effects.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Append the given effect to the end of the stack.
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+
+    /// Append a gaussian blur with the given radius in pixels.
+    pub fn blur(self, radius: f32) -> Self {
+        self.effect(Effect::Blur { radius })
+    }
+
+    /// Append a bloom effect.
+    ///
+    /// Light brighter than the `threshold` luminance is blurred by `radius` pixels and added back
+    /// to the image, scaled by `intensity`.
+    pub fn bloom(self, threshold: f32, intensity: f32, radius: f32) -> Self {
+        self.effect(Effect::Bloom {
+            threshold,
+            intensity,
+            radius,
+        })
+    }
+
+    /// Append a color grading effect adjusting the exposure (in stops), contrast and saturation.
+    ///
+    /// Neutral values are `0.0`, `1.0` and `1.0` respectively.
+    pub fn color_grade(self, exposure: f32, contrast: f32, saturation: f32) -> Self {
+        let tint = LinSrgb::new(1.0, 1.0, 1.0);
+        self.effect(Effect::ColorGrade {
+            exposure,
+            contrast,
+            saturation,
+            tint,
+        })
+    }
+
+    /// Append a black vignette that begins at `radius` from the centre and reaches full strength
+    /// `softness` further out.
+    pub fn vignette(self, radius: f32, softness: f32) -> Self {
+        let color = LinSrgb::new(0.0, 0.0, 0.0);
+        self.effect(Effect::Vignette {
+            radius,
+            softness,
+            intensity: 1.0,
+            color,
+        })
+    }
+
+    /// Append a chromatic aberration effect offsetting the red and blue channels by up to `amount`
+    /// pixels.
+    pub fn chromatic_aberration(self, amount: f32) -> Self {
+        self.effect(Effect::ChromaticAberration { amount })
+    }
+
+    /// Append an effect using the given WGSL fragment shader and parameters.
+    ///
+    /// See **Shader** for the interface expected of the shader.
+    pub fn wgsl<S>(self, source: S, params: [f32; 8]) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        let shader = Shader::Wgsl(source.into());
+        self.effect(Effect::Custom { shader, params })
+    }
+}
+
+impl Effect {
+    // The fullscreen passes used to apply the effect.
+    fn programs(&self) -> Vec<(Program, [f32; 8])> {
+        match *self {
+            Effect::Blur { radius } => blur_programs(radius),
+            Effect::Bloom {
+                threshold,
+                intensity,
+                radius,
+            } => {
+                let mut programs = vec![(Program::Threshold, params(&[threshold], &[]))];
+                programs.extend(blur_programs(radius));
+                programs.push((Program::Composite, params(&[intensity], &[])));
+                programs
+            }
+            Effect::ColorGrade {
+                exposure,
+                contrast,
+                saturation,
+                tint,
+            } => {
+                let a = [exposure, contrast, saturation];
+                let b = [tint.red, tint.green, tint.blue];
+                vec![(Program::ColorGrade, params(&a, &b))]
+            }
+            Effect::Vignette {
+                radius,
+                softness,
+                intensity,
+                color,
+            } => {
+                let a = [radius, softness, intensity];
+                let b = [color.red, color.green, color.blue];
+                vec![(Program::Vignette, params(&a, &b))]
+            }
+            Effect::ChromaticAberration { amount } => {
+                vec![(Program::ChromaticAberration, params(&[amount], &[]))]
+            }
+            Effect::Custom { ref shader, params } => {
+                vec![(Program::Custom(shader.clone()), params)]
+            }
+        }
+    }
+}
+
+// The fragment shader used by a single fullscreen pass.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Program {
+    Blur,
+    Threshold,
+    Composite,
+    ColorGrade,
+    Vignette,
+    ChromaticAberration,
+    Custom(Shader),
+}
+
+// The image read or written by a pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Image {
+    // The frame's resolved intermediary texture.
+    Frame,
+    // One of the post processor's own targets.
+    Target(usize),
+}
+
+// A single fullscreen pass of an effect.
+#[derive(Clone, Debug, PartialEq)]
+struct Pass {
+    program: Program,
+    input: Image,
+    original: Image,
+    output: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Uniforms {
+    resolution: [f32; 2],
+    texel: [f32; 2],
+    params: [f32; 8],
+}
+
+/// Applies a window's **PostProcess** stack to each frame.
+///
+/// GPU resources are only created once the stack contains at least one effect.
+#[derive(Debug)]
+pub(crate) struct PostProcessor {
+    post_process: PostProcess,
+    size: [u32; 2],
+    dst_format: wgpu::TextureFormat,
+    gpu: Option<Gpu>,
+}
+
+// The GPU resources used to apply the effects.
+#[derive(Debug)]
+struct Gpu {
+    vs_mod: wgpu::ShaderModule,
+    fs_mod: wgpu::ShaderModule,
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    pipelines: HashMap<Program, wgpu::RenderPipeline>,
+    targets: Vec<Target>,
+    passes: Vec<(Pass, wgpu::Buffer, wgpu::BindGroup)>,
+}
+
+/// A texture written by a post-processing pass, along with the reshaper used to write it to the
+/// swapchain texture.
+#[derive(Debug)]
+pub(crate) struct Target {
+    pub(crate) texture: wgpu::Texture,
+    pub(crate) texture_view: wgpu::TextureView,
+    pub(crate) reshaper: wgpu::TextureReshaper,
+}
+
+impl PostProcessor {
+    // The number of targets required to apply any effect. One target may hold the image from
+    // before the effect began, one the input of the current pass and one its output.
+    const TARGET_COUNT: usize = 3;
+
+    pub(crate) fn new(
+        post_process: PostProcess,
+        size: [u32; 2],
+        dst_format: wgpu::TextureFormat,
+    ) -> Self {
+        PostProcessor {
+            post_process,
+            size,
+            dst_format,
+            gpu: None,
+        }
+    }
+
+    /// The current post-processing stack.
+    pub(crate) fn post_process(&self) -> &PostProcess {
+        &self.post_process
+    }
+
+    /// Replace the post-processing stack.
+    pub(crate) fn set_post_process(&mut self, post_process: PostProcess) {
+        self.post_process = post_process;
+    }
+
+    /// Encode the passes of all effects, reading from the given resolved frame texture.
+    ///
+    /// Returns the target containing the final image, or `None` if the stack is empty.
+    pub(crate) fn encode(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        frame_view: &wgpu::TextureView,
+    ) -> Option<&Target> {
+        if self.post_process.is_empty() {
+            return None;
+        }
+
+        let size = self.size;
+        let dst_format = self.dst_format;
+        let gpu = self
+            .gpu
+            .get_or_insert_with(|| Gpu::new(device, size, dst_format));
+
+        // Only recreate the uniform buffers and bind groups if the structure of the stack changed.
+        let (passes, params) = plan(&self.post_process.effects);
+        if gpu.passes.len() != passes.len()
+            || gpu.passes.iter().zip(&passes).any(|(a, b)| a.0 != *b)
+        {
+            let new_passes: Vec<_> = passes
+                .into_iter()
+                .map(|pass| gpu.create_pass(device, frame_view, pass))
+                .collect();
+            gpu.passes = new_passes;
+        }
+
+        // Upload the parameters of each pass.
+        let [w, h] = size;
+        let uniforms: Vec<Uniforms> = params
+            .into_iter()
+            .map(|params| Uniforms {
+                resolution: [w as f32, h as f32],
+                texel: [1.0 / w as f32, 1.0 / h as f32],
+                params,
+            })
+            .collect();
+        let uniforms_bytes = uniforms_as_bytes(&uniforms);
+        let new_uniform_buffer = device.create_buffer_init(&wgpu::BufferInitDescriptor {
+            label: Some("nannou_post_process_uniforms"),
+            contents: uniforms_bytes,
+            usage: wgpu::BufferUsages::COPY_SRC,
+        });
+        let uniforms_size = std::mem::size_of::<Uniforms>() as wgpu::BufferAddress;
+        for (i, (_, buffer, _)) in gpu.passes.iter().enumerate() {
+            let offset = i as wgpu::BufferAddress * uniforms_size;
+            encoder.copy_buffer_to_buffer(&new_uniform_buffer, offset, buffer, 0, uniforms_size);
+        }
+
+        // Encode the passes.
+        for (pass, _, bind_group) in &gpu.passes {
+            let pipeline = &gpu.pipelines[&pass.program];
+            let output = &gpu.targets[pass.output].texture_view;
+            let mut render_pass = wgpu::RenderPassBuilder::new()
+                .color_attachment(output, |color| color)
+                .begin(encoder);
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        let gpu: &Gpu = gpu;
+        gpu.passes
+            .last()
+            .map(|(pass, _, _)| &gpu.targets[pass.output])
+    }
+}
+
+impl Gpu {
+    fn new(device: &wgpu::Device, size: [u32; 2], dst_format: wgpu::TextureFormat) -> Self {
+        let vs_desc = wgpu::include_wgsl!("shaders/post_vs.wgsl");
+        let fs_desc = wgpu::include_wgsl!("shaders/post_fs.wgsl");
+        let vs_mod = device.create_shader_module(&vs_desc);
+        let fs_mod = device.create_shader_module(&fs_desc);
+
+        let sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
+        let sampler_filtering = wgpu::sampler_filtering(&sampler_desc);
+        let sampler = device.create_sampler(&sampler_desc);
+
+        let sample_type = wgpu::TextureSampleType::Float { filterable: true };
+        let bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .sampler(wgpu::ShaderStages::FRAGMENT, sampler_filtering)
+            .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .build(device);
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("nannou_post_process"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let targets = (0..PostProcessor::TARGET_COUNT)
+            .map(|_| Target::new(device, size, dst_format))
+            .collect();
+
+        Gpu {
+            vs_mod,
+            fs_mod,
+            sampler,
+            bind_group_layout,
+            pipeline_layout,
+            pipelines: HashMap::new(),
+            targets,
+            passes: vec![],
+        }
+    }
+
+    // Create the uniform buffer and bind group for the given pass, along with its pipeline if
+    // this is the first pass to use its program.
+    fn create_pass(
+        &mut self,
+        device: &wgpu::Device,
+        frame_view: &wgpu::TextureView,
+        pass: Pass,
+    ) -> (Pass, wgpu::Buffer, wgpu::BindGroup) {
+        if !self.pipelines.contains_key(&pass.program) {
+            let pipeline = self.create_pipeline(device, &pass.program);
+            self.pipelines.insert(pass.program.clone(), pipeline);
+        }
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("nannou_post_process_uniforms"),
+            size: std::mem::size_of::<Uniforms>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let view = |image: Image| match image {
+            Image::Frame => frame_view,
+            Image::Target(ix) => &self.targets[ix].texture_view,
+        };
+        let bind_group = wgpu::BindGroupBuilder::new()
+            .texture_view(view(pass.input))
+            .sampler(&self.sampler)
+            .buffer::<Uniforms>(&uniform_buffer, 0..1)
+            .texture_view(view(pass.original))
+            .build(device, &self.bind_group_layout);
+        (pass, uniform_buffer, bind_group)
+    }
+
+    fn create_pipeline(&self, device: &wgpu::Device, program: &Program) -> wgpu::RenderPipeline {
+        let entry_point = match *program {
+            Program::Blur => "blur",
+            Program::Threshold => "threshold",
+            Program::Composite => "composite",
+            Program::ColorGrade => "color_grade",
+            Program::Vignette => "vignette",
+            Program::ChromaticAberration => "chromatic_aberration",
+            Program::Custom(_) => "main",
+        };
+        let custom_mod = match *program {
+            Program::Custom(ref shader) => Some(shader.create_module(device)),
+            _ => None,
+        };
+        let fs_mod = custom_mod.as_ref().unwrap_or(&self.fs_mod);
+        wgpu::RenderPipelineBuilder::from_layout(&self.pipeline_layout, &self.vs_mod)
+            .fragment_shader(fs_mod)
+            .fragment_entry_point(entry_point)
+            .color_format(Frame::TEXTURE_FORMAT)
+            .color_blend(wgpu::BlendComponent::REPLACE)
+            .alpha_blend(wgpu::BlendComponent::REPLACE)
+            .build(device)
+    }
+}
+
+impl Target {
+    fn new(device: &wgpu::Device, size: [u32; 2], dst_format: wgpu::TextureFormat) -> Self {
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(Frame::TEXTURE_FORMAT)
+            .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING)
+            .build(device);
+        let texture_view = texture.view().build();
+        let src_sample_count = 1;
+        let dst_sample_count = 1;
+        let reshaper = wgpu::TextureReshaper::new(
+            device,
+            &texture_view,
+            src_sample_count,
+            texture_view.sample_type(),
+            dst_sample_count,
+            dst_format,
+        );
+        Target {
+            texture,
+            texture_view,
+            reshaper,
+        }
+    }
+}
+
+impl Shader {
+    fn create_module(&self, device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = match *self {
+            Shader::Wgsl(ref src) => wgpu::ShaderSource::Wgsl(src.clone()),
+            #[cfg(feature = "spirv")]
+            Shader::Spirv(ref bytes) => wgpu::util::make_spirv(&bytes[..]),
+        };
+        let desc = wgpu::ShaderModuleDescriptor {
+            label: Some("nannou_post_process_custom"),
+            source,
+        };
+        device.create_shader_module(&desc)
+    }
+}
+
+// Produce the passes of all effects along with the parameters of each.
+//
+// Each pass writes to whichever target holds neither its input nor the image from before its
+// effect began.
+fn plan(effects: &[Effect]) -> (Vec<Pass>, Vec<[f32; 8]>) {
+    let mut passes = vec![];
+    let mut params = vec![];
+    let mut current = Image::Frame;
+    for effect in effects {
+        let original = current;
+        for (program, p) in effect.programs() {
+            let output = (0..PostProcessor::TARGET_COUNT)
+                .find(|&ix| Image::Target(ix) != current && Image::Target(ix) != original)
+                .expect("no free post-processing target");
+            passes.push(Pass {
+                program,
+                input: current,
+                original,
+                output,
+            });
+            params.push(p);
+            current = Image::Target(output);
+        }
+    }
+    (passes, params)
+}
+
+fn blur_programs(radius: f32) -> Vec<(Program, [f32; 8])> {
+    vec![
+        (Program::Blur, params(&[radius, 1.0, 0.0], &[])),
+        (Program::Blur, params(&[radius, 0.0, 1.0], &[])),
+    ]
+}
+
+// Pack up to four values for each of the `params0` and `params1` uniforms.
+fn params(a: &[f32], b: &[f32]) -> [f32; 8] {
+    let mut params = [0.0; 8];
+    for (p, &v) in params[..4].iter_mut().zip(a) {
+        *p = v;
+    }
+    for (p, &v) in params[4..].iter_mut().zip(b) {
+        *p = v;
+    }
+    params
+}
+
+fn uniforms_as_bytes(uniforms: &[Uniforms]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(uniforms) }
+}
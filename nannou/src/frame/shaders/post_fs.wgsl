@@ -0,0 +1,122 @@
+struct FragmentOutput {
+    [[location(0)]] out_color: vec4<f32>;
+};
+
+[[block]]
+struct Uniforms {
+    resolution: vec2<f32>;
+    texel: vec2<f32>;
+    params0: vec4<f32>;
+    params1: vec4<f32>;
+};
+
+[[group(0), binding(0)]]
+var tex: texture_2d<f32>;
+[[group(0), binding(1)]]
+var tex_sampler: sampler;
+[[group(0), binding(2)]]
+var<uniform> uniforms: Uniforms;
+[[group(0), binding(3)]]
+var original: texture_2d<f32>;
+
+fn luminance(rgb: vec3<f32>) -> f32 {
+    return dot(rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+// A one dimensional gaussian blur.
+//
+// params0: radius, direction x, direction y.
+[[stage(fragment)]]
+fn blur(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let radius: f32 = uniforms.params0.x;
+    let delta: vec2<f32> = uniforms.params0.yz * uniforms.texel;
+    let sigma: f32 = max(radius * 0.5, 0.0001);
+    let taps: i32 = min(i32(ceil(radius)), 64);
+    var color: vec4<f32> = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total: f32 = 0.0;
+    var i: i32 = -taps;
+    loop {
+        if (i > taps) {
+            break;
+        }
+        let x: f32 = f32(i);
+        let weight: f32 = exp(-(x * x) / (2.0 * sigma * sigma));
+        color = color + textureSample(tex, tex_sampler, tex_coords + delta * x) * weight;
+        total = total + weight;
+        continuing {
+            i = i + 1;
+        }
+    }
+    return FragmentOutput(color / vec4<f32>(total));
+}
+
+// Keep only the light brighter than the threshold.
+//
+// params0: threshold.
+[[stage(fragment)]]
+fn threshold(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let brightness: f32 = luminance(color.rgb);
+    let factor: f32 = max(brightness - uniforms.params0.x, 0.0) / max(brightness, 0.0001);
+    return FragmentOutput(vec4<f32>(color.rgb * factor, color.a));
+}
+
+// Add the input to the image from before the effect began.
+//
+// params0: intensity.
+[[stage(fragment)]]
+fn composite(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let light: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let base: vec4<f32> = textureSample(original, tex_sampler, tex_coords);
+    let rgb: vec3<f32> = base.rgb + light.rgb * uniforms.params0.x;
+    return FragmentOutput(vec4<f32>(rgb, base.a));
+}
+
+// params0: exposure, contrast, saturation.
+// params1: tint.
+[[stage(fragment)]]
+fn color_grade(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let mid_grey: vec3<f32> = vec3<f32>(0.18);
+    var rgb: vec3<f32> = color.rgb * exp2(uniforms.params0.x) * uniforms.params1.rgb;
+    rgb = (rgb - mid_grey) * uniforms.params0.y + mid_grey;
+    rgb = mix(vec3<f32>(luminance(rgb)), rgb, vec3<f32>(uniforms.params0.z));
+    return FragmentOutput(vec4<f32>(max(rgb, vec3<f32>(0.0)), color.a));
+}
+
+// params0: radius, softness, intensity.
+// params1: color.
+[[stage(fragment)]]
+fn vignette(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let dist: f32 = length(tex_coords - vec2<f32>(0.5)) * 2.0;
+    let radius: f32 = uniforms.params0.x;
+    let edge: f32 = radius + max(uniforms.params0.y, 0.0001);
+    let amount: f32 = smoothStep(radius, edge, dist) * uniforms.params0.z;
+    let rgb: vec3<f32> = mix(color.rgb, uniforms.params1.rgb, vec3<f32>(amount));
+    return FragmentOutput(vec4<f32>(rgb, color.a));
+}
+
+// Offsets the red and blue channels away from the centre.
+//
+// params0: amount in pixels at the edge.
+[[stage(fragment)]]
+fn chromatic_aberration(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let offset: vec2<f32> = (tex_coords - vec2<f32>(0.5)) * 2.0 * uniforms.params0.x * uniforms.texel;
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let r: f32 = textureSample(tex, tex_sampler, tex_coords + offset).r;
+    let b: f32 = textureSample(tex, tex_sampler, tex_coords - offset).b;
+    return FragmentOutput(vec4<f32>(r, color.g, b, color.a));
+}
@@ -0,0 +1,16 @@
+struct VertexOutput {
+    [[location(0)]] tex_coords: vec2<f32>;
+    [[builtin(position)]] out_pos: vec4<f32>;
+};
+
+// Produces a single triangle that covers the whole output.
+[[stage(vertex)]]
+fn main(
+    [[builtin(vertex_index)]] vertex_index: u32,
+) -> VertexOutput {
+    let x: f32 = f32(i32(vertex_index & 1u) * 4 - 1);
+    let y: f32 = f32(i32(vertex_index >> 1u) * 4 - 1);
+    let out_pos: vec4<f32> = vec4<f32>(x, y, 0.0, 1.0);
+    let tex_coords: vec2<f32> = vec2<f32>(x * 0.5 + 0.5, 1.0 - (y * 0.5 + 0.5));
+    return VertexOutput(tex_coords, out_pos);
+}
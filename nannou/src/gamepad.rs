@@ -0,0 +1,144 @@
+//! Polls connected gamepads for events.
+//!
+//! Gamepad support is provided by `gilrs` and requires the `gamepad` feature. Without the
+//! feature, the backend never produces any events.
+
+use crate::event::GamepadEvent;
+
+pub(crate) struct Backend {
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<gilrs::Gilrs>,
+    #[cfg(feature = "gamepad")]
+    initial: Vec<GamepadEvent>,
+}
+
+impl Backend {
+    // Connect to the OS gamepad API.
+    //
+    // If the API is unavailable, a warning is printed and no gamepad events are produced.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn new() -> Self {
+        use crate::event::{GamepadEventKind, GamepadId};
+        let gilrs = match gilrs::Gilrs::new() {
+            Ok(gilrs) => Some(gilrs),
+            Err(gilrs::Error::NotImplemented(gilrs)) => Some(gilrs),
+            Err(err) => {
+                // TODO: Replace eprintlns with proper logging.
+                eprintln!("failed to initialise gamepad support: {}", err);
+                None
+            }
+        };
+        // Gamepads connected before the app started are announced before the first update.
+        let initial = gilrs
+            .iter()
+            .flat_map(|gilrs| gilrs.gamepads())
+            .map(|(id, pad)| GamepadEvent {
+                id: GamepadId(id.into()),
+                kind: GamepadEventKind::Connected {
+                    name: pad.name().to_string(),
+                },
+            })
+            .collect();
+        Backend { gilrs, initial }
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn new() -> Self {
+        Backend {}
+    }
+
+    // Whether or not gamepads are being polled for events.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn is_active(&self) -> bool {
+        self.gilrs.is_some()
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn is_active(&self) -> bool {
+        false
+    }
+
+    // Produce the next pending gamepad event.
+    #[cfg(feature = "gamepad")]
+    pub(crate) fn next_event(&mut self) -> Option<GamepadEvent> {
+        use crate::event::{GamepadEventKind, GamepadId};
+        if !self.initial.is_empty() {
+            return Some(self.initial.remove(0));
+        }
+        let gilrs = self.gilrs.as_mut()?;
+        while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+            let kind = match event {
+                gilrs::EventType::Connected => GamepadEventKind::Connected {
+                    name: gilrs.gamepad(id).name().to_string(),
+                },
+                gilrs::EventType::Disconnected => GamepadEventKind::Disconnected,
+                gilrs::EventType::ButtonPressed(button, _) => {
+                    GamepadEventKind::ButtonPressed(convert::button(button))
+                }
+                gilrs::EventType::ButtonReleased(button, _) => {
+                    GamepadEventKind::ButtonReleased(convert::button(button))
+                }
+                gilrs::EventType::ButtonChanged(button, value, _) => {
+                    GamepadEventKind::ButtonChanged(convert::button(button), value)
+                }
+                gilrs::EventType::AxisChanged(axis, value, _) => {
+                    GamepadEventKind::AxisChanged(convert::axis(axis), value)
+                }
+                // Repeats and dropped events carry no new state.
+                _ => continue,
+            };
+            let id = GamepadId(id.into());
+            return Some(GamepadEvent { id, kind });
+        }
+        None
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    pub(crate) fn next_event(&mut self) -> Option<GamepadEvent> {
+        None
+    }
+}
+
+#[cfg(feature = "gamepad")]
+mod convert {
+    use crate::event::{GamepadAxis, GamepadButton};
+
+    pub fn button(button: gilrs::Button) -> GamepadButton {
+        match button {
+            gilrs::Button::South => GamepadButton::South,
+            gilrs::Button::East => GamepadButton::East,
+            gilrs::Button::North => GamepadButton::North,
+            gilrs::Button::West => GamepadButton::West,
+            gilrs::Button::C => GamepadButton::C,
+            gilrs::Button::Z => GamepadButton::Z,
+            gilrs::Button::LeftTrigger => GamepadButton::LeftTrigger,
+            gilrs::Button::LeftTrigger2 => GamepadButton::LeftTrigger2,
+            gilrs::Button::RightTrigger => GamepadButton::RightTrigger,
+            gilrs::Button::RightTrigger2 => GamepadButton::RightTrigger2,
+            gilrs::Button::Select => GamepadButton::Select,
+            gilrs::Button::Start => GamepadButton::Start,
+            gilrs::Button::Mode => GamepadButton::Mode,
+            gilrs::Button::LeftThumb => GamepadButton::LeftThumb,
+            gilrs::Button::RightThumb => GamepadButton::RightThumb,
+            gilrs::Button::DPadUp => GamepadButton::DPadUp,
+            gilrs::Button::DPadDown => GamepadButton::DPadDown,
+            gilrs::Button::DPadLeft => GamepadButton::DPadLeft,
+            gilrs::Button::DPadRight => GamepadButton::DPadRight,
+            gilrs::Button::Unknown => GamepadButton::Unknown,
+        }
+    }
+
+    pub fn axis(axis: gilrs::Axis) -> GamepadAxis {
+        match axis {
+            gilrs::Axis::LeftStickX => GamepadAxis::LeftStickX,
+            gilrs::Axis::LeftStickY => GamepadAxis::LeftStickY,
+            gilrs::Axis::LeftZ => GamepadAxis::LeftZ,
+            gilrs::Axis::RightStickX => GamepadAxis::RightStickX,
+            gilrs::Axis::RightStickY => GamepadAxis::RightStickY,
+            gilrs::Axis::RightZ => GamepadAxis::RightZ,
+            gilrs::Axis::DPadX => GamepadAxis::DPadX,
+            gilrs::Axis::DPadY => GamepadAxis::DPadY,
+            gilrs::Axis::Unknown => GamepadAxis::Unknown,
+        }
+    }
+}
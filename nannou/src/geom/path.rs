@@ -109,6 +109,12 @@ impl Builder {
         self
     }
 
+    /// Ends the current sub-path without closing it.
+    pub fn end(mut self) -> Self {
+        self.builder.end(false);
+        self
+    }
+
     /// Closes the current sub path and sets the current position to the first position of the
     /// current sub-path.
     pub fn close(mut self) -> Self {
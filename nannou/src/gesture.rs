@@ -0,0 +1,368 @@
+//! Recognition of multi-touch gestures from a stream of **TouchEvent**s.
+//!
+//! Each window of an **App** owns a **Recognizer** that consumes the window's
+//! `WindowEvent::Touch` events and delivers the recognised **Gesture**s to the window's
+//! `gesture` function. A **Recognizer** may also be driven manually, e.g. for touch input that
+//! arrives via OSC or TUIO.
+
+use crate::event::{TouchEvent, TouchPhase};
+use crate::geom::Point2;
+use crate::glam::Vec2;
+use instant::Instant;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// A gesture recognised from one or more touches.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Gesture {
+    /// A single touch was briefly pressed and released without moving.
+    Tap { position: Point2 },
+    /// A second tap occurred shortly after and close to a first.
+    ///
+    /// The preceding `Tap`s are delivered as usual.
+    DoubleTap { position: Point2 },
+    /// A single touch was held in place.
+    LongPress { position: Point2 },
+    /// A single touch moved beyond the tap threshold.
+    ///
+    /// `delta` is the movement since the previous `Pan` event. A pan ends when its touch is
+    /// released or when a second touch begins.
+    Pan {
+        phase: TouchPhase,
+        position: Point2,
+        delta: Vec2,
+    },
+    /// The distance between two touches changed.
+    ///
+    /// `scale` is the ratio of the current distance to the distance at the previous `Pinch`.
+    Pinch { center: Point2, scale: f32 },
+    /// The angle between two touches changed.
+    ///
+    /// `angle` is the change in radians since the previous `Rotate`, positive counter-clockwise.
+    Rotate { center: Point2, angle: f32 },
+    /// A pan was released while moving quickly.
+    Swipe {
+        direction: SwipeDirection,
+        velocity: Vec2,
+    },
+}
+
+/// The dominant direction of a **Swipe**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Thresholds used to distinguish between gestures.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The maximum distance in points a touch may move and still count as a tap or long press.
+    pub tap_max_distance: f32,
+    /// The maximum duration of a tap.
+    pub tap_max_duration: Duration,
+    /// The maximum duration between two taps for them to count as a double tap.
+    pub double_tap_interval: Duration,
+    /// The duration a touch must be held in place to count as a long press.
+    pub long_press_duration: Duration,
+    /// The minimum release velocity of a pan in points per second for it to count as a swipe.
+    pub swipe_min_velocity: f32,
+    /// The maximum duration between a pan's last movement and its release for it to count as a
+    /// swipe.
+    pub swipe_max_hold: Duration,
+}
+
+/// Recognises gestures from a stream of touch events.
+#[derive(Clone, Debug)]
+pub struct Recognizer {
+    config: Config,
+    touches: BTreeMap<u64, Touch>,
+    panning: Option<u64>,
+    two_finger: Option<TwoFinger>,
+    last_tap: Option<(Instant, Point2)>,
+}
+
+// The tracked state of a single touch.
+#[derive(Copy, Clone, Debug)]
+struct Touch {
+    start: Instant,
+    start_position: Point2,
+    position: Point2,
+    last_moved: Instant,
+    velocity: Vec2,
+    moved: bool,
+    long_pressed: bool,
+}
+
+// The distance and angle between two touches at the previous pinch and rotate.
+#[derive(Copy, Clone, Debug)]
+struct TwoFinger {
+    distance: f32,
+    angle: f32,
+}
+
+impl Config {
+    /// The thresholds used by default.
+    pub const DEFAULT: Self = Config {
+        tap_max_distance: 10.0,
+        tap_max_duration: Duration::from_millis(300),
+        double_tap_interval: Duration::from_millis(300),
+        long_press_duration: Duration::from_millis(500),
+        swipe_min_velocity: 500.0,
+        swipe_max_hold: Duration::from_millis(100),
+    };
+}
+
+impl Recognizer {
+    /// A recognizer using the default thresholds.
+    pub fn new() -> Self {
+        Self::with_config(Config::DEFAULT)
+    }
+
+    /// A recognizer using the given thresholds.
+    pub fn with_config(config: Config) -> Self {
+        Recognizer {
+            config,
+            touches: Default::default(),
+            panning: None,
+            two_finger: None,
+            last_tap: None,
+        }
+    }
+
+    /// The thresholds used to distinguish between gestures.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The number of touches currently in contact.
+    pub fn touch_count(&self) -> usize {
+        self.touches.len()
+    }
+
+    /// Process the given touch event that occurred at the given moment, producing any recognised
+    /// gestures.
+    pub fn touch(&mut self, event: &TouchEvent, now: Instant) -> Vec<Gesture> {
+        let mut gestures = vec![];
+        match event.phase {
+            TouchPhase::Started => {
+                let touch = Touch {
+                    start: now,
+                    start_position: event.position,
+                    position: event.position,
+                    last_moved: now,
+                    velocity: Vec2::ZERO,
+                    moved: false,
+                    long_pressed: false,
+                };
+                self.touches.insert(event.id, touch);
+                if self.touches.len() >= 2 {
+                    self.end_pan(TouchPhase::Cancelled, &mut gestures);
+                    self.two_finger = self.two_finger_state().map(|(_, state)| state);
+                }
+            }
+            TouchPhase::Moved => {
+                let config = self.config;
+                let touch = match self.touches.get_mut(&event.id) {
+                    None => return gestures,
+                    Some(touch) => touch,
+                };
+                let delta = event.position - touch.position;
+                let dt = now.duration_since(touch.last_moved).as_secs_f32();
+                if dt > 0.0 {
+                    touch.velocity = delta / dt;
+                }
+                touch.position = event.position;
+                touch.last_moved = now;
+                if touch.start_position.distance(touch.position) > config.tap_max_distance {
+                    touch.moved = true;
+                }
+                let touch = *touch;
+
+                if self.touches.len() == 1 && touch.moved && !touch.long_pressed {
+                    let phase = match self.panning {
+                        Some(_) => TouchPhase::Moved,
+                        None => {
+                            self.panning = Some(event.id);
+                            TouchPhase::Started
+                        }
+                    };
+                    let delta = match phase {
+                        TouchPhase::Started => touch.position - touch.start_position,
+                        _ => delta,
+                    };
+                    gestures.push(Gesture::Pan {
+                        phase,
+                        position: touch.position,
+                        delta,
+                    });
+                } else if let Some((center, state)) = self.two_finger_state() {
+                    if let Some(prev) = self.two_finger {
+                        if prev.distance > 0.0 && state.distance != prev.distance {
+                            let scale = state.distance / prev.distance;
+                            gestures.push(Gesture::Pinch { center, scale });
+                        }
+                        let angle = wrap_angle(state.angle - prev.angle);
+                        if angle != 0.0 {
+                            gestures.push(Gesture::Rotate { center, angle });
+                        }
+                    }
+                    self.two_finger = Some(state);
+                }
+            }
+            TouchPhase::Ended => {
+                // The release position may differ from that of the last movement.
+                if let Some(touch) = self.touches.get_mut(&event.id) {
+                    touch.position = event.position;
+                    if touch.start_position.distance(touch.position) > self.config.tap_max_distance
+                    {
+                        touch.moved = true;
+                    }
+                }
+                let was_panning = self.panning == Some(event.id);
+                if was_panning {
+                    self.end_pan(TouchPhase::Ended, &mut gestures);
+                }
+                let touch = match self.touches.remove(&event.id) {
+                    None => return gestures,
+                    Some(touch) => touch,
+                };
+                if was_panning {
+                    // A pan that came to rest before its release is not a swipe.
+                    let held = now.duration_since(touch.last_moved);
+                    let velocity = match held <= self.config.swipe_max_hold {
+                        true => touch.velocity,
+                        false => Vec2::ZERO,
+                    };
+                    if velocity.length() >= self.config.swipe_min_velocity {
+                        let direction = swipe_direction(velocity);
+                        gestures.push(Gesture::Swipe {
+                            direction,
+                            velocity,
+                        });
+                    }
+                } else if self.touches.is_empty()
+                    && !touch.moved
+                    && !touch.long_pressed
+                    && now.duration_since(touch.start) <= self.config.tap_max_duration
+                {
+                    let position = touch.position;
+                    gestures.push(Gesture::Tap { position });
+                    let is_double = match self.last_tap {
+                        Some((time, last)) => {
+                            now.duration_since(time) <= self.config.double_tap_interval
+                                && last.distance(position) <= self.config.tap_max_distance
+                        }
+                        None => false,
+                    };
+                    if is_double {
+                        gestures.push(Gesture::DoubleTap { position });
+                        self.last_tap = None;
+                    } else {
+                        self.last_tap = Some((now, position));
+                    }
+                }
+                self.two_finger = self.two_finger_state().map(|(_, state)| state);
+            }
+            TouchPhase::Cancelled => {
+                if self.panning == Some(event.id) {
+                    self.end_pan(TouchPhase::Cancelled, &mut gestures);
+                }
+                self.touches.remove(&event.id);
+                self.two_finger = self.two_finger_state().map(|(_, state)| state);
+            }
+        }
+        gestures
+    }
+
+    /// Check for gestures that depend on the passing of time, e.g. long presses.
+    ///
+    /// This should be called regularly, e.g. once per update.
+    pub fn update(&mut self, now: Instant) -> Vec<Gesture> {
+        let mut gestures = vec![];
+        if self.touches.len() != 1 {
+            return gestures;
+        }
+        for touch in self.touches.values_mut() {
+            let held = now.duration_since(touch.start);
+            if !touch.moved && !touch.long_pressed && held >= self.config.long_press_duration {
+                touch.long_pressed = true;
+                let position = touch.position;
+                gestures.push(Gesture::LongPress { position });
+            }
+        }
+        gestures
+    }
+
+    // End the current pan, if any.
+    fn end_pan(&mut self, phase: TouchPhase, gestures: &mut Vec<Gesture>) {
+        if let Some(id) = self.panning.take() {
+            let position = self
+                .touches
+                .get(&id)
+                .map(|t| t.position)
+                .unwrap_or_default();
+            gestures.push(Gesture::Pan {
+                phase,
+                position,
+                delta: Vec2::ZERO,
+            });
+        }
+    }
+
+    // The center, distance and angle between the first two touches.
+    fn two_finger_state(&self) -> Option<(Point2, TwoFinger)> {
+        let mut touches = self.touches.values();
+        let a = touches.next()?.position;
+        let b = touches.next()?.position;
+        let v = b - a;
+        let center = (a + b) * 0.5;
+        let state = TwoFinger {
+            distance: v.length(),
+            angle: v.y.atan2(v.x),
+        };
+        Some((center, state))
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Default for Recognizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Wrap the given angle to the range `-PI..=PI`.
+fn wrap_angle(angle: f32) -> f32 {
+    use std::f32::consts::PI;
+    let mut angle = angle;
+    while angle > PI {
+        angle -= 2.0 * PI;
+    }
+    while angle < -PI {
+        angle += 2.0 * PI;
+    }
+    angle
+}
+
+// The dominant direction of the given velocity.
+fn swipe_direction(velocity: Vec2) -> SwipeDirection {
+    if velocity.x.abs() >= velocity.y.abs() {
+        if velocity.x >= 0.0 {
+            SwipeDirection::Right
+        } else {
+            SwipeDirection::Left
+        }
+    } else if velocity.y >= 0.0 {
+        SwipeDirection::Up
+    } else {
+        SwipeDirection::Down
+    }
+}
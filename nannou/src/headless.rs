@@ -0,0 +1,351 @@
+//! Items related to running an **App** without a window or display server.
+//!
+//! A headless **App** renders its default **view** to an offscreen texture rather than to the
+//! surface of a window. No winit event loop is created, allowing sketches to run on render farms,
+//! CI containers and other machines where there is no display to connect to.
+//!
+//! See `app::Builder::headless` for details.
+
+use crate::app::App;
+use crate::color::IntoLinSrgba;
+use crate::frame::{self, Frame};
+use crate::geom;
+use crate::wgpu;
+use crate::window::{self, FrameData};
+use std::cell::Cell;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A type used for describing the offscreen **Target** of a headless **App**.
+#[derive(Debug)]
+pub struct Builder {
+    size: [u32; 2],
+    scale_factor: f64,
+    format: wgpu::TextureFormat,
+    msaa_samples: u32,
+    power_preference: wgpu::PowerPreference,
+    force_fallback_adapter: bool,
+    device_desc: Option<wgpu::DeviceDescriptor<'static>>,
+    clear_color: wgpu::Color,
+    post_process: frame::PostProcess,
+}
+
+/// The offscreen target to which a headless **App** renders each frame.
+///
+/// The target stands in for the window of a regular **App**. The **Frame**s delivered to the
+/// **view** function are associated with the target's `id` and `draw.to_frame(app, &frame)`,
+/// `app.window_rect()` and `app.elapsed_frames()` all refer to the target.
+#[derive(Debug)]
+pub struct Target {
+    id: window::Id,
+    device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    scale_factor: f64,
+    msaa_samples: u32,
+    pub(crate) frame_data: FrameData,
+    pub(crate) frame_count: Cell<u64>,
+    pub(crate) is_invalidated: Cell<bool>,
+    pub(crate) clear_color: wgpu::Color,
+    pub(crate) quit_requested: Cell<bool>,
+}
+
+impl Builder {
+    /// The default size of the target in pixels.
+    pub const DEFAULT_SIZE: [u32; 2] = [1024, 768];
+    /// By default, one pixel is rendered per point.
+    pub const DEFAULT_SCALE_FACTOR: f64 = 1.0;
+    /// The default format of the target texture.
+    pub const DEFAULT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+    /// The default power preference used to request the WGPU adapter.
+    pub const DEFAULT_POWER_PREFERENCE: wgpu::PowerPreference = wgpu::DEFAULT_POWER_PREFERENCE;
+    /// The default `force_fallback_adapter` field used to request the WGPU adapter.
+    pub const DEFAULT_FORCE_FALLBACK_ADAPTER: bool = false;
+
+    /// Begin building a new headless target.
+    pub fn new() -> Self {
+        Builder {
+            size: Self::DEFAULT_SIZE,
+            scale_factor: Self::DEFAULT_SCALE_FACTOR,
+            format: Self::DEFAULT_FORMAT,
+            msaa_samples: Frame::DEFAULT_MSAA_SAMPLES,
+            power_preference: Self::DEFAULT_POWER_PREFERENCE,
+            force_fallback_adapter: Self::DEFAULT_FORCE_FALLBACK_ADAPTER,
+            device_desc: None,
+            clear_color: wgpu::Color::BLACK,
+            post_process: Default::default(),
+        }
+    }
+
+    /// The size of the target in pixels.
+    pub fn size_pixels(mut self, width: u32, height: u32) -> Self {
+        self.size = [width, height];
+        self
+    }
+
+    /// The number of pixels per point.
+    ///
+    /// The target may be drawn to as though it were `size / scale_factor` points in size,
+    /// matching the behaviour of a window on a high DPI display.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// The format of the texture to which each frame is written.
+    pub fn format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Specify the number of samples per pixel for the multisample anti-aliasing render pass.
+    ///
+    /// By default, this is `Frame::DEFAULT_MSAA_SAMPLES`.
+    pub fn msaa_samples(mut self, msaa_samples: u32) -> Self {
+        self.msaa_samples = msaa_samples;
+        self
+    }
+
+    /// Specify the power preference desired for the WGPU adapter.
+    pub fn power_preference(mut self, pref: wgpu::PowerPreference) -> Self {
+        self.power_preference = pref;
+        self
+    }
+
+    /// Indicates that only a fallback adapter can be returned, e.g. a software implementation of
+    /// the backend.
+    ///
+    /// By default, this is `false`. Note that if no hardware adapter is available, a fallback
+    /// adapter is requested regardless.
+    pub fn force_fallback_adapter(mut self, force: bool) -> Self {
+        self.force_fallback_adapter = force;
+        self
+    }
+
+    /// Specify a device descriptor to use when requesting the logical device from the adapter.
+    pub fn device_descriptor(mut self, device_desc: wgpu::DeviceDescriptor<'static>) -> Self {
+        self.device_desc = Some(device_desc);
+        self
+    }
+
+    /// The color with which the target is cleared before the first frame.
+    ///
+    /// By default, this is opaque black.
+    pub fn clear_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        let lin_srgba = color.into_lin_srgba();
+        let (r, g, b, a) = lin_srgba.into_components();
+        let (r, g, b, a) = (r as f64, g as f64, b as f64, a as f64);
+        self.clear_color = wgpu::Color { r, g, b, a };
+        self
+    }
+
+    /// Specify the post-processing effects applied to each frame.
+    pub fn post_process(mut self, post_process: frame::PostProcess) -> Self {
+        self.post_process = post_process;
+        self
+    }
+
+    // Request a device and create the offscreen target.
+    pub(crate) async fn build_async(
+        self,
+        app: &App,
+        max_capture_frame_jobs: u32,
+        capture_frame_timeout: Option<Duration>,
+    ) -> Result<Target, window::BuildError> {
+        let Builder {
+            size,
+            scale_factor,
+            format,
+            msaa_samples,
+            power_preference,
+            force_fallback_adapter,
+            device_desc,
+            clear_color,
+            post_process,
+        } = self;
+
+        // Request the adapter, falling back to a software adapter if necessary.
+        let mut request_adapter_opts = wgpu::RequestAdapterOptions {
+            power_preference,
+            compatible_surface: None,
+            force_fallback_adapter,
+        };
+        let mut adapter = app
+            .wgpu_adapters()
+            .get_or_request_async(request_adapter_opts.clone(), app.instance())
+            .await;
+        if adapter.is_none() && !force_fallback_adapter {
+            request_adapter_opts.force_fallback_adapter = true;
+            adapter = app
+                .wgpu_adapters()
+                .get_or_request_async(request_adapter_opts, app.instance())
+                .await;
+        }
+        let adapter = adapter.ok_or(window::BuildError::NoAvailableAdapter)?;
+
+        // Instantiate the logical device.
+        let device_desc = device_desc.unwrap_or_else(wgpu::default_device_descriptor);
+        let device_queue_pair = adapter.get_or_request_device_async(device_desc).await;
+        let device = device_queue_pair.device();
+
+        // Create the texture that stands in for the surface texture of a window.
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(format)
+            .usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC,
+            )
+            .build(device);
+        let texture_view = texture.view().build();
+
+        let render = frame::RenderData::new(device, size, format, msaa_samples, post_process);
+        let capture = frame::CaptureData::new(max_capture_frame_jobs, capture_frame_timeout);
+        let frame_data = FrameData { render, capture };
+
+        Ok(Target {
+            id: target_id(),
+            device_queue_pair,
+            texture,
+            texture_view,
+            scale_factor,
+            msaa_samples,
+            frame_data,
+            frame_count: Cell::new(0),
+            is_invalidated: Cell::new(true),
+            clear_color,
+            quit_requested: Cell::new(false),
+        })
+    }
+}
+
+impl Target {
+    /// The ID associated with the **Frame**s rendered to the target.
+    pub fn id(&self) -> window::Id {
+        self.id
+    }
+
+    /// The size of the target in pixels.
+    pub fn size_pixels(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// The size of the target in points.
+    pub fn size_points(&self) -> (f32, f32) {
+        let [w, h] = self.size_pixels();
+        let sf = self.scale_factor;
+        ((w as f64 / sf) as f32, (h as f64 / sf) as f32)
+    }
+
+    /// The number of pixels per point.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor as f32
+    }
+
+    /// The rectangle representing the dimensions of the target in points.
+    pub fn rect(&self) -> geom::Rect {
+        let (w, h) = self.size_points();
+        geom::Rect::from_w_h(w, h)
+    }
+
+    /// The format of the target texture.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.texture.format()
+    }
+
+    /// The number of samples used in the MSAA for the image associated with the **Frame**.
+    pub fn msaa_samples(&self) -> u32 {
+        self.msaa_samples
+    }
+
+    /// The texture containing the most recently rendered frame.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// A view of the texture containing the most recently rendered frame.
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// The wgpu logical device used to render to the target.
+    pub fn device(&self) -> &wgpu::Device {
+        self.device_queue_pair.device()
+    }
+
+    /// The wgpu queue to which commands for the target are submitted.
+    pub fn queue(&self) -> &wgpu::Queue {
+        self.device_queue_pair.queue()
+    }
+
+    /// The logical device and queue pair used to render to the target.
+    pub fn device_queue_pair(&self) -> &Arc<wgpu::DeviceQueuePair> {
+        &self.device_queue_pair
+    }
+
+    /// The number of times `view` has been called with a `Frame` for this target.
+    pub fn elapsed_frames(&self) -> u64 {
+        self.frame_count.get()
+    }
+
+    /// Capture the next frame right before it is written to the target and write it to an image
+    /// file at the given path.
+    ///
+    /// The destination image file type will be inferred from the extension given in the path.
+    pub fn capture_frame<P>(&self, path: P)
+    where
+        P: AsRef<Path>,
+    {
+        self.frame_data.capture.capture_next_frame(path.as_ref());
+    }
+
+    /// Block and wait for all active capture frame jobs to complete.
+    ///
+    /// This is called implicitly when the target is dropped to ensure any pending captures
+    /// complete.
+    pub fn await_capture_frame_jobs(
+        &self,
+    ) -> Result<(), wgpu::TextureCapturerAwaitWorkerTimeout<()>> {
+        let capture_data = &self.frame_data.capture;
+        capture_data
+            .texture_capturer
+            .await_active_snapshots(self.device())
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Target {
+    fn drop(&mut self) {
+        if self.await_capture_frame_jobs().is_err() {
+            // TODO: Replace eprintlns with proper logging.
+            eprintln!("timed out while waiting for capture jobs to complete");
+        }
+    }
+}
+
+impl<'a> wgpu::WithDeviceQueuePair for &'a Target {
+    fn with_device_queue_pair<F, O>(self, f: F) -> O
+    where
+        F: FnOnce(&wgpu::Device, &wgpu::Queue) -> O,
+    {
+        self.device_queue_pair().with_device_queue_pair(f)
+    }
+}
+
+// The ID associated with the frames of a headless target.
+//
+// winit provides no safe constructor for window IDs. The dummy ID may be equal to that of a real
+// window, however no winit windows are ever created by a headless app, so it cannot collide.
+fn target_id() -> window::Id {
+    unsafe { window::Id::dummy() }
+}
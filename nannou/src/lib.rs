@@ -33,11 +33,17 @@ pub mod draw;
 pub mod ease;
 pub mod event;
 pub mod frame;
+mod gamepad;
 pub mod geom;
+pub mod gesture;
+pub mod headless;
 pub mod image;
 pub mod io;
 pub mod noise;
+pub mod params;
 pub mod prelude;
+pub mod recording;
+pub mod snapshot;
 pub mod state;
 pub mod text;
 pub mod time;
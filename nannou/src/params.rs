@@ -0,0 +1,437 @@
+//! Items related to declaring the tweakable parameters of a sketch.
+//!
+//! A **Params** registry is declared once within the model, e.g.
+//!
+//! ```
+//! use nannou::params::Params;
+//!
+//! let params = Params::new()
+//!     .float("radius", 50.0, 0.0..=200.0)
+//!     .bool("animate", true)
+//!     .color("fill", nannou::color::srgba(1.0, 0.4, 0.2, 1.0))
+//!     .choice("shape", 0, &["ellipse", "rect", "tri"]);
+//! assert_eq!(params.get_f32("radius"), Some(50.0));
+//! ```
+//!
+//! The same registry may then be edited via the `nannou_egui::edit_params` panel, driven via OSC
+//! using `nannou_osc::Receiver::try_update_params` and stored as a TOML preset via `save_preset`
+//! and `load_preset`.
+
+use crate::color::{IntoLinSrgba, LinSrgba, Srgba};
+use crate::io;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+/// A registry of named, tweakable sketch parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Params {
+    params: Vec<Param>,
+    osc_prefix: String,
+}
+
+/// A single named parameter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    /// The unique name of the parameter.
+    pub name: String,
+    /// The current value along with any constraints.
+    pub value: Value,
+}
+
+/// The value of a parameter along with any constraints on the value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A floating point value clamped to the inclusive range `min..=max`.
+    Float { value: f32, min: f32, max: f32 },
+    /// A boolean toggle.
+    Bool(bool),
+    /// A linear sRGBA color.
+    Color(LinSrgba),
+    /// A selection from a fixed set of named options.
+    Choice {
+        selected: usize,
+        options: Vec<String>,
+    },
+}
+
+/// A snapshot of the values of a **Params** registry, keyed by parameter name.
+///
+/// Colors are stored as sRGBA components and choices by the name of the selected option so that
+/// presets remain readable and survive the reordering of options.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Preset {
+    pub values: BTreeMap<String, PresetValue>,
+}
+
+/// The value of a single parameter within a **Preset**.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PresetValue {
+    Bool(bool),
+    Float(f32),
+    Color([f32; 4]),
+    Choice(String),
+}
+
+impl Params {
+    /// The address prefix used for OSC bindings by default.
+    pub const DEFAULT_OSC_PREFIX: &'static str = "/param";
+
+    /// Begin declaring a new, empty registry.
+    pub fn new() -> Self {
+        Params {
+            params: vec![],
+            osc_prefix: Self::DEFAULT_OSC_PREFIX.to_string(),
+        }
+    }
+
+    /// Declare a floating point parameter with the given initial value and range.
+    ///
+    /// If a parameter with the same name already exists, it is replaced.
+    pub fn float(self, name: &str, value: f32, range: RangeInclusive<f32>) -> Self {
+        let (min, max) = range.into_inner();
+        let value = clamp(value, min, max);
+        self.param(name, Value::Float { value, min, max })
+    }
+
+    /// Declare a boolean parameter with the given initial value.
+    pub fn bool(self, name: &str, value: bool) -> Self {
+        self.param(name, Value::Bool(value))
+    }
+
+    /// Declare a color parameter with the given initial value.
+    pub fn color<C>(self, name: &str, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.param(name, Value::Color(color.into_lin_srgba()))
+    }
+
+    /// Declare a parameter whose value is one of the given options.
+    ///
+    /// `selected` is the index of the initially selected option.
+    pub fn choice(self, name: &str, selected: usize, options: &[&str]) -> Self {
+        assert!(!options.is_empty(), "a choice requires at least one option");
+        let selected = selected.min(options.len() - 1);
+        let options = options.iter().map(|s| s.to_string()).collect();
+        self.param(name, Value::Choice { selected, options })
+    }
+
+    /// The prefix used to produce the OSC address of each parameter.
+    ///
+    /// By default, this is `DEFAULT_OSC_PREFIX`, resulting in addresses like `/param/radius`.
+    pub fn osc_prefix(mut self, prefix: &str) -> Self {
+        self.osc_prefix = prefix.trim_end_matches('/').to_string();
+        self
+    }
+
+    // Insert or replace the parameter with the given name.
+    fn param(mut self, name: &str, value: Value) -> Self {
+        match self.params.iter_mut().find(|p| p.name == name) {
+            Some(param) => param.value = value,
+            None => self.params.push(Param {
+                name: name.to_string(),
+                value,
+            }),
+        }
+        self
+    }
+
+    /// The number of declared parameters.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Whether or not no parameters have been declared.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Iterate over the parameters in the order in which they were declared.
+    pub fn iter(&self) -> std::slice::Iter<Param> {
+        self.params.iter()
+    }
+
+    /// Mutably iterate over the parameters in the order in which they were declared.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<Param> {
+        self.params.iter_mut()
+    }
+
+    /// The value of the parameter with the given name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.params
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| &p.value)
+    }
+
+    /// Mutable access to the value of the parameter with the given name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.params
+            .iter_mut()
+            .find(|p| p.name == name)
+            .map(|p| &mut p.value)
+    }
+
+    /// The value of the float parameter with the given name.
+    pub fn get_f32(&self, name: &str) -> Option<f32> {
+        match self.get(name) {
+            Some(Value::Float { value, .. }) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value of the bool parameter with the given name.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name) {
+            Some(Value::Bool(b)) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// The value of the color parameter with the given name.
+    pub fn get_color(&self, name: &str) -> Option<LinSrgba> {
+        match self.get(name) {
+            Some(Value::Color(color)) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// The index of the selected option of the choice parameter with the given name.
+    pub fn get_choice(&self, name: &str) -> Option<usize> {
+        match self.get(name) {
+            Some(Value::Choice { selected, .. }) => Some(*selected),
+            _ => None,
+        }
+    }
+
+    /// The name of the selected option of the choice parameter with the given name.
+    pub fn get_choice_name(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(Value::Choice { selected, options }) => Some(&options[*selected]),
+            _ => None,
+        }
+    }
+
+    /// Set the value of the float parameter with the given name, clamped to its range.
+    ///
+    /// Returns `false` if there is no float parameter with the given name.
+    pub fn set_f32(&mut self, name: &str, value: f32) -> bool {
+        self.get_mut(name)
+            .map(|v| v.set_f32(value))
+            .unwrap_or(false)
+    }
+
+    /// Set the value of the bool parameter with the given name.
+    ///
+    /// Returns `false` if there is no bool parameter with the given name.
+    pub fn set_bool(&mut self, name: &str, value: bool) -> bool {
+        self.get_mut(name)
+            .map(|v| v.set_bool(value))
+            .unwrap_or(false)
+    }
+
+    /// Set the value of the color parameter with the given name.
+    ///
+    /// Returns `false` if there is no color parameter with the given name.
+    pub fn set_color<C>(&mut self, name: &str, color: C) -> bool
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.get_mut(name)
+            .map(|v| v.set_color(color))
+            .unwrap_or(false)
+    }
+
+    /// Select the option at the given index of the choice parameter with the given name.
+    ///
+    /// Returns `false` if there is no choice parameter with the given name or if the index is out
+    /// of range.
+    pub fn set_choice(&mut self, name: &str, selected: usize) -> bool {
+        self.get_mut(name)
+            .map(|v| v.set_choice(selected))
+            .unwrap_or(false)
+    }
+
+    /// The OSC address bound to the parameter with the given name.
+    pub fn osc_address(&self, name: &str) -> String {
+        format!("{}/{}", self.osc_prefix, name)
+    }
+
+    /// Mutable access to the parameter bound to the given OSC address.
+    pub fn get_by_osc_address_mut(&mut self, addr: &str) -> Option<&mut Param> {
+        let name = addr.strip_prefix(&self.osc_prefix)?.strip_prefix('/')?;
+        self.params.iter_mut().find(|p| p.name == name)
+    }
+
+    /// Produce a **Preset** containing the current value of every parameter.
+    pub fn preset(&self) -> Preset {
+        let values = self
+            .params
+            .iter()
+            .map(|p| (p.name.clone(), p.value.to_preset_value()))
+            .collect();
+        Preset { values }
+    }
+
+    /// Apply the values within the given preset.
+    ///
+    /// Values for unknown parameters or of a mismatched kind are ignored. Parameters without a
+    /// value in the preset are left unchanged.
+    pub fn apply_preset(&mut self, preset: &Preset) {
+        for param in &mut self.params {
+            if let Some(value) = preset.values.get(&param.name) {
+                param.value.apply_preset_value(value);
+            }
+        }
+    }
+
+    /// Save the current value of every parameter to a TOML file at the given path.
+    pub fn save_preset<P>(&self, path: P) -> Result<(), io::TomlFileSaveError>
+    where
+        P: AsRef<Path>,
+    {
+        io::save_to_toml(path, &self.preset())
+    }
+
+    /// Load and apply the TOML preset at the given path.
+    ///
+    /// Parameters that are missing from the preset are left unchanged.
+    pub fn load_preset<P>(&mut self, path: P) -> Result<(), io::TomlFileLoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let preset: Preset = io::load_from_toml(path)?;
+        self.apply_preset(&preset);
+        Ok(())
+    }
+}
+
+impl Value {
+    /// Set the value of a float parameter, clamped to its range.
+    ///
+    /// Returns `false` if the value is not a `Float`.
+    pub fn set_f32(&mut self, new: f32) -> bool {
+        match self {
+            Value::Float { value, min, max } => {
+                *value = clamp(new, *min, *max);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Set the value of a bool parameter.
+    ///
+    /// Returns `false` if the value is not a `Bool`.
+    pub fn set_bool(&mut self, new: bool) -> bool {
+        match self {
+            Value::Bool(b) => {
+                *b = new;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Set the value of a color parameter.
+    ///
+    /// Returns `false` if the value is not a `Color`.
+    pub fn set_color<C>(&mut self, new: C) -> bool
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        match self {
+            Value::Color(color) => {
+                *color = new.into_lin_srgba();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Select the option at the given index of a choice parameter.
+    ///
+    /// Returns `false` if the value is not a `Choice` or if the index is out of range.
+    pub fn set_choice(&mut self, new: usize) -> bool {
+        match self {
+            Value::Choice { selected, options } if new < options.len() => {
+                *selected = new;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Select the option with the given name of a choice parameter.
+    ///
+    /// Returns `false` if the value is not a `Choice` or if there is no option with the name.
+    pub fn set_choice_name(&mut self, name: &str) -> bool {
+        let ix = match self {
+            Value::Choice { options, .. } => options.iter().position(|o| o == name),
+            _ => None,
+        };
+        ix.map(|ix| self.set_choice(ix)).unwrap_or(false)
+    }
+
+    fn to_preset_value(&self) -> PresetValue {
+        match *self {
+            Value::Float { value, .. } => PresetValue::Float(value),
+            Value::Bool(b) => PresetValue::Bool(b),
+            Value::Color(color) => {
+                let (r, g, b, a) = Srgba::from_linear(color).into_components();
+                PresetValue::Color([r, g, b, a])
+            }
+            Value::Choice {
+                selected,
+                ref options,
+            } => PresetValue::Choice(options[selected].clone()),
+        }
+    }
+
+    fn apply_preset_value(&mut self, preset: &PresetValue) {
+        match *preset {
+            PresetValue::Float(f) => {
+                self.set_f32(f);
+            }
+            PresetValue::Bool(b) => {
+                self.set_bool(b);
+            }
+            PresetValue::Color([r, g, b, a]) => {
+                self.set_color(Srgba::new(r, g, b, a));
+            }
+            PresetValue::Choice(ref name) => {
+                self.set_choice_name(name);
+            }
+        }
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Params {
+    type Item = &'a Param;
+    type IntoIter = std::slice::Iter<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Params {
+    type Item = &'a mut Param;
+    type IntoIter = std::slice::IterMut<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+fn clamp(value: f32, min: f32, max: f32) -> f32 {
+    value.max(min).min(max)
+}
@@ -0,0 +1,144 @@
+//! Items related to recording the input events of an **App** and replaying them later.
+//!
+//! A **Recording** is a timestamped sequence of the window and gamepad input events and updates
+//! delivered to the user's model. Replaying a recording feeds the same sequence back to the
+//! `event`, `update`, `gamepad` and window functions on the recorded virtual clock, allowing for
+//! reproducing interactive bugs and regenerating performances frame-exactly.
+//!
+//! See `app::Builder::record` and `app::Builder::replay`.
+
+use crate::event::{GamepadEvent, ModifiersState, Update, WindowEvent};
+use crate::io;
+use instant::Instant;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// A timestamped sequence of the events delivered to an **App**'s model.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// The recorded events in the order in which they were delivered.
+    pub entries: Vec<Entry>,
+}
+
+/// A single recorded event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The wall-clock duration since the start of the recording at which the event occurred.
+    pub time: Duration,
+    /// The recorded event.
+    pub event: RecordedEvent,
+}
+
+/// The kinds of events that are recorded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RecordedEvent {
+    /// An update was applied to the model.
+    Update(Update),
+    /// A window received an input event.
+    ///
+    /// Windows are identified by the order in which they were created, as `window::Id`s are not
+    /// stable between runs.
+    WindowEvent { window: usize, event: WindowEvent },
+    /// The state of the keyboard's modifier keys changed.
+    ModifiersChanged(ModifiersState),
+    /// A gamepad emitted an event.
+    Gamepad(GamepadEvent),
+}
+
+// Records events delivered to the model for writing to a file on exit.
+#[derive(Debug)]
+pub(crate) struct Recorder {
+    path: PathBuf,
+    start: Instant,
+    recording: Recording,
+}
+
+// Replays the events of a recording.
+#[derive(Debug)]
+pub(crate) struct Player {
+    start: Instant,
+    entries: VecDeque<Entry>,
+}
+
+impl Recording {
+    /// Load a recording from the JSON file at the given path.
+    pub fn load<P>(path: P) -> Result<Self, io::JsonFileError>
+    where
+        P: AsRef<Path>,
+    {
+        io::load_from_json(path)
+    }
+
+    /// Save the recording to a JSON file at the given path.
+    pub fn save<P>(&self, path: P) -> Result<(), io::JsonFileError>
+    where
+        P: AsRef<Path>,
+    {
+        io::save_to_json(path, self)
+    }
+
+    /// The number of updates within the recording.
+    pub fn updates(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| match entry.event {
+                RecordedEvent::Update(_) => true,
+                _ => false,
+            })
+            .count()
+    }
+}
+
+impl Recorder {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Recorder {
+            path,
+            start: Instant::now(),
+            recording: Default::default(),
+        }
+    }
+
+    // Record the given event, timestamped with the time since the recorder was created.
+    pub(crate) fn record(&mut self, event: RecordedEvent) {
+        let time = self.start.elapsed();
+        self.recording.entries.push(Entry { time, event });
+    }
+
+    // Write the recording to the recorder's path.
+    pub(crate) fn save(&self) -> Result<(), io::JsonFileError> {
+        self.recording.save(&self.path)
+    }
+}
+
+impl Player {
+    pub(crate) fn new(recording: Recording) -> Self {
+        let entries = recording.entries.into();
+        Player {
+            start: Instant::now(),
+            entries,
+        }
+    }
+
+    // Whether or not all recorded events have been produced.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // The moment at which the next recorded event is due, relative to the player's creation.
+    pub(crate) fn next_due(&self) -> Option<Instant> {
+        self.entries.front().map(|entry| self.start + entry.time)
+    }
+
+    // Produce the next recorded event if it is due by the given moment.
+    //
+    // If `now` is `None` the next event is produced regardless of its timestamp. This is the case
+    // for the `Offline` loop mode, where replay proceeds on a virtual clock.
+    pub(crate) fn next(&mut self, now: Option<Instant>) -> Option<RecordedEvent> {
+        match (now, self.next_due()) {
+            (Some(now), Some(due)) if due > now => None,
+            _ => self.entries.pop_front().map(|entry| entry.event),
+        }
+    }
+}
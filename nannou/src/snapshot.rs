@@ -0,0 +1,90 @@
+//! Items related to persisting an **App**'s model between launches.
+//!
+//! A **Snapshot** writes a serializable model to a JSON file when the app exits and restores it
+//! the next time the app is launched, allowing camera positions, seeds and parameters to persist
+//! across changes to a sketch's code.
+//!
+//! Snapshots are restored on launch only. Reloading the `view` and `update` functions from a
+//! dynamic library while the app is running is not supported, as nannou holds these functions as
+//! plain function pointers into the running binary. Relaunching the sketch after rebuilding it,
+//! e.g. via `cargo watch`, restores the last snapshot instead.
+//!
+//! See `app::Builder::snapshot`.
+
+use crate::io;
+use std::path::{Path, PathBuf};
+
+/// Saves and restores a model to and from a JSON file.
+pub struct Snapshot<M> {
+    path: PathBuf,
+    save_fn: fn(&Path, &M) -> Result<(), io::JsonFileError>,
+    load_fn: fn(&Path) -> Result<M, io::JsonFileError>,
+}
+
+impl<M> Snapshot<M>
+where
+    M: serde::Serialize + for<'de> serde::Deserialize<'de>,
+{
+    /// A snapshot stored within the JSON file at the given path.
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Snapshot {
+            path: path.into(),
+            save_fn: |path, model| io::save_to_json(path, model),
+            load_fn: |path| io::load_from_json(path),
+        }
+    }
+}
+
+impl<M> Snapshot<M> {
+    /// The path of the JSON file in which the snapshot is stored.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the given model to the snapshot file.
+    pub fn save(&self, model: &M) -> Result<(), io::JsonFileError> {
+        (self.save_fn)(&self.path, model)
+    }
+
+    /// Read the model from the snapshot file.
+    pub fn load(&self) -> Result<M, io::JsonFileError> {
+        (self.load_fn)(&self.path)
+    }
+
+    /// Replace the given model with the snapshot if one exists.
+    ///
+    /// If the snapshot cannot be read, e.g. because the fields of the model have changed, a
+    /// warning is printed and the given model is returned.
+    pub fn restore(&self, model: M) -> M {
+        if !self.path.exists() {
+            return model;
+        }
+        match self.load() {
+            Ok(restored) => restored,
+            Err(err) => {
+                // TODO: Replace eprintlns with proper logging.
+                eprintln!(
+                    "failed to restore model snapshot from {}: {}",
+                    self.path.display(),
+                    err
+                );
+                model
+            }
+        }
+    }
+
+    // Save the model, printing a warning on failure.
+    pub(crate) fn save_or_warn(&self, model: &M) {
+        if let Err(err) = self.save(model) {
+            // TODO: Replace eprintlns with proper logging.
+            eprintln!(
+                "failed to save model snapshot to {}: {}",
+                self.path.display(),
+                err
+            );
+        }
+    }
+}
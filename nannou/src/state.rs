@@ -1,9 +1,11 @@
-//! Small tracked parts of the application state. Includes **window**, **keys**, **mouse**, and
-//! **time** - each of which are stored in the **App**.
+//! Small tracked parts of the application state. Includes **window**, **keys**, **mouse**,
+//! **touches**, **gamepads** and **time** - each of which are stored in the **App**.
 
+pub use self::gamepads::Gamepads;
 pub use self::keys::Keys;
 pub use self::mouse::Mouse;
 pub use self::time::Time;
+pub use self::touches::Touches;
 pub use self::window::Window;
 
 /// Tracked state related to the focused window.
@@ -37,16 +39,28 @@ pub mod window {
 /// Tracked state related to the keyboard.
 pub mod keys {
     use crate::event::{Key, ModifiersState};
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use std::ops::Deref;
 
     /// The state of the keyboard.
+    ///
+    /// The `just_pressed`, `just_released` and `just_repeated` sets describe the key events that
+    /// occurred between the previous update and the current one. They are refreshed at the start
+    /// of each update, so each key event is observed by exactly one update.
     #[derive(Clone, Debug, Default)]
     pub struct Keys {
         /// The state of the modifier keys as last indicated by winit.
         pub mods: ModifiersState,
         /// The state of all keys as tracked via the nannou App event handling.
         pub down: Down,
+        /// The keys that were pressed since the previous update.
+        pub just_pressed: KeySet,
+        /// The keys that were released since the previous update.
+        pub just_released: KeySet,
+        /// The keys that emitted an OS key repeat since the previous update.
+        pub just_repeated: KeySet,
+        repeats: HashMap<Key, u32>,
+        pending: Pending,
     }
 
     /// The set of keys that are currently pressed.
@@ -55,19 +69,79 @@ pub mod keys {
         pub(crate) keys: HashSet<Key>,
     }
 
+    /// A set of keys associated with some event.
+    #[derive(Clone, Debug, Default)]
+    pub struct KeySet {
+        keys: HashSet<Key>,
+    }
+
+    // Key events received since the last update.
+    #[derive(Clone, Debug, Default)]
+    struct Pending {
+        pressed: HashSet<Key>,
+        released: HashSet<Key>,
+        repeated: HashSet<Key>,
+    }
+
+    impl Keys {
+        /// The number of OS key repeats emitted since the given key was pressed.
+        ///
+        /// Returns `0` if the key is not down.
+        pub fn repeat_count(&self, key: Key) -> u32 {
+            self.repeats.get(&key).cloned().unwrap_or(0)
+        }
+
+        // Track a key press, distinguishing OS key repeats from initial presses.
+        pub(crate) fn press(&mut self, key: Key) {
+            if self.down.keys.insert(key) {
+                self.pending.pressed.insert(key);
+                self.repeats.insert(key, 0);
+            } else {
+                self.pending.repeated.insert(key);
+                *self.repeats.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        // Track a key release.
+        pub(crate) fn release(&mut self, key: Key) {
+            self.down.keys.remove(&key);
+            self.repeats.remove(&key);
+            self.pending.released.insert(key);
+        }
+
+        // Expose the key events received since the previous update.
+        pub(crate) fn begin_update(&mut self) {
+            let pending = std::mem::take(&mut self.pending);
+            self.just_pressed.keys = pending.pressed;
+            self.just_released.keys = pending.released;
+            self.just_repeated.keys = pending.repeated;
+        }
+    }
+
     impl Deref for Down {
         type Target = HashSet<Key>;
         fn deref(&self) -> &Self::Target {
             &self.keys
         }
     }
+
+    impl Deref for KeySet {
+        type Target = HashSet<Key>;
+        fn deref(&self) -> &Self::Target {
+            &self.keys
+        }
+    }
 }
 
 /// Tracked state related to the mouse.
 pub mod mouse {
+    use crate::event::MouseScrollDelta;
     use crate::geom::Point2;
+    use crate::glam::Vec2;
     use crate::window;
-    use std::collections::HashMap;
+    use instant::Instant;
+    use std::collections::{HashMap, HashSet};
+    use std::time::Duration;
 
     #[doc(inline)]
     pub use crate::event::MouseButton as Button;
@@ -83,6 +157,48 @@ pub mod mouse {
         pub y: f32,
         /// A map describing the state of each mouse button.
         pub buttons: ButtonMap,
+        /// The distance the mouse moved between the previous update and the current one.
+        pub delta: Vec2,
+        /// The velocity of the mouse over the previous update in points per second.
+        ///
+        /// This is measured by the wall-clock time between passes of the event loop, so updates
+        /// applied within the same pass, e.g. while a fixed-rate loop catches up, share the
+        /// velocity of the first.
+        pub velocity: Vec2,
+        /// The distance scrolled via the mouse wheel between the previous update and the current
+        /// one, in points.
+        ///
+        /// Line-based scroll deltas are converted via `Mouse::POINTS_PER_LINE`.
+        pub wheel: Vec2,
+        /// The total distance scrolled via the mouse wheel since the app started, in points.
+        pub wheel_total: Vec2,
+        /// The buttons that were pressed since the previous update.
+        pub just_pressed: HashSet<Button>,
+        /// The buttons that were released since the previous update.
+        pub just_released: HashSet<Button>,
+        /// The buttons that were double-clicked since the previous update.
+        pub double_clicked: HashSet<Button>,
+        last_click: Option<Click>,
+        last_update: Option<Instant>,
+        pending: Pending,
+    }
+
+    // The most recent button press, used to detect double clicks.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Click {
+        button: Button,
+        time: Instant,
+        position: Point2,
+    }
+
+    // Mouse events received since the last update.
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Pending {
+        delta: Vec2,
+        wheel: Vec2,
+        pressed: HashSet<Button>,
+        released: HashSet<Button>,
+        double_clicked: HashSet<Button>,
     }
 
     /// Whether the button is up or down.
@@ -103,6 +219,15 @@ pub mod mouse {
     }
 
     impl Mouse {
+        /// The maximum duration between two presses of a button for them to count as a double
+        /// click.
+        pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+        /// The maximum distance in points between two presses of a button for them to count as a
+        /// double click.
+        pub const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+        /// The number of points scrolled per line for line-based mouse wheel deltas.
+        pub const POINTS_PER_LINE: f32 = 20.0;
+
         /// Construct a new default `Mouse`.
         pub fn new() -> Self {
             Mouse {
@@ -110,6 +235,16 @@ pub mod mouse {
                 buttons: ButtonMap::new(),
                 x: 0.0,
                 y: 0.0,
+                delta: Vec2::ZERO,
+                velocity: Vec2::ZERO,
+                wheel: Vec2::ZERO,
+                wheel_total: Vec2::ZERO,
+                just_pressed: HashSet::new(),
+                just_released: HashSet::new(),
+                double_clicked: HashSet::new(),
+                last_click: None,
+                last_update: None,
+                pending: Pending::default(),
             }
         }
 
@@ -117,6 +252,88 @@ pub mod mouse {
         pub fn position(&self) -> Point2 {
             [self.x, self.y].into()
         }
+
+        // Track the mouse moving to the given position within the given window.
+        //
+        // Movement between windows does not contribute to the mouse's `delta`.
+        pub(crate) fn move_to(&mut self, window: window::Id, position: Point2) {
+            if self.window == Some(window) {
+                self.pending.delta += position - self.position();
+            }
+            self.x = position.x;
+            self.y = position.y;
+            self.window = Some(window);
+        }
+
+        // Track a button press that occurred at the given moment.
+        pub(crate) fn press(&mut self, window: window::Id, button: Button, time: Instant) {
+            let position = self.position();
+            self.buttons.press(button, position);
+            self.window = Some(window);
+            self.pending.pressed.insert(button);
+            let click = Click {
+                button,
+                time,
+                position,
+            };
+            let is_double = match self.last_click {
+                Some(last) => {
+                    last.button == button
+                        && time.duration_since(last.time) <= Self::DOUBLE_CLICK_INTERVAL
+                        && last.position.distance(position) <= Self::DOUBLE_CLICK_DISTANCE
+                }
+                None => false,
+            };
+            if is_double {
+                // Reset so that a third click does not count as a second double click.
+                self.pending.double_clicked.insert(button);
+                self.last_click = None;
+            } else {
+                self.last_click = Some(click);
+            }
+        }
+
+        // Track a button release.
+        pub(crate) fn release(&mut self, window: window::Id, button: Button) {
+            self.buttons.release(button);
+            self.window = Some(window);
+            self.pending.released.insert(button);
+        }
+
+        // Track a mouse wheel scroll. Pixel deltas are converted to points via `scale_factor`.
+        pub(crate) fn scroll(&mut self, delta: MouseScrollDelta, scale_factor: f64) {
+            let delta = match delta {
+                MouseScrollDelta::LineDelta(x, y) => Vec2::new(x, y) * Self::POINTS_PER_LINE,
+                MouseScrollDelta::PixelDelta(pos) => {
+                    let pos = pos.to_logical::<f32>(scale_factor);
+                    Vec2::new(pos.x, pos.y)
+                }
+            };
+            self.pending.wheel += delta;
+            self.wheel_total += delta;
+        }
+
+        // Expose the mouse events received since the previous update, given the moment at which
+        // the current loop pass began.
+        pub(crate) fn begin_update(&mut self, now: Instant) {
+            let pending = std::mem::take(&mut self.pending);
+            self.delta = pending.delta;
+            match self.last_update {
+                None => self.velocity = Vec2::ZERO,
+                Some(last) => {
+                    // No time passes between updates within the same pass.
+                    let secs = now.saturating_duration_since(last).as_secs_f32();
+                    if secs > 0.0 {
+                        self.velocity = pending.delta / secs;
+                    }
+                }
+            }
+            self.last_update = Some(now);
+            self.wheel = pending.wheel;
+            self.just_pressed = pending.pressed;
+            self.just_released = pending.released;
+            self.double_clicked = pending.double_clicked;
+        }
     }
 
     impl ButtonPosition {
@@ -223,6 +440,200 @@ pub mod mouse {
     }
 }
 
+/// Tracked state related to the touches currently in contact with a touch screen.
+pub mod touches {
+    use crate::event::{TouchEvent, TouchPhase};
+    use crate::geom::Point2;
+    use crate::window;
+    use instant::Instant;
+    use std::collections::BTreeMap;
+
+    /// The state of all touches currently in contact, keyed by their touch ID.
+    #[derive(Clone, Debug, Default)]
+    pub struct Touches {
+        touches: BTreeMap<u64, Touch>,
+    }
+
+    /// The state of a single touch.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub struct Touch {
+        /// The window in which the touch occurred.
+        pub window: window::Id,
+        /// The position at which the touch began.
+        pub start_position: Point2,
+        /// The current position of the touch.
+        pub position: Point2,
+        /// The moment at which the touch began.
+        pub start: Instant,
+    }
+
+    impl Touches {
+        /// The touch with the given ID, if it is in contact.
+        pub fn get(&self, id: u64) -> Option<&Touch> {
+            self.touches.get(&id)
+        }
+
+        /// Iterate over all touches in contact in order of their IDs.
+        pub fn iter(&self) -> impl Iterator<Item = (u64, &Touch)> {
+            self.touches.iter().map(|(&id, touch)| (id, touch))
+        }
+
+        /// The number of touches in contact.
+        pub fn len(&self) -> usize {
+            self.touches.len()
+        }
+
+        /// Whether or not no touches are in contact.
+        pub fn is_empty(&self) -> bool {
+            self.touches.is_empty()
+        }
+
+        // Update the tracked state with the given event.
+        pub(crate) fn apply_event(&mut self, window: window::Id, event: &TouchEvent) {
+            match event.phase {
+                TouchPhase::Started => {
+                    let touch = Touch {
+                        window,
+                        start_position: event.position,
+                        position: event.position,
+                        start: Instant::now(),
+                    };
+                    self.touches.insert(event.id, touch);
+                }
+                TouchPhase::Moved => {
+                    if let Some(touch) = self.touches.get_mut(&event.id) {
+                        touch.position = event.position;
+                    }
+                }
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    self.touches.remove(&event.id);
+                }
+            }
+        }
+    }
+}
+
+/// Tracked state related to connected gamepads.
+pub mod gamepads {
+    use crate::event::{GamepadAxis, GamepadButton, GamepadEvent, GamepadEventKind, GamepadId};
+    use crate::geom::Point2;
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    #[doc(inline)]
+    pub use crate::event::GamepadAxis as Axis;
+    #[doc(inline)]
+    pub use crate::event::GamepadButton as Button;
+
+    /// The state of all currently connected gamepads.
+    #[derive(Clone, Debug, Default)]
+    pub struct Gamepads {
+        pads: BTreeMap<GamepadId, Gamepad>,
+    }
+
+    /// The state of a single connected gamepad.
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct Gamepad {
+        /// The name of the gamepad as reported by the OS.
+        pub name: String,
+        /// The set of buttons that are currently pressed.
+        pub buttons: HashSet<Button>,
+        /// The last known value of each analog button, in the range `0.0..=1.0`.
+        pub button_values: HashMap<Button, f32>,
+        /// The last known value of each axis, in the range `-1.0..=1.0`.
+        pub axes: HashMap<Axis, f32>,
+    }
+
+    impl Gamepads {
+        /// The state of the gamepad with the given ID, if it is connected.
+        pub fn get(&self, id: GamepadId) -> Option<&Gamepad> {
+            self.pads.get(&id)
+        }
+
+        /// The connected gamepad with the lowest ID.
+        ///
+        /// Useful for sketches that only expect a single controller.
+        pub fn first(&self) -> Option<(GamepadId, &Gamepad)> {
+            self.pads.iter().next().map(|(&id, pad)| (id, pad))
+        }
+
+        /// Iterate over all connected gamepads in order of their IDs.
+        pub fn iter(&self) -> impl Iterator<Item = (GamepadId, &Gamepad)> {
+            self.pads.iter().map(|(&id, pad)| (id, pad))
+        }
+
+        /// The number of connected gamepads.
+        pub fn len(&self) -> usize {
+            self.pads.len()
+        }
+
+        /// Whether or not no gamepads are connected.
+        pub fn is_empty(&self) -> bool {
+            self.pads.is_empty()
+        }
+
+        /// Whether or not the given button is pressed on any connected gamepad.
+        pub fn any_pressed(&self, button: Button) -> bool {
+            self.pads.values().any(|pad| pad.is_pressed(button))
+        }
+
+        /// Update the tracked state with the given event.
+        pub fn apply_event(&mut self, event: &GamepadEvent) {
+            match event.kind {
+                GamepadEventKind::Connected { ref name } => {
+                    let pad = self.pads.entry(event.id).or_default();
+                    pad.name = name.clone();
+                }
+                GamepadEventKind::Disconnected => {
+                    self.pads.remove(&event.id);
+                }
+                GamepadEventKind::ButtonPressed(button) => {
+                    let pad = self.pads.entry(event.id).or_default();
+                    pad.buttons.insert(button);
+                }
+                GamepadEventKind::ButtonReleased(button) => {
+                    let pad = self.pads.entry(event.id).or_default();
+                    pad.buttons.remove(&button);
+                }
+                GamepadEventKind::ButtonChanged(button, value) => {
+                    let pad = self.pads.entry(event.id).or_default();
+                    pad.button_values.insert(button, value);
+                }
+                GamepadEventKind::AxisChanged(axis, value) => {
+                    let pad = self.pads.entry(event.id).or_default();
+                    pad.axes.insert(axis, value);
+                }
+            }
+        }
+    }
+
+    impl Gamepad {
+        /// Whether or not the given button is pressed.
+        pub fn is_pressed(&self, button: GamepadButton) -> bool {
+            self.buttons.contains(&button)
+        }
+
+        /// The value of the given analog button, or `0.0` if it has not yet been moved.
+        pub fn button_value(&self, button: GamepadButton) -> f32 {
+            self.button_values.get(&button).cloned().unwrap_or(0.0)
+        }
+
+        /// The value of the given axis, or `0.0` if it has not yet been moved.
+        pub fn axis(&self, axis: GamepadAxis) -> f32 {
+            self.axes.get(&axis).cloned().unwrap_or(0.0)
+        }
+
+        /// The position of the left stick, each axis in the range `-1.0..=1.0`.
+        pub fn left_stick(&self) -> Point2 {
+            [self.axis(Axis::LeftStickX), self.axis(Axis::LeftStickY)].into()
+        }
+
+        /// The position of the right stick, each axis in the range `-1.0..=1.0`.
+        pub fn right_stick(&self) -> Point2 {
+            [self.axis(Axis::RightStickX), self.axis(Axis::RightStickY)].into()
+        }
+    }
+}
+
 /// Tracked durations related to the App.
 pub mod time {
     /// The state of time tracked by the App.
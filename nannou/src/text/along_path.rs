@@ -0,0 +1,119 @@
+//! Laying out text along arbitrary paths, similar to SVG's `<textPath>` element.
+//!
+//! Each glyph is placed so that the centre of its advance lies on the path and is rotated to
+//! follow the path's tangent at that point. Lines following the first are offset along the path's
+//! normal by the distance between their baseline and the baseline of the first line.
+
+use crate::geom::Point2;
+use crate::glam::{Mat4, Vec2};
+use crate::text::{shape, Justify, Run, Scalar, Text};
+use lyon::algorithms::{length, walk};
+use lyon::path::PathEvent;
+
+/// The placement of a single glyph laid out along a path.
+#[derive(Copy, Clone, Debug)]
+pub struct Placement<'a> {
+    /// The run containing the glyph.
+    pub run: &'a Run,
+    /// The shaped glyph.
+    pub glyph: &'a shape::Glyph,
+    /// The position of the glyph's origin, i.e. the left end of its baseline.
+    pub origin: Point2,
+    /// The rotation of the glyph about its origin in radians, following the path's tangent.
+    pub rotation: f32,
+}
+
+// The tolerance used when measuring the length of curves.
+const TOLERANCE: f32 = 0.01;
+
+impl<'a> Placement<'a> {
+    /// The transform from the glyph's local space, in which the origin is the left end of its
+    /// baseline, to the space of the path.
+    pub fn transform(&self) -> Mat4 {
+        Mat4::from_translation(self.origin.extend(0.0)) * Mat4::from_rotation_z(self.rotation)
+    }
+}
+
+/// Lay out the glyphs of the given text along the given path.
+///
+/// Each line is aligned along the length of the path according to the layout's `Justify`:
+/// `Left` begins at the start of the path, `Center` centres the line on the middle of the path
+/// and `Right` ends at the end of the path. The line is then shifted along the path by `offset`.
+/// Animating the `offset` scrolls the text along the path.
+///
+/// Glyphs are yielded in the same order as `Text::glyphs`. Glyphs whose centre falls beyond either
+/// end of the path are omitted.
+pub fn place<'a, I>(text: &'a Text, path: I, offset: Scalar) -> Vec<Placement<'a>>
+where
+    I: IntoIterator<Item = PathEvent> + Clone,
+{
+    let runs = text.runs();
+    let first_baseline = match runs.first() {
+        None => return vec![],
+        Some(run) => run.rect.bottom(),
+    };
+    let path_len = length::approximate_length(path.clone(), TOLERANCE);
+
+    // The horizontal extent of each line.
+    let mut lines = vec![(std::f32::MAX, std::f32::MIN); text.num_lines()];
+    for run in runs {
+        let (ref mut l, ref mut r) = lines[run.line];
+        *l = l.min(run.rect.left());
+        *r = r.max(run.rect.right());
+    }
+
+    // The distance along the path of the centre of each glyph, alongside its offset along the
+    // path's normal.
+    let mut glyphs = vec![];
+    for run in runs {
+        let (line_l, line_r) = lines[run.line];
+        let start = match text.layout().justify {
+            Justify::Left => 0.0,
+            Justify::Center => (path_len - (line_r - line_l)) / 2.0,
+            Justify::Right => path_len - (line_r - line_l),
+        };
+        for glyph in &run.glyphs {
+            let x = run.rect.left() - line_l + glyph.position.x + glyph.advance / 2.0;
+            let distance = start + offset + x;
+            let normal = run.rect.bottom() + glyph.position.y - first_baseline;
+            glyphs.push((run, glyph, distance, normal));
+        }
+    }
+
+    // Walk the path, sampling the position and tangent at each glyph in order of distance.
+    let mut order: Vec<usize> = (0..glyphs.len())
+        .filter(|&i| 0.0 <= glyphs[i].2 && glyphs[i].2 <= path_len)
+        .collect();
+    order.sort_by(|&a, &b| glyphs[a].2.partial_cmp(&glyphs[b].2).unwrap());
+    let mut samples = vec![None; glyphs.len()];
+    if let Some(&first) = order.first() {
+        let mut next = 0;
+        let mut pattern = |position: lyon::math::Point, tangent: lyon::math::Vector, _: f32| {
+            let i = order[next];
+            samples[i] = Some((
+                Vec2::new(position.x, position.y),
+                Vec2::new(tangent.x, tangent.y),
+            ));
+            next += 1;
+            order.get(next).map(|&j| glyphs[j].2 - glyphs[i].2)
+        };
+        walk::walk_along_path(path.into_iter(), glyphs[first].2, &mut pattern);
+    }
+
+    glyphs
+        .iter()
+        .zip(samples)
+        .filter_map(|(&(run, glyph, _, normal), sample)| {
+            let (position, tangent) = sample?;
+            let normal = tangent.perp() * normal;
+            let origin = position - tangent * (glyph.advance / 2.0) + normal;
+            let rotation = tangent.y.atan2(tangent.x);
+            Some(Placement {
+                run,
+                glyph,
+                origin,
+                rotation,
+            })
+        })
+        .collect()
+}
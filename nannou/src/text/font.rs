@@ -1,9 +1,17 @@
-//! The `font::Id` and `font::Map` types.
+//! The `font::Id` and `font::Map` types along with functions for loading, selecting and looking
+//! up fonts.
+//!
+//! Characters missing from a font may be displayed using the first of a chain of fallback fonts
+//! that contains them. See the `select` function and `Map::set_fallbacks`.
+//!
+//! When the `system-fonts` feature is enabled, fonts installed on the system may be loaded by
+//! family, weight and style via `from_system` and `Map::insert_system`.
 
-use crate::text::{Font, FontCollection};
+use crate::text::{rt, Font, FontCollection, GlyphId};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// A type-safe wrapper around the `FontId`.
 ///
@@ -19,6 +27,7 @@ pub struct Id(usize);
 pub struct Map {
     next_index: usize,
     map: HashMap<Id, Font>,
+    fallbacks: Vec<Id>,
 }
 
 /// An iterator yielding an `Id` for each new `rusttype::Font` inserted into the `Map` via the
@@ -42,9 +51,38 @@ pub enum Error {
     NoFont,
 }
 
+/// The weight of a font's glyphs, ranging from `Weight::THIN` (100) to `Weight::BLACK` (900).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Weight(pub u16);
+
+/// The slant of a font's glyphs.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Style {
+    /// Upright glyphs.
+    Normal,
+    /// Cursive glyphs designed as a separate face.
+    Italic,
+    /// Slanted versions of the upright glyphs.
+    Oblique,
+}
+
+/// The raw data from which a `Font` was loaded.
+///
+/// `rusttype` does not provide access to a font's OpenType layout tables, so the data is retained
+/// in order to allow for shaping via the `text::shape` module.
+pub type Data = rt::SharedBytes<'static>;
+
 /// The name of the default directory that is searched for fonts.
 pub const DEFAULT_DIRECTORY_NAME: &str = "fonts";
 
+// The data of each registered font along with the index of the font within the data, keyed by
+// the `data_key` of the font.
+static DATA: Mutex<Option<HashMap<usize, (Data, u32)>>> = Mutex::new(None);
+
+// The database of fonts installed on the system, loaded upon the first lookup.
+#[cfg(feature = "system-fonts")]
+static SYSTEM: Mutex<Option<fontdb::Database>> = Mutex::new(None);
+
 impl Id {
     /// Returns the inner `usize` from the `Id`.
     pub fn index(self) -> usize {
@@ -52,12 +90,25 @@ impl Id {
     }
 }
 
+impl Weight {
+    pub const THIN: Self = Weight(100);
+    pub const EXTRA_LIGHT: Self = Weight(200);
+    pub const LIGHT: Self = Weight(300);
+    pub const NORMAL: Self = Weight(400);
+    pub const MEDIUM: Self = Weight(500);
+    pub const SEMIBOLD: Self = Weight(600);
+    pub const BOLD: Self = Weight(700);
+    pub const EXTRA_BOLD: Self = Weight(800);
+    pub const BLACK: Self = Weight(900);
+}
+
 impl Map {
     /// Construct the new, empty `Map`.
     pub fn new() -> Self {
         Map {
             next_index: 0,
             map: HashMap::default(),
+            fallbacks: vec![],
         }
     }
 
@@ -84,6 +135,55 @@ impl Map {
         Ok(self.insert(font))
     }
 
+    /// Insert the system font best matching the given family, weight and style.
+    ///
+    /// See the `from_system` function for details.
+    #[cfg(feature = "system-fonts")]
+    pub fn insert_system(
+        &mut self,
+        family: &str,
+        weight: Weight,
+        style: Style,
+    ) -> Result<Id, Error> {
+        let font = from_system(family, weight, style)?;
+        Ok(self.insert(font))
+    }
+
+    /// Specify the chain of fonts used, in order, to display characters that are missing from
+    /// a primary font.
+    pub fn set_fallbacks<I>(&mut self, ids: I)
+    where
+        I: IntoIterator<Item = Id>,
+    {
+        self.fallbacks = ids.into_iter().collect();
+    }
+
+    /// The chain of fallback font `Id`s in order.
+    pub fn fallbacks(&self) -> &[Id] {
+        &self.fallbacks
+    }
+
+    /// The chain of fallback fonts in order, ready for use with `text::Builder::fallback_fonts`.
+    ///
+    /// `Id`s that are no longer within the map are skipped.
+    pub fn fallback_fonts(&self) -> Vec<Font> {
+        self.fallbacks
+            .iter()
+            .filter_map(|&id| self.get(id).cloned())
+            .collect()
+    }
+
+    /// The `Id` of the font used to display the given character.
+    ///
+    /// This is the first font containing the character from the `primary` font followed by each
+    /// of the fallbacks in order. Returns `primary` if no font contains the character.
+    pub fn font_for_char(&self, primary: Id, ch: char) -> Id {
+        std::iter::once(primary)
+            .chain(self.fallbacks.iter().cloned())
+            .find(|&id| self.get(id).map(|font| contains(font, ch)).unwrap_or(false))
+            .unwrap_or(primary)
+    }
+
     // /// Adds each font in the given `rusttype::FontCollection` to the `Map` and returns an
     // /// iterator yielding a unique `Id` for each.
     // pub fn insert_collection(&mut self, collection: FontCollection) -> NewIds {
@@ -118,6 +218,157 @@ pub fn id(font: &Font) -> Id {
     Id((hasher.finish() % std::usize::MAX as u64) as usize)
 }
 
+/// Register the raw data from which the given font was loaded.
+///
+/// Fonts loaded via the `from_file`, `from_bytes` and `default_notosans` functions are registered
+/// automatically. Fonts loaded by other means must be registered in order to be shaped, otherwise
+/// they are laid out one glyph per character without ligatures or contextual forms.
+///
+/// Fonts without a name table cannot be registered and are always laid out one glyph per
+/// character.
+pub fn register_data<D>(font: &Font, data: D)
+where
+    D: Into<Data>,
+{
+    register_data_at(font, data, 0);
+}
+
+/// Register the raw data of the font collection containing the given font, along with the index
+/// of the font within the collection.
+pub fn register_data_at<D>(font: &Font, data: D, index: u32)
+where
+    D: Into<Data>,
+{
+    let key = match data_key(font) {
+        None => return,
+        Some(key) => key,
+    };
+    let mut guard = DATA.lock().expect("failed to lock font data");
+    let map = guard.get_or_insert_with(HashMap::new);
+    map.insert(key, (data.into(), index));
+}
+
+/// The raw data registered for the given font along with the index of the font within the data,
+/// if any.
+pub fn data(font: &Font) -> Option<(Data, u32)> {
+    let key = data_key(font)?;
+    let guard = DATA.lock().ok()?;
+    guard.as_ref().and_then(|map| map.get(&key).cloned())
+}
+
+/// Load a single `Font` from the given bytes, registering its data for shaping.
+pub fn from_bytes<D>(data: D) -> Result<Font, Error>
+where
+    D: Into<Data>,
+{
+    from_bytes_at(data, 0)
+}
+
+/// Load the font at the given index within the collection described by the given bytes,
+/// registering its data for shaping.
+///
+/// The font and its registered data share the same bytes.
+pub fn from_bytes_at<D>(data: D, index: u32) -> Result<Font, Error>
+where
+    D: Into<Data>,
+{
+    let data = data.into();
+    let collection = FontCollection::from_bytes(data.clone()).map_err(std::io::Error::from)?;
+    let font = collection.font_at(index as usize).or(Err(Error::NoFont))?;
+    register_data_at(&font, data, index);
+    Ok(font)
+}
+
+/// Load the font installed on the system that best matches the given family, weight and style.
+///
+/// The family may be the name of a font family, e.g. `"Noto Color Emoji"`, or one of the generic
+/// families `"serif"`, `"sans-serif"`, `"monospace"`, `"cursive"` or `"fantasy"`.
+///
+/// The system fonts are enumerated upon the first call, which may take some time. Returns
+/// `Error::NoFont` if no installed font belongs to the family.
+///
+/// This function is only available if the `system-fonts` feature is enabled.
+#[cfg(feature = "system-fonts")]
+pub fn from_system(family: &str, weight: Weight, style: Style) -> Result<Font, Error> {
+    let mut guard = SYSTEM.lock().expect("failed to lock system font database");
+    let db = guard.get_or_insert_with(|| {
+        let mut db = fontdb::Database::new();
+        db.load_system_fonts();
+        db
+    });
+    let family = match family {
+        "serif" => fontdb::Family::Serif,
+        "sans-serif" => fontdb::Family::SansSerif,
+        "monospace" => fontdb::Family::Monospace,
+        "cursive" => fontdb::Family::Cursive,
+        "fantasy" => fontdb::Family::Fantasy,
+        name => fontdb::Family::Name(name),
+    };
+    let query = fontdb::Query {
+        families: &[family],
+        weight: fontdb::Weight(weight.0),
+        stretch: fontdb::Stretch::Normal,
+        style: match style {
+            Style::Normal => fontdb::Style::Normal,
+            Style::Italic => fontdb::Style::Italic,
+            Style::Oblique => fontdb::Style::Oblique,
+        },
+    };
+    let face = db.query(&query).ok_or(Error::NoFont)?;
+    let (bytes, index) = db
+        .with_face_data(face, |bytes, index| (bytes.to_vec(), index))
+        .ok_or(Error::NoFont)?;
+    from_bytes_at(bytes, index)
+}
+
+/// Whether or not the given font contains a glyph for the given character.
+pub fn contains(font: &Font, ch: char) -> bool {
+    font.glyph(ch).id() != GlyphId(0)
+}
+
+/// Select the first font containing the given character from the `primary` font followed by each
+/// of the `fallbacks` in order.
+///
+/// Returns the `primary` font if no font contains the character.
+pub fn select<'a>(ch: char, primary: &'a Font, fallbacks: &'a [Font]) -> &'a Font {
+    if contains(primary, ch) {
+        return primary;
+    }
+    fallbacks
+        .iter()
+        .find(|font| contains(font, ch))
+        .unwrap_or(primary)
+}
+
+// Identifies the data from which the given font was loaded.
+//
+// `rusttype` provides no access to a font's data, however the font's name strings are slices of
+// it. The address of the first name string is therefore unique to the data and the font within it,
+// unlike the name strings themselves which are shared by different versions of the same font.
+// Returns `None` if the font has no name table.
+fn data_key(font: &Font) -> Option<usize> {
+    font.font_name_strings()
+        .next()
+        .map(|(name, _, _)| name.as_ptr() as usize)
+}
+
+// Whether or not the character modifies the preceding character, in which case it should be
+// displayed using the same font, e.g. combining marks, joiners and variation selectors.
+pub(crate) fn is_combining(ch: char) -> bool {
+    match ch {
+        '\u{0300}'..='\u{036F}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{1F3FB}'..='\u{1F3FF}'
+        | '\u{E0100}'..='\u{E01EF}' => true,
+        _ => false,
+    }
+}
+
 /// Load a `FontCollection` from a file at a given path.
 pub fn collection_from_file<P>(path: P) -> Result<FontCollection, std::io::Error>
 where
@@ -136,8 +387,8 @@ pub fn from_file<P>(path: P) -> Result<Font, Error>
 where
     P: AsRef<std::path::Path>,
 {
-    let collection = collection_from_file(path)?;
-    collection.into_font().or(Err(Error::NoFont))
+    let bytes = std::fs::read(path)?;
+    from_bytes(bytes)
 }
 
 /// Load the default notosans font.
@@ -147,9 +398,13 @@ where
 pub fn default_notosans() -> Font {
     let collection = FontCollection::from_bytes(notosans::REGULAR_TTF)
         .expect("failed to load the `notosans::REGULAR_TTF` font collection");
-    collection
+    let font = collection
         .into_font()
-        .expect("the `notosans::REGULAR_TTF` font collection contained no fonts")
+        .expect("the `notosans::REGULAR_TTF` font collection contained no fonts");
+    if data(&font).is_none() {
+        register_data(&font, notosans::REGULAR_TTF);
+    }
+    font
 }
 
 /// The directory that is searched for default fonts.
@@ -189,6 +444,18 @@ pub fn default(assets: &Path) -> Result<Font, Error> {
     Err(Error::NoFont)
 }
 
+impl Default for Weight {
+    fn default() -> Self {
+        Weight::NORMAL
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style::Normal
+    }
+}
+
 impl Iterator for NewIds {
     type Item = Id;
     fn next(&mut self) -> Option<Self::Item> {
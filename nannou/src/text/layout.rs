@@ -10,6 +10,7 @@ pub struct Builder {
     pub font_size: Option<FontSize>,
     pub justify: Option<Justify>,
     pub font: Option<Option<Font>>,
+    pub fallback_fonts: Option<Vec<Font>>,
     pub y_align: Option<Align>,
 }
 
@@ -21,6 +22,7 @@ pub struct Layout {
     pub justify: Justify,
     pub font_size: FontSize,
     pub font: Option<Font>,
+    pub fallback_fonts: Vec<Font>,
     pub y_align: Align,
 }
 
@@ -72,6 +74,25 @@ impl Builder {
         self
     }
 
+    /// Specify the fonts used, in order, to display characters that are missing from the `font`.
+    ///
+    /// Characters missing from every font are displayed using the `font`'s missing glyph.
+    pub fn fallback_fonts<I>(mut self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        self.fallback_fonts = Some(fonts.into_iter().collect());
+        self
+    }
+
+    /// Append a font to the end of the fallback chain.
+    ///
+    /// See the `fallback_fonts` method for details.
+    pub fn fallback_font(mut self, font: Font) -> Self {
+        self.fallback_fonts.get_or_insert_with(Vec::new).push(font);
+        self
+    }
+
     /// Describe the end along the *x* axis to which the text should be aligned.
     pub fn justify(mut self, justify: Justify) -> Self {
         self.justify = Some(justify);
@@ -125,6 +146,7 @@ impl Builder {
     /// Set all the parameters via an existing `Layout`
     pub fn layout(mut self, layout: &Layout) -> Self {
         self.font = Some(layout.font.clone());
+        self.fallback_fonts = Some(layout.fallback_fonts.clone());
         self.line_spacing(layout.line_spacing)
             .line_wrap(layout.line_wrap)
             .justify(layout.justify)
@@ -140,6 +162,7 @@ impl Builder {
             justify: self.justify.unwrap_or(DEFAULT_JUSTIFY),
             font_size: self.font_size.unwrap_or(DEFAULT_FONT_SIZE),
             font: self.font.unwrap_or(None),
+            fallback_fonts: self.fallback_fonts.unwrap_or_default(),
             y_align: self.y_align.unwrap_or(DEFAULT_Y_ALIGN),
         }
     }
@@ -153,6 +176,7 @@ impl Default for Layout {
             justify: DEFAULT_JUSTIFY,
             font_size: DEFAULT_FONT_SIZE,
             font: None,
+            fallback_fonts: vec![],
             y_align: DEFAULT_Y_ALIGN,
         }
     }
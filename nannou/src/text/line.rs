@@ -3,6 +3,7 @@
 //! This module is the core of multi-line text handling.
 
 use crate::geom::{Range, Rect};
+use crate::text::span::{self, Span};
 use crate::text::{self, FontSize, Scalar, Wrap};
 
 /// The two types of **Break** indices returned by the **WrapIndicesBy** iterators.
@@ -102,7 +103,10 @@ pub struct Rects<I> {
     x_align: text::Justify,
     line_spacing: Scalar,
     last_line_top: Scalar,
+    last_line_height: Scalar,
     font_size: FontSize,
+    font_sizes: Vec<FontSize>,
+    line: usize,
     next: Option<Rect>,
 }
 
@@ -119,6 +123,46 @@ pub struct SelectedRects<'a, I> {
 /// wrapping function.
 pub type NextBreakFnPtr = fn(&str, &text::Font, FontSize, Scalar) -> NextBreak;
 
+/// Types that may be used by **Infos** to find the next line break.
+///
+/// This is implemented for all functions compatible with **NextBreakFnPtr**, which are given the
+/// remaining text starting at the beginning of the line. Other implementations are given the
+/// full text along with the byte index at which the line starts, allowing them to depend on the
+/// position of the line within the text (e.g. **SpanBreak**).
+pub trait NextBreakFn {
+    /// Find the next break within the line starting at `start_byte` within the given `text`.
+    ///
+    /// Indices within the returned **NextBreak** are relative to `start_byte`.
+    fn next_break(
+        &mut self,
+        text: &str,
+        start_byte: usize,
+        font: &text::Font,
+        font_size: FontSize,
+        max_width: Scalar,
+    ) -> NextBreak;
+}
+
+/// Finds line breaks while measuring each character with the font and font size resolved from a
+/// set of styled **Span**s.
+///
+/// Produced via the `infos_with_spans` function.
+#[derive(Copy, Clone, Debug)]
+pub struct SpanBreak<'a> {
+    spans: &'a [Span],
+    wrap: Option<Wrap>,
+}
+
+/// Finds line breaks using pre-measured character advances, e.g. those produced by shaping the
+/// text via the `text::shape` module.
+///
+/// Produced via the `infos_measured` function.
+#[derive(Copy, Clone, Debug)]
+pub struct MeasuredBreak<'a> {
+    metrics: &'a [(Scalar, Scalar)],
+    wrap: Option<Wrap>,
+}
+
 impl Break {
     /// Return the index at which the break occurs.
     pub fn byte_index(self) -> usize {
@@ -231,56 +275,10 @@ fn advance_width_and_height(
 /// along with the width of the line.
 fn next_break(text: &str, font: &text::Font, font_size: FontSize) -> NextBreak {
     let scale = text::pt_to_scale(font_size);
-    let mut width = 0.0;
-    let mut height = 0.0;
-    let mut char_i = 0;
-    let mut char_indices = text.char_indices().peekable();
     let mut last_glyph = None;
-    while let Some((byte_i, ch)) = char_indices.next() {
-        // Check for a newline.
-        if ch == '\r' {
-            if let Some(&(_, '\n')) = char_indices.peek() {
-                let break_ = Break::Newline {
-                    byte: byte_i,
-                    char: char_i,
-                    len_bytes: 2, // skip "\r\n"
-                    len_chars: 2,
-                };
-                return NextBreak {
-                    break_,
-                    width,
-                    height,
-                };
-            }
-        } else if ch == '\n' {
-            let break_ = Break::Newline {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 1, // skip "\n"
-                len_chars: 1,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        // Update the width.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
-        width += adv_w;
-        height = height.max(h);
-        char_i += 1;
-    }
-    let break_ = Break::End {
-        byte: text.len(),
-        char: char_i,
-    };
-    NextBreak {
-        break_,
-        width,
-        height,
-    }
+    next_break_measured(text, std::f32::MAX, None, |_, ch| {
+        advance_width_and_height(ch, font, scale, &mut last_glyph)
+    })
 }
 
 /// Returns the next index at which the text will break by either:
@@ -295,82 +293,19 @@ fn next_break_by_character(
     max_width: Scalar,
 ) -> NextBreak {
     let scale = text::pt_to_scale(font_size);
-    let mut width = 0.0;
-    let mut height = 0.0;
-    let mut char_i = 0;
-    let mut char_indices = text.char_indices().peekable();
     let mut last_glyph = None;
-    while let Some((byte_i, ch)) = char_indices.next() {
-        // Check for a newline.
-        if ch == '\r' {
-            if let Some(&(_, '\n')) = char_indices.peek() {
-                let break_ = Break::Newline {
-                    byte: byte_i,
-                    char: char_i,
-                    len_bytes: 2, // skip "\r\n"
-                    len_chars: 2,
-                };
-                return NextBreak {
-                    break_,
-                    width,
-                    height,
-                };
-            }
-        } else if ch == '\n' {
-            let break_ = Break::Newline {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 1, // skip "\n"
-                len_chars: 1,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        // Add the character's width to the width so far.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
-        let new_width = width + adv_w;
-
-        // Check for a line wrap.
-        if new_width > max_width {
-            let break_ = Break::Wrap {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 0, // skip nothing in middle of word
-                len_chars: 0,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        height = height.max(h);
-        width = new_width;
-        char_i += 1;
-    }
-
-    let break_ = Break::End {
-        byte: text.len(),
-        char: char_i,
-    };
-    NextBreak {
-        break_,
-        width,
-        height,
-    }
+    next_break_measured(text, max_width, Some(Wrap::Character), |_, ch| {
+        advance_width_and_height(ch, font, scale, &mut last_glyph)
+    })
 }
 
 /// Returns the next index at which the text will break by either:
 /// - A newline character.
-/// - A line wrap at the beginning of the whitespace that preceeds the first word
-/// exceeding the `max_width`.
+/// - A line wrap at the last line break opportunity preceding the first word exceeding the
+/// `max_width`, as described by the Unicode Line Breaking Algorithm (UAX #14). Whitespace
+/// preceding the opportunity is skipped.
 /// - A line wrap at the beginning of the first character exceeding the `max_width`,
-/// if no whitespace appears for `max_width` characters.
+/// if no line break opportunity appears for `max_width` characters.
 ///
 /// Also returns the width the line alongside the Break.
 fn next_break_by_whitespace(
@@ -379,18 +314,46 @@ fn next_break_by_whitespace(
     font_size: FontSize,
     max_width: Scalar,
 ) -> NextBreak {
+    let scale = text::pt_to_scale(font_size);
+    let mut last_glyph = None;
+    next_break_measured(text, max_width, Some(Wrap::Whitespace), |_, ch| {
+        advance_width_and_height(ch, font, scale, &mut last_glyph)
+    })
+}
+
+/// The logic shared by all `next_break` functions.
+///
+/// Returns the next index at which the text breaks via a newline character or, if `wrap` is
+/// `Some`, via the given wrapping strategy. The `measure` function is given the byte index and
+/// the `char` of each character and returns its advance width and height.
+fn next_break_measured<M>(
+    text: &str,
+    max_width: Scalar,
+    wrap: Option<Wrap>,
+    mut measure: M,
+) -> NextBreak
+where
+    M: FnMut(usize, char) -> (Scalar, Scalar),
+{
+    // The last opportunity at which the line may be wrapped, excluding trailing whitespace.
     struct Last {
         byte: usize,
         char: usize,
+        len_bytes: usize,
+        len_chars: usize,
         width_before: Scalar,
     }
-    let scale = text::pt_to_scale(font_size);
-    let mut last_whitespace_start = None;
+    let mut opportunities = match wrap {
+        Some(Wrap::Whitespace) => Some(unicode_linebreak::linebreaks(text).peekable()),
+        _ => None,
+    };
+    let mut last_opportunity = None;
+    // The start of the current run of whitespace along with the width preceding it.
+    let mut whitespace_start: Option<(usize, usize, Scalar)> = None;
     let mut width = 0.0;
     let mut height = 0.0;
     let mut char_i = 0;
     let mut char_indices = text.char_indices().peekable();
-    let mut last_glyph = None;
     while let Some((byte_i, ch)) = char_indices.next() {
         // Check for a newline.
         if ch == '\r' {
@@ -421,32 +384,36 @@ fn next_break_by_whitespace(
             };
         }
 
+        // Check for a line break opportunity preceding this character.
+        if let Some(ref mut opportunities) = opportunities {
+            while let Some(&(byte, _)) = opportunities.peek() {
+                if byte > byte_i {
+                    break;
+                }
+                opportunities.next();
+                if byte == byte_i && byte_i > 0 {
+                    let (byte, char, width_before) =
+                        whitespace_start.unwrap_or((byte_i, char_i, width));
+                    last_opportunity = Some(Last {
+                        byte,
+                        char,
+                        len_bytes: byte_i - byte,
+                        len_chars: char_i - char,
+                        width_before,
+                    });
+                }
+            }
+        }
+
         // Add the character's width to the width so far.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
+        let (adv_w, h) = measure(byte_i, ch);
         let new_width = width + adv_w;
 
         // Check for a line wrap.
-        if width > max_width {
-            match last_whitespace_start {
-                Some(Last {
-                    byte,
-                    char,
-                    width_before,
-                }) => {
-                    let break_ = Break::Wrap {
-                        byte: byte,
-                        char: char,
-                        len_bytes: 1, // skip one whitespace character
-                        len_chars: 1,
-                    };
-                    let width = width_before;
-                    return NextBreak {
-                        break_,
-                        width,
-                        height,
-                    };
-                }
-                None => {
+        match wrap {
+            None => (),
+            Some(Wrap::Character) => {
+                if new_width > max_width {
                     let break_ = Break::Wrap {
                         byte: byte_i,
                         char: char_i,
@@ -460,19 +427,61 @@ fn next_break_by_whitespace(
                     };
                 }
             }
-        }
+            Some(Wrap::Whitespace) => {
+                // Trailing whitespace never causes a line to wrap.
+                if !ch.is_whitespace() && new_width > max_width {
+                    match last_opportunity {
+                        Some(Last {
+                            byte,
+                            char,
+                            len_bytes,
+                            len_chars,
+                            width_before,
+                        }) => {
+                            let break_ = Break::Wrap {
+                                byte,
+                                char,
+                                len_bytes, // skip the trailing whitespace
+                                len_chars,
+                            };
+                            let width = width_before;
+                            return NextBreak {
+                                break_,
+                                width,
+                                height,
+                            };
+                        }
+                        // Always allow at least one character per line.
+                        None if char_i > 0 => {
+                            let break_ = Break::Wrap {
+                                byte: byte_i,
+                                char: char_i,
+                                len_bytes: 0, // skip nothing in middle of word
+                                len_chars: 0,
+                            };
+                            return NextBreak {
+                                break_,
+                                width,
+                                height,
+                            };
+                        }
+                        None => (),
+                    }
+                }
 
-        // Check for a new whitespace.
-        if ch.is_whitespace() {
-            last_whitespace_start = Some(Last {
-                byte: byte_i,
-                char: char_i,
-                width_before: width,
-            });
+                // Track the start of the current run of whitespace.
+                if ch.is_whitespace() {
+                    if whitespace_start.is_none() {
+                        whitespace_start = Some((byte_i, char_i, width));
+                    }
+                } else {
+                    whitespace_start = None;
+                }
+            }
         }
 
-        width = new_width;
         height = height.max(h);
+        width = new_width;
         char_i += 1;
     }
 
@@ -512,7 +521,7 @@ pub fn infos_wrapped_by<'a, F>(
     next_break_fn: F,
 ) -> Infos<'a, F>
 where
-    F: for<'b> FnMut(&'b str, &'b text::Font, FontSize, Scalar) -> NextBreak,
+    F: NextBreakFn,
 {
     Infos {
         text: text,
@@ -562,6 +571,54 @@ pub fn infos_maybe_wrapped<'a>(
     }
 }
 
+/// Produce an `Infos` iterator for text styled by the given `spans`, that may or may not be
+/// wrapped.
+///
+/// Each character is measured using the font and font size resolved from the spans, falling back
+/// to the given `font` and `font_size`.
+pub fn infos_with_spans<'a>(
+    text: &'a str,
+    spans: &'a [Span],
+    font: &'a text::Font,
+    font_size: FontSize,
+    maybe_wrap: Option<Wrap>,
+    max_width: Scalar,
+) -> Infos<'a, SpanBreak<'a>> {
+    let max_width = match maybe_wrap {
+        None => std::f32::MAX,
+        Some(_) => max_width,
+    };
+    let next_break_fn = SpanBreak {
+        spans,
+        wrap: maybe_wrap,
+    };
+    infos_wrapped_by(text, font, font_size, max_width, next_break_fn)
+}
+
+/// Produce an `Infos` iterator for text whose characters have already been measured, that may or
+/// may not be wrapped.
+///
+/// `metrics` must contain the advance width and height of each character at the index of the
+/// character's first byte, e.g. as produced by `text::shape::char_metrics`.
+pub fn infos_measured<'a>(
+    text: &'a str,
+    metrics: &'a [(Scalar, Scalar)],
+    font: &'a text::Font,
+    font_size: FontSize,
+    maybe_wrap: Option<Wrap>,
+    max_width: Scalar,
+) -> Infos<'a, MeasuredBreak<'a>> {
+    let max_width = match maybe_wrap {
+        None => std::f32::MAX,
+        Some(_) => max_width,
+    };
+    let next_break_fn = MeasuredBreak {
+        metrics,
+        wrap: maybe_wrap,
+    };
+    infos_wrapped_by(text, font, font_size, max_width, next_break_fn)
+}
+
 /// Produce an iterator yielding the bounding `Rect` for each line in the text.
 ///
 /// Yielded `Rect`s will begin with the top-left of the first line at a [0.0, 0.0].
@@ -569,7 +626,26 @@ pub fn infos_maybe_wrapped<'a>(
 /// This function assumes that `font_size` and `max_width` are the same as those used to produce
 /// the `Info`s yielded by the `infos` Iterator.
 pub fn rects<I>(
+    infos: I,
+    font_size: FontSize,
+    max_width: Scalar,
+    x_align: text::Justify,
+    line_spacing: Scalar,
+) -> Rects<I>
+where
+    I: Iterator<Item = Info>,
+{
+    rects_with_font_sizes(infos, vec![], font_size, max_width, x_align, line_spacing)
+}
+
+/// Produce an iterator yielding the bounding `Rect` for each line in the text, where the height
+/// of each line is given by the font size at the same index within `font_sizes`.
+///
+/// Lines without a corresponding font size use the given `font_size`. This is useful for text
+/// with styled spans, where the height of each line is the greatest font size within the line.
+pub fn rects_with_font_sizes<I>(
     mut infos: I,
+    font_sizes: Vec<FontSize>,
     font_size: FontSize,
     max_width: Scalar,
     x_align: text::Justify,
@@ -578,6 +654,7 @@ pub fn rects<I>(
 where
     I: Iterator<Item = Info>,
 {
+    let first_line_height = font_sizes.first().cloned().unwrap_or(font_size) as Scalar;
     let first_rect = infos.next().map(|first_info| {
         // Calculate the `x` `Range` of the first line `Rect`.
         let x_bounds = Range::new(0.0, max_width);
@@ -587,9 +664,9 @@ where
             text::Justify::Center => range.align_middle_of(x_bounds),
             text::Justify::Right => range.align_end_of(x_bounds),
         };
-        let y_start = -(font_size as Scalar);
+        let y_start = -first_line_height;
         //let y_end = y_start + first_info.height;
-        let y_end = y_start + font_size as Scalar;
+        let y_end = y_start + first_line_height;
         let y = Range::new(y_start, y_end);
         Rect { x: x, y: y }
     });
@@ -598,7 +675,10 @@ where
         next: first_rect,
         x_align: x_align,
         last_line_top: 0.0,
+        last_line_height: first_line_height,
         font_size: font_size,
+        font_sizes: font_sizes,
+        line: 0,
         line_spacing: line_spacing,
     }
 }
@@ -630,9 +710,72 @@ where
     }
 }
 
-impl<'a, F> Iterator for Infos<'a, F>
+impl<F> NextBreakFn for F
 where
     F: for<'b> FnMut(&'b str, &'b text::Font, FontSize, Scalar) -> NextBreak,
+{
+    fn next_break(
+        &mut self,
+        text: &str,
+        start_byte: usize,
+        font: &text::Font,
+        font_size: FontSize,
+        max_width: Scalar,
+    ) -> NextBreak {
+        self(&text[start_byte..], font, font_size, max_width)
+    }
+}
+
+impl<'a> NextBreakFn for SpanBreak<'a> {
+    fn next_break(
+        &mut self,
+        text: &str,
+        start_byte: usize,
+        font: &text::Font,
+        font_size: FontSize,
+        max_width: Scalar,
+    ) -> NextBreak {
+        let spans = self.spans;
+        let mut last_style: Option<span::Resolved> = None;
+        let mut last_glyph = None;
+        next_break_measured(&text[start_byte..], max_width, self.wrap, |byte, ch| {
+            let style = span::resolve(spans, start_byte + byte, font, font_size);
+            // Kerning is reset at the start of each run, matching the layout of `Text::runs`.
+            if let Some(ref last) = last_style {
+                if !last.same_style(&style) {
+                    last_glyph = None;
+                }
+            }
+            let scale = text::pt_to_scale(style.font_size);
+            let size = advance_width_and_height(ch, style.font, scale, &mut last_glyph);
+            last_style = Some(style);
+            size
+        })
+    }
+}
+
+impl<'a> NextBreakFn for MeasuredBreak<'a> {
+    fn next_break(
+        &mut self,
+        text: &str,
+        start_byte: usize,
+        _font: &text::Font,
+        _font_size: FontSize,
+        max_width: Scalar,
+    ) -> NextBreak {
+        let metrics = self.metrics;
+        next_break_measured(&text[start_byte..], max_width, self.wrap, |byte, _| {
+            metrics
+                .get(start_byte + byte)
+                .cloned()
+                .unwrap_or((0.0, 0.0))
+        })
+    }
+}
+
+impl<'a, F> Iterator for Infos<'a, F>
+where
+    F: NextBreakFn,
 {
     type Item = Info;
     fn next(&mut self) -> Option<Self::Item> {
@@ -647,7 +790,7 @@ where
             ref mut last_break,
         } = *self;
 
-        let next = next_break_fn(&text[*start_byte..], font, font_size, max_width);
+        let next = next_break_fn.next_break(text, *start_byte, font, font_size, max_width);
         match next.break_ {
             Break::Newline { .. } | Break::Wrap { .. } => {
                 let next_break = match next.break_ {
@@ -756,17 +899,23 @@ where
             ref mut infos,
             x_align,
             ref mut last_line_top,
+            ref mut last_line_height,
             font_size,
+            ref font_sizes,
+            ref mut line,
             line_spacing,
         } = *self;
         next.map(|line_rect| {
             *next = infos.next().map(|info| {
+                *line += 1;
+                let line_height = font_sizes.get(*line).cloned().unwrap_or(font_size) as Scalar;
                 let y = {
-                    let line_top = *last_line_top - font_size as Scalar - line_spacing;
+                    let line_top = *last_line_top - *last_line_height - line_spacing;
                     *last_line_top = line_top;
-                    let y_start = line_top - font_size as Scalar;
+                    *last_line_height = line_height;
+                    let y_start = line_top - line_height;
                     //let y_end = y_start + info.height;
-                    let y_end = y_start + font_size as Scalar;
+                    let y_end = y_start + line_height;
                     Range::new(y_start, y_end)
                 };
 
@@ -3,21 +3,27 @@
 //! Currently, this crate is used primarily by the `draw.text()` API but will also play an
 //! important role in future GUI work.
 
+pub mod along_path;
 pub mod cursor;
 pub mod font;
 pub mod glyph;
 pub mod layout;
 pub mod line;
+pub mod sdf;
+pub mod shape;
+pub mod span;
 pub mod rt {
     //! Re-exported RustType geometric types.
-    pub use rusttype::{gpu_cache, point, vector, Point, Rect, Vector};
+    pub use rusttype::{gpu_cache, point, vector, Point, Rect, SharedBytes, Vector};
 }
 
 // Re-export all relevant rusttype types here.
 pub use self::layout::Layout;
+pub use self::span::Span;
 pub use rusttype::gpu_cache::Cache as GlyphCache;
 pub use rusttype::{Glyph, GlyphId, GlyphIter, LayoutIter, Scale, ScaledGlyph};
 
+use crate::color::LinSrgba;
 use crate::geom;
 use crate::glam::Vec2;
 use std::borrow::Cow;
@@ -42,6 +48,7 @@ pub type FontSize = u32;
 pub struct Builder<'a> {
     text: Cow<'a, str>,
     layout_builder: layout::Builder,
+    spans: Vec<Span>,
 }
 
 /// An instance of some multi-line text and its layout.
@@ -50,10 +57,48 @@ pub struct Text<'a> {
     text: Cow<'a, str>,
     font: Font,
     layout: Layout,
+    spans: Vec<Span>,
     line_infos: Vec<line::Info>,
+    // The greatest font size within each line. Empty if there are no spans.
+    line_font_sizes: Vec<FontSize>,
+    runs: Vec<Run>,
     rect: geom::Rect,
 }
 
+/// A contiguous segment of a single line of text in which every character shares the same
+/// resolved **span::Style**.
+///
+/// Runs are shaped as a whole and are stored in visual order. Text without spans or
+/// right-to-left scripts produces a single run for each non-empty line.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// The index of the line on which the run appears.
+    pub line: usize,
+    /// The range of bytes within the full text covered by the run.
+    pub byte_range: std::ops::Range<usize>,
+    /// The index of the first character of the run within the full text.
+    pub start_char: usize,
+    /// The font used to lay out the run.
+    pub font: Font,
+    /// The font size used to lay out the run.
+    pub font_size: FontSize,
+    /// The color of the run, if specified by a span.
+    pub color: Option<LinSrgba>,
+    /// Whether or not the run is underlined.
+    pub underline: bool,
+    /// Whether or not the run is struck through.
+    pub strikethrough: bool,
+    /// The direction in which the run is laid out.
+    pub direction: shape::Direction,
+    /// The shaped glyphs of the run in visual order.
+    pub glyphs: Vec<shape::Glyph>,
+    /// The bounds of the run.
+    ///
+    /// The bottom edge is the baseline of the line, the height is equal to the font size and the
+    /// width is the total advance width of the run's glyphs.
+    pub rect: geom::Rect,
+}
+
 /// An iterator yielding each line within the given `text` as a new `&str`, where the start and end
 /// indices into each line are provided by the given iterator.
 #[derive(Clone)]
@@ -87,12 +132,11 @@ pub type TextLinesWithRects<'a> = std::iter::Zip<TextLines<'a>, TextLineRects<'a
 /// An alias for the iterator yielded by `Text::glyphs_per_line`.
 pub type TextGlyphsPerLine<'a> = glyph::RectsPerLine<'a, TextLinesWithRects<'a>>;
 
-/// An alias for the iterator yielded by `Text::glyphs`.
-pub type TextGlyphs<'a> = std::iter::FlatMap<
-    TextGlyphsPerLine<'a>,
-    glyph::Rects<'a, 'a>,
-    fn(glyph::Rects<'a, 'a>) -> glyph::Rects<'a, 'a>,
->;
+/// The iterator yielded by `Text::glyphs`.
+pub struct TextGlyphs<'a> {
+    runs: std::slice::Iter<'a, Run>,
+    run: Option<(&'a Run, std::slice::Iter<'a, shape::Glyph>)>,
+}
 
 /// Alignment along an axis.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
@@ -123,15 +167,21 @@ pub enum Wrap {
     /// Wrap at the first character that exceeds the width.
     Character,
     /// Wrap at the first word that exceeds the width.
+    ///
+    /// Words are delimited by the line break opportunities described by the Unicode Line
+    /// Breaking Algorithm (UAX #14), allowing for wrapping of scripts that do not separate words
+    /// with spaces, e.g. Chinese and Japanese.
     Whitespace,
 }
 
 impl<'a> From<Cow<'a, str>> for Builder<'a> {
     fn from(text: Cow<'a, str>) -> Self {
         let layout_builder = Default::default();
+        let spans = vec![];
         Builder {
             text,
             layout_builder,
+            spans,
         }
     }
 }
@@ -198,6 +248,19 @@ impl<'a> Builder<'a> {
         self.map_layout(|l| l.font(font))
     }
 
+    /// Specify the fonts used, in order, to display characters that are missing from the `font`.
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        self.map_layout(|l| l.fallback_fonts(fonts))
+    }
+
+    /// Append a font to the end of the fallback chain.
+    pub fn fallback_font(self, font: Font) -> Self {
+        self.map_layout(|l| l.fallback_font(font))
+    }
+
     /// Describe the end along the *x* axis to which the text should be aligned.
     pub fn justify(self, justify: Justify) -> Self {
         self.map_layout(|l| l.justify(justify))
@@ -250,6 +313,26 @@ impl<'a> Builder<'a> {
         self.map_layout(|l| l.layout(layout))
     }
 
+    /// Apply the given style to the given range of bytes within the text.
+    ///
+    /// Properties that the style leaves unspecified fall back to the layout. Where spans
+    /// overlap, the properties of later spans take precedence.
+    pub fn span(mut self, range: std::ops::Range<usize>, style: span::Style) -> Self {
+        self.spans.push(Span::new(range, style));
+        self
+    }
+
+    /// Apply each of the given spans to the text.
+    ///
+    /// See the `span` method for details.
+    pub fn spans<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = Span>,
+    {
+        self.spans.extend(spans);
+        self
+    }
+
     /// Build the text.
     ///
     /// This iterates over the text in order to pre-calculates the text's multi-line information
@@ -270,17 +353,68 @@ impl<'a> Builder<'a> {
                 .expect("failed to detect the assets directory when searching for a default font");
             font::default(&assets).expect("failed to detect a default font")
         });
+        let spans = self.spans;
         let max_width = rect.w();
-        let line_infos =
-            line::infos_maybe_wrapped(&text, &font, layout.font_size, layout.line_wrap, max_width)
-                .collect();
-        Text {
+        let mut text = Text {
             text,
             font,
             layout,
-            line_infos,
+            spans,
+            line_infos: vec![],
+            line_font_sizes: vec![],
+            runs: vec![],
             rect,
+        };
+
+        // The bidi info and the font faces are shared between measuring and laying out runs.
+        let bidi = unicode_bidi::BidiInfo::new(&text.text, None);
+        let span_fonts = text
+            .spans
+            .iter()
+            .filter_map(|span| span.style.font.as_ref());
+        let fonts = std::iter::once(&text.font)
+            .chain(&text.layout.fallback_fonts)
+            .chain(span_fonts);
+        let font_data = shape::FontData::new(fonts);
+        let shaper = shape::Shaper::new(&font_data);
+
+        let metrics = measure_chars(
+            &text.text,
+            &bidi.levels,
+            &text.spans,
+            &text.font,
+            &text.layout.fallback_fonts,
+            text.layout.font_size,
+            &shaper,
+        );
+        text.line_infos = line::infos_measured(
+            &text.text,
+            &metrics,
+            &text.font,
+            text.layout.font_size,
+            text.layout.line_wrap,
+            max_width,
+        )
+        .collect();
+        if !text.spans.is_empty() {
+            text.line_font_sizes = text
+                .line_infos
+                .iter()
+                .map(|info| {
+                    text.text[info.byte_range()]
+                        .char_indices()
+                        .map(|(i, _)| {
+                            let byte = info.start_byte + i;
+                            let font_size = text.layout.font_size;
+                            span::resolve(&text.spans, byte, &text.font, font_size).font_size
+                        })
+                        .max()
+                        .unwrap_or(text.layout.font_size)
+                })
+                .collect();
         }
+        text.runs = text.layout_runs(&bidi, &shaper);
+        text
     }
 }
 
@@ -301,10 +435,27 @@ impl<'a> Text<'a> {
     }
 
     /// The font used for this text instance.
+    ///
+    /// Spans may lay out parts of the text using other fonts. See the `runs` method.
     pub fn font(&self) -> &Font {
         &self.font
     }
 
+    /// The styled spans applied to the text.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// The contiguous segments of each line that share the same style.
+    pub fn runs(&self) -> &[Run] {
+        &self.runs
+    }
+
+    /// The slice of text covered by the given run.
+    pub fn run_text(&self, run: &Run) -> &str {
+        &self.text[run.byte_range.clone()]
+    }
+
     /// The number of lines in the text.
     pub fn num_lines(&self) -> usize {
         self.line_infos.len()
@@ -324,7 +475,7 @@ impl<'a> Text<'a> {
             None => return geom::Rect::from_w_h(0.0, 0.0),
             Some(info) => info,
         };
-        let line_h = self.layout.font_size as Scalar;
+        let line_h = self.line_font_size(0) as Scalar;
         r.y.end -= line_h - info.height;
         r
     }
@@ -332,7 +483,7 @@ impl<'a> Text<'a> {
     /// The rectangle that describes the min and max bounds along each axis reached by the text.
     ///
     /// This is similar to `bounding_rect` but assumes that all lines have a height equal to
-    /// `font_size`, rather than using the exact height.
+    /// their greatest `font_size`, rather than using the exact height.
     pub fn bounding_rect_by_lines(&self) -> geom::Rect {
         let mut lrs = self.line_rects();
         let lr = match lrs.next() {
@@ -359,12 +510,16 @@ impl<'a> Text<'a> {
             None => return 0.0,
             Some(info) => info,
         };
-        exact_height(
-            info.height,
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-        )
+        if self.line_font_sizes.is_empty() {
+            return exact_height(
+                info.height,
+                self.num_lines(),
+                self.layout.font_size,
+                self.layout.line_spacing,
+            );
+        }
+        let first_line_h = self.line_font_size(0) as Scalar;
+        self.height_by_lines() - first_line_h + info.height
     }
 
     /// Determine the total height of a block of text with the given number of lines, font size and
@@ -373,11 +528,16 @@ impl<'a> Text<'a> {
     /// The height of all lines of text are assumed to match the `font_size`. If looking for the exact
     /// height, see the `exact_height` function.
     pub fn height_by_lines(&self) -> Scalar {
-        height_by_lines(
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-        )
+        if self.line_font_sizes.is_empty() {
+            return height_by_lines(
+                self.num_lines(),
+                self.layout.font_size,
+                self.layout.line_spacing,
+            );
+        }
+        let num_lines = self.num_lines();
+        let lines_h: Scalar = self.line_font_sizes.iter().map(|&s| s as Scalar).sum();
+        lines_h + num_lines.saturating_sub(1) as Scalar * self.layout.line_spacing
     }
 
     /// Produce an iterator yielding each wrapped line within the **Text**.
@@ -391,8 +551,9 @@ impl<'a> Text<'a> {
     /// The bounding rectangle for each line.
     pub fn line_rects(&self) -> TextLineRects {
         let offset = self.position_offset();
-        let line_rects = line::rects(
+        let line_rects = line::rects_with_font_sizes(
             self.line_infos.iter().cloned(),
+            self.line_font_sizes.clone(),
             self.layout.font_size,
             self.rect.w(),
             self.layout.justify,
@@ -408,15 +569,57 @@ impl<'a> Text<'a> {
 
     /// Produce an iterator yielding iterators yielding every glyph alongside its bounding rect for
     /// each line.
+    ///
+    /// Each line is laid out using the text's `font` and layout font size, ignoring any spans. Use
+    /// the `glyphs` method for the glyphs of styled text.
     pub fn glyphs_per_line(&self) -> TextGlyphsPerLine {
         glyph::rects_per_line(self.lines_with_rects(), &self.font, self.layout.font_size)
     }
 
     /// Produce an iterator yielding every glyph alongside its bounding rect.
     ///
-    /// This is the "flattened" version of the `glyphs_per_line` method.
+    /// Glyphs are yielded run by run, using the font and font size of each **Run**.
     pub fn glyphs(&self) -> TextGlyphs {
-        self.glyphs_per_line().flat_map(std::convert::identity)
+        TextGlyphs {
+            runs: self.runs.iter(),
+            run: None,
+        }
+    }
+
+    /// Produce an iterator yielding each underlined or struck through run alongside the rect of
+    /// its line.
+    ///
+    /// The thickness of each line is proportional to the run's font size. A run that is both
+    /// underlined and struck through is yielded twice.
+    pub fn decorations(&self) -> impl '_ + Iterator<Item = (&Run, geom::Rect)> {
+        self.runs.iter().flat_map(|run| {
+            let px = pt_to_px(run.font_size) as Scalar;
+            let thickness = (px / 14.0).max(1.0);
+            let baseline = run.rect.bottom();
+            let line = move |bottom: Scalar| geom::Rect {
+                x: run.rect.x,
+                y: geom::Range::new(bottom, bottom + thickness),
+            };
+            let underline = match run.underline {
+                true => Some((run, line(baseline - thickness * 2.0))),
+                false => None,
+            };
+            let strikethrough = match run.strikethrough {
+                true => Some((run, line(baseline + px * 0.3 - thickness / 2.0))),
+                false => None,
+            };
+            underline.into_iter().chain(strikethrough)
+        })
+    }
+
+    /// Lay out every glyph along the given path, rotating each to follow the path's tangent.
+    ///
+    /// See `along_path::place` for details on alignment and the `offset`.
+    pub fn glyphs_along_path<I>(&self, path: I, offset: Scalar) -> Vec<along_path::Placement>
+    where
+        I: IntoIterator<Item = lyon::path::PathEvent> + Clone,
+    {
+        along_path::place(self, path, offset)
     }
 
     /// Produce an iterator yielding the path events for every glyph in every line.
@@ -488,13 +691,10 @@ impl<'a> Text<'a> {
         window_size: Vec2,
         scale_factor: Scalar,
     ) -> impl 'a + 'b + Iterator<Item = PositionedGlyph> {
-        rt_positioned_glyphs(
-            self.lines_with_rects(),
-            &self.font,
-            self.layout.font_size,
-            window_size,
-            scale_factor,
-        )
+        self.runs.iter().flat_map(move |run| {
+            run.rt_glyphs(window_size, scale_factor)
+                .map(|(_, glyph)| glyph)
+        })
     }
 
     /// Converts this `Text` instance into an instance that owns the inner text string.
@@ -503,7 +703,10 @@ impl<'a> Text<'a> {
             text,
             font,
             layout,
+            spans,
             line_infos,
+            line_font_sizes,
+            runs,
             rect,
         } = self;
         let text = Cow::Owned(text.into_owned());
@@ -511,19 +714,127 @@ impl<'a> Text<'a> {
             text,
             font,
             layout,
+            spans,
             line_infos,
+            line_font_sizes,
+            runs,
             rect,
         }
     }
 
     fn position_offset(&self) -> Vec2 {
-        position_offset(
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-            self.rect,
-            self.layout.y_align,
-        )
+        let total_text_height = self.height_by_lines();
+        y_aligned_offset(total_text_height, self.rect, self.layout.y_align)
+    }
+
+    // The greatest font size within the line at the given index.
+    fn line_font_size(&self, line: usize) -> FontSize {
+        self.line_font_sizes
+            .get(line)
+            .cloned()
+            .unwrap_or(self.layout.font_size)
+    }
+
+    // Divide each line into shaped runs of uniform direction and style, in visual order.
+    fn layout_runs(&self, bidi: &unicode_bidi::BidiInfo, shaper: &shape::Shaper) -> Vec<Run> {
+        let mut runs = vec![];
+        let infos_and_rects = self.line_infos.iter().zip(self.line_rects());
+        for (line, (info, line_rect)) in infos_and_rects.enumerate() {
+            let mut x = line_rect.left();
+            let baseline = line_rect.bottom();
+            for (range, direction) in shape::visual_runs_with(bidi, info.byte_range()) {
+                let mut styled = style_runs(
+                    &self.text,
+                    range,
+                    &self.spans,
+                    &self.font,
+                    &self.layout.fallback_fonts,
+                    self.layout.font_size,
+                );
+                // The logical end of a right-to-left run appears first.
+                if direction == shape::Direction::RightToLeft {
+                    styled.reverse();
+                }
+                for (style, byte_range) in styled {
+                    let start_char = info.start_char
+                        + self.text[info.start_byte..byte_range.start].chars().count();
+                    let run_text = &self.text[byte_range.clone()];
+                    let mut glyphs = shaper.shape(run_text, style.font, style.font_size, direction);
+                    for g in glyphs.iter_mut() {
+                        g.byte += byte_range.start;
+                        g.char += start_char;
+                    }
+                    let w: Scalar = glyphs.iter().map(|g| g.advance).sum();
+                    let h = style.font_size as Scalar;
+                    let rect = geom::Rect {
+                        x: geom::Range::new(x, x + w),
+                        y: geom::Range::new(baseline, baseline + h),
+                    };
+                    x += w;
+                    runs.push(Run {
+                        line,
+                        byte_range,
+                        start_char,
+                        font: style.font.clone(),
+                        font_size: style.font_size,
+                        color: style.color,
+                        underline: style.underline,
+                        strikethrough: style.strikethrough,
+                        direction,
+                        glyphs,
+                        rect,
+                    });
+                }
+            }
+        }
+        runs
+    }
+}
+
+impl Run {
+    /// The glyph produced from the given shaped glyph of the run alongside its bounding rect.
+    ///
+    /// The bottom left corner of the rect is the glyph's origin.
+    pub fn glyph_rect(&self, glyph: &shape::Glyph) -> (ScaledGlyph<'static>, geom::Rect) {
+        let scale = pt_to_scale(self.font_size);
+        let x = self.rect.left() + glyph.position.x;
+        let y = self.rect.bottom() + glyph.position.y;
+        let g = self.font.glyph(glyph.id).scaled(scale);
+        let (right, height) = g
+            .clone()
+            .positioned(rt::point(x, 0.0))
+            .pixel_bounding_box()
+            .map(|bb| (bb.max.x as Scalar, (bb.max.y - bb.min.y) as Scalar))
+            .unwrap_or_else(|| (x + g.h_metrics().advance_width as Scalar, 0.0));
+        let rect = geom::Rect {
+            x: geom::Range::new(x, right),
+            y: geom::Range::new(y, y + height),
+        };
+        (g, rect)
+    }
+
+    /// Produce positioned rusttype glyphs for the run ready for caching, alongside the shaped
+    /// glyphs from which they were produced.
+    ///
+    /// See `Text::rt_glyphs` for details on the window dimensions and scale factor.
+    pub fn rt_glyphs(
+        &self,
+        window_size: Vec2,
+        scale_factor: Scalar,
+    ) -> impl '_ + Iterator<Item = (&shape::Glyph, PositionedGlyph)> {
+        // Functions for converting nannou coordinates to rusttype pixel coordinates.
+        let trans_x = move |x: Scalar| (x + window_size.x / 2.0) * scale_factor as Scalar;
+        let trans_y = move |y: Scalar| ((-y) + window_size.y / 2.0) * scale_factor as Scalar;
+        let scale = f32_pt_to_scale(self.font_size as f32 * scale_factor);
+        let (left, baseline) = (self.rect.left(), self.rect.bottom());
+        self.glyphs.iter().map(move |g| {
+            let point = rt::Point {
+                x: trans_x(left + g.position.x) as f32,
+                y: trans_y(baseline + g.position.y) as f32,
+            };
+            let glyph = self.font.glyph(g.id).scaled(scale).positioned(point);
+            (g, glyph)
+        })
     }
 }
 
@@ -541,6 +852,21 @@ where
     }
 }
 
+impl<'a> Iterator for TextGlyphs<'a> {
+    type Item = (ScaledGlyph<'static>, geom::Rect);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((run, glyphs)) = self.run.as_mut() {
+                if let Some(glyph) = glyphs.next() {
+                    return Some(run.glyph_rect(glyph));
+                }
+            }
+            let run = self.runs.next()?;
+            self.run = Some((run, run.glyphs.iter()));
+        }
+    }
+}
+
 impl<'a> Iterator for TextLineRects<'a> {
     type Item = geom::Rect;
     fn next(&mut self) -> Option<Self::Item> {
@@ -604,10 +930,16 @@ pub fn position_offset(
     bounding_rect: geom::Rect,
     y_align: Align,
 ) -> Vec2 {
+    let total_text_height = height_by_lines(num_lines, font_size, line_spacing);
+    y_aligned_offset(total_text_height, bounding_rect, y_align)
+}
+
+// The position offset required to shift text of the given total height into the given bounding
+// rectangle.
+fn y_aligned_offset(total_text_height: Scalar, bounding_rect: geom::Rect, y_align: Align) -> Vec2 {
     let x_offset = bounding_rect.x.start;
     let y_offset = {
         // Calculate the `y` `Range` of the first line `Rect`.
-        let total_text_height = height_by_lines(num_lines, font_size, line_spacing);
         let total_text_y_range = geom::Range::new(0.0, total_text_height);
         let total_text_y = match y_align {
             Align::Start => total_text_y_range.align_start_of(bounding_rect.y),
@@ -619,6 +951,77 @@ pub fn position_offset(
     geom::vec2(x_offset, y_offset)
 }
 
+// Measure each character of the text by shaping each run of uniform direction and style.
+//
+// The metrics are indexed by the byte index of each character.
+fn measure_chars(
+    text: &str,
+    levels: &[unicode_bidi::Level],
+    spans: &[Span],
+    font: &Font,
+    fallbacks: &[Font],
+    font_size: FontSize,
+    shaper: &shape::Shaper,
+) -> Vec<(Scalar, Scalar)> {
+    let mut metrics = vec![(0.0, 0.0); text.len()];
+    for (range, direction) in shape::logical_runs(text, levels, 0..text.len()) {
+        for (style, range) in style_runs(text, range, spans, font, fallbacks, font_size) {
+            let run_text = &text[range.clone()];
+            let run_metrics = shaper.char_metrics(run_text, style.font, style.font_size, direction);
+            metrics[range].copy_from_slice(&run_metrics);
+        }
+    }
+    metrics
+}
+
+// Divide the given range of text into runs of characters sharing the same resolved style, in
+// logical order.
+//
+// Characters missing from the resolved font are assigned the first of the `fallbacks` that
+// contains them. Newline characters are excluded so that runs never span multiple lines.
+fn style_runs<'a>(
+    text: &str,
+    range: std::ops::Range<usize>,
+    spans: &'a [Span],
+    font: &'a Font,
+    fallbacks: &'a [Font],
+    font_size: FontSize,
+) -> Vec<(span::Resolved<'a>, std::ops::Range<usize>)> {
+    let mut runs = vec![];
+    let mut current: Option<(span::Resolved, usize)> = None;
+    for (i, ch) in text[range.clone()].char_indices() {
+        let byte = range.start + i;
+        if ch == '\r' || ch == '\n' {
+            if let Some((style, start)) = current.take() {
+                runs.push((style, start..byte));
+            }
+            continue;
+        }
+        let mut style = span::resolve(spans, byte, font, font_size);
+        if !fallbacks.is_empty() {
+            // Joiners and combining marks remain with the font of the preceding character.
+            let prev = current.as_ref().map(|(style, _)| style.font);
+            style.font = match prev {
+                Some(prev) if font::is_combining(ch) => prev,
+                _ => font::select(ch, style.font, fallbacks),
+            };
+        }
+        match current {
+            Some((ref current_style, _)) if current_style.same_style(&style) => (),
+            _ => {
+                if let Some((current_style, start)) = current.take() {
+                    runs.push((current_style, start..byte));
+                }
+                current = Some((style, byte));
+            }
+        }
+    }
+    if let Some((style, start)) = current {
+        runs.push((style, start..range.end));
+    }
+    runs
+}
+
 /// Produce the position of each glyph ready for the rusttype glyph cache.
 ///
 /// Window dimensions are expected in logical coordinates.
@@ -0,0 +1,222 @@
+//! Signed distance field glyph generation and atlas packing.
+//!
+//! Rather than coverage, a signed distance field (SDF) stores the distance from each texel to the
+//! nearest edge of a glyph's outline. As distance varies smoothly between texels, the outline may
+//! be reconstructed at any scale by thresholding the interpolated field. This allows for glyphs
+//! that remain crisp under large scales and 3D transforms from a single rasterization.
+//!
+//! Fields are generated once per glyph at a scale of `SIZE` pixels, independently of the size at
+//! which the glyph appears.
+
+use crate::geom;
+use crate::text::{font, rt, Font, GlyphId, Scalar, Scale};
+use std::collections::HashMap;
+
+/// The scale in pixels at which glyph distance fields are generated.
+pub const SIZE: f32 = 48.0;
+
+/// The greatest distance in pixels from the outline that may be represented by a field.
+///
+/// Fields are padded by this distance on each side. Distances beyond it are clamped.
+pub const SPREAD: f32 = 6.0;
+
+/// The distance field generated for a single glyph.
+#[derive(Clone, Debug)]
+pub struct Field {
+    /// The width of the field in texels.
+    pub width: u32,
+    /// The height of the field in texels.
+    pub height: u32,
+    /// One value per texel in rows from top to bottom.
+    ///
+    /// A value of 128 lies on the outline. Greater values lie inside the glyph.
+    pub data: Vec<u8>,
+    /// The bounds of the field relative to the glyph's origin in pixels at `SIZE`, with *y* up.
+    pub bounds: geom::Rect,
+}
+
+/// The location of a glyph's distance field within an **Atlas**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The normalised texture coordinates of the field within the atlas, with *y* down.
+    pub uv_rect: rt::Rect<f32>,
+    /// The bounds of the field relative to the glyph's origin in pixels at `SIZE`, with *y* up.
+    pub bounds: geom::Rect,
+}
+
+/// Packs glyph distance fields into rows within a single channel texture.
+#[derive(Clone, Debug)]
+pub struct Atlas {
+    width: u32,
+    height: u32,
+    // The top left corner of the next field and the height of the current row.
+    x: u32,
+    y: u32,
+    row_height: u32,
+    // Glyphs without an outline, e.g. spaces, map to `None`.
+    entries: HashMap<(font::Id, GlyphId), Option<Entry>>,
+}
+
+/// Returned when the **Atlas** has insufficient space remaining for a new glyph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AtlasFull;
+
+impl Atlas {
+    /// An empty atlas packing fields within a texture of the given dimensions.
+    pub fn new(width: u32, height: u32) -> Self {
+        Atlas {
+            width,
+            height,
+            x: 0,
+            y: 0,
+            row_height: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// The dimensions of the atlas texture.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Remove all glyphs, allowing the whole texture to be repacked.
+    pub fn clear(&mut self) {
+        self.x = 0;
+        self.y = 0;
+        self.row_height = 0;
+        self.entries.clear();
+    }
+
+    /// The entry for the given glyph if it has already been packed.
+    pub fn get(&self, font: &Font, id: GlyphId) -> Option<Option<Entry>> {
+        self.entries.get(&(font::id(font), id)).cloned()
+    }
+
+    /// The entry for the given glyph, generating and packing its field if necessary.
+    ///
+    /// The `upload` function is called with the texel rect to which a newly packed field must be
+    /// written, along with the field's data. Returns `Ok(None)` for glyphs without an outline.
+    pub fn get_or_insert<F>(
+        &mut self,
+        font: &Font,
+        id: GlyphId,
+        upload: F,
+    ) -> Result<Option<Entry>, AtlasFull>
+    where
+        F: FnOnce(rt::Rect<u32>, &[u8]),
+    {
+        let key = (font::id(font), id);
+        if let Some(entry) = self.entries.get(&key) {
+            return Ok(*entry);
+        }
+        let field = match generate(font, id) {
+            None => {
+                self.entries.insert(key, None);
+                return Ok(None);
+            }
+            Some(field) => field,
+        };
+
+        // Leave a texel between fields so that they do not bleed into one another when filtered.
+        let (w, h) = (field.width + 1, field.height + 1);
+        if self.x + w > self.width {
+            self.x = 0;
+            self.y += self.row_height;
+            self.row_height = 0;
+        }
+        if self.x + w > self.width || self.y + h > self.height {
+            return Err(AtlasFull);
+        }
+        let rect = rt::Rect {
+            min: rt::point(self.x, self.y),
+            max: rt::point(self.x + field.width, self.y + field.height),
+        };
+        upload(rect, &field.data);
+        self.x += w;
+        self.row_height = self.row_height.max(h);
+
+        let (atlas_w, atlas_h) = (self.width as f32, self.height as f32);
+        let uv_rect = rt::Rect {
+            min: rt::point(rect.min.x as f32 / atlas_w, rect.min.y as f32 / atlas_h),
+            max: rt::point(rect.max.x as f32 / atlas_w, rect.max.y as f32 / atlas_h),
+        };
+        let entry = Entry {
+            uv_rect,
+            bounds: field.bounds,
+        };
+        self.entries.insert(key, Some(entry));
+        Ok(Some(entry))
+    }
+}
+
+/// Generate the distance field for the given glyph.
+///
+/// Returns `None` if the glyph has no outline.
+pub fn generate(font: &Font, id: GlyphId) -> Option<Field> {
+    let glyph = font
+        .glyph(id)
+        .scaled(Scale::uniform(SIZE))
+        .positioned(rt::point(0.0, 0.0));
+    let bb = glyph.pixel_bounding_box()?;
+    let pad = SPREAD.ceil() as i32;
+    let (w, h) = (bb.width() + pad * 2, bb.height() + pad * 2);
+
+    // Rasterize the coverage of the glyph within the padded field.
+    let mut coverage = vec![0.0; (w * h) as usize];
+    glyph.draw(|x, y, v| {
+        let ix = (y as i32 + pad) * w + x as i32 + pad;
+        coverage[ix as usize] = v;
+    });
+    let inside = |x: i32, y: i32| coverage[(y * w + x) as usize] >= 0.5;
+
+    // Find the distance from each texel to the nearest texel on the other side of the outline.
+    //
+    // Texels adjacent to the outline use their coverage to estimate the sub-texel distance.
+    let max_dist_sq = ((pad + 1) * (pad + 1)) as Scalar;
+    let mut data = Vec::with_capacity(coverage.len());
+    for y in 0..h {
+        for x in 0..w {
+            let is_inside = inside(x, y);
+            let mut dist_sq = max_dist_sq;
+            for ny in (y - pad).max(0)..(y + pad + 1).min(h) {
+                for nx in (x - pad).max(0)..(x + pad + 1).min(w) {
+                    if inside(nx, ny) != is_inside {
+                        let (dx, dy) = ((nx - x) as Scalar, (ny - y) as Scalar);
+                        dist_sq = dist_sq.min(dx * dx + dy * dy);
+                    }
+                }
+            }
+            let dist = match dist_sq <= 1.0 {
+                true => coverage[(y * w + x) as usize] - 0.5,
+                false => match is_inside {
+                    true => dist_sq.sqrt() - 0.5,
+                    false => 0.5 - dist_sq.sqrt(),
+                },
+            };
+            let value = 0.5 + dist / (SPREAD * 2.0);
+            data.push((value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+    }
+
+    let bounds = geom::Rect::from_corners(
+        [(bb.min.x - pad) as Scalar, -(bb.max.y + pad) as Scalar].into(),
+        [(bb.max.x + pad) as Scalar, -(bb.min.y - pad) as Scalar].into(),
+    );
+    Some(Field {
+        width: w as u32,
+        height: h as u32,
+        data,
+        bounds,
+    })
+}
+
+impl std::error::Error for AtlasFull {}
+
+impl std::fmt::Display for AtlasFull {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "insufficient space remaining within the glyph distance field atlas"
+        )
+    }
+}
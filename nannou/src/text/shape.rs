@@ -0,0 +1,337 @@
+//! Complex text shaping and bidirectional reordering.
+//!
+//! Shaping maps a sequence of characters to positioned glyphs using the OpenType layout tables of
+//! a font, applying ligatures, contextual forms, mark positioning and kerning. This is required
+//! for the correct display of scripts such as Arabic and Devanagari. Shaping is performed by
+//! `rustybuzz` for all fonts whose data has been registered via `font::register_data`.
+//!
+//! Text containing both left-to-right and right-to-left scripts is reordered for display as
+//! described by the Unicode Bidirectional Algorithm (UAX #9).
+
+use crate::glam::Vec2;
+use crate::text::{self, font, Font, FontSize, GlyphId, Scalar};
+use std::ops::Range;
+use unicode_bidi::BidiInfo;
+
+/// The direction in which a run of text is laid out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A single glyph produced by shaping a run of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glyph {
+    /// The ID of the glyph within the font used to shape the run.
+    pub id: GlyphId,
+    /// The byte index of the first character of the cluster from which the glyph was produced.
+    pub byte: usize,
+    /// The char index of the first character of the cluster from which the glyph was produced.
+    pub char: usize,
+    /// The position of the glyph's origin relative to the origin of its run.
+    ///
+    /// The origin of a run is the left end of its baseline. Positive *y* is up.
+    pub position: Vec2,
+    /// The distance by which the following glyph is advanced along the *x* axis.
+    pub advance: Scalar,
+}
+
+/// Shape the given text using a single font, font size and direction.
+///
+/// Glyphs are produced in visual order, i.e. from left to right regardless of direction. The
+/// `byte` and `char` of each glyph are relative to the start of `text`.
+///
+/// If no data has been registered for the font, glyphs are laid out one per character using the
+/// font's kerning table.
+pub fn shape(text: &str, font: &Font, font_size: FontSize, direction: Direction) -> Vec<Glyph> {
+    let data = font::data(font);
+    let face = data
+        .as_ref()
+        .and_then(|(data, index)| rustybuzz::Face::from_slice(data, *index));
+    shape_with(text, face.as_ref(), font, font_size, direction)
+}
+
+/// The advance width and height of each character within the given text once shaped.
+///
+/// The metrics are indexed by the byte index of each character's first byte. The advance of each
+/// glyph is attributed to the first character of its cluster, so that the remaining characters of
+/// a ligature have no width. The height is the distance from the baseline to the top of the
+/// tallest glyph of the cluster.
+pub fn char_metrics(
+    text: &str,
+    font: &Font,
+    font_size: FontSize,
+    direction: Direction,
+) -> Vec<(Scalar, Scalar)> {
+    let glyphs = shape(text, font, font_size, direction);
+    metrics(text, &glyphs, font, font_size)
+}
+
+/// The registered data of a set of fonts, from which a `Shaper` may be produced.
+pub(crate) struct FontData<'a> {
+    fonts: Vec<(&'a Font, Option<(font::Data, u32)>)>,
+}
+
+/// Shapes text using the OpenType faces of a set of fonts, parsed once up front.
+pub(crate) struct Shaper<'a> {
+    faces: Vec<(&'a Font, Option<rustybuzz::Face<'a>>)>,
+}
+
+impl<'a> FontData<'a> {
+    /// Look up the registered data of each distinct font.
+    pub fn new<I>(fonts: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Font>,
+    {
+        let mut distinct: Vec<&Font> = vec![];
+        for font in fonts {
+            if !distinct.iter().any(|&f| std::ptr::eq(f, font)) {
+                distinct.push(font);
+            }
+        }
+        let fonts = distinct
+            .into_iter()
+            .map(|font| (font, font::data(font)))
+            .collect();
+        FontData { fonts }
+    }
+}
+
+impl<'a> Shaper<'a> {
+    /// Parse the face of each font with registered data.
+    pub fn new(data: &'a FontData<'a>) -> Self {
+        let faces = data
+            .fonts
+            .iter()
+            .map(|(font, data)| {
+                let face = data
+                    .as_ref()
+                    .and_then(|(data, index)| rustybuzz::Face::from_slice(data, *index));
+                (*font, face)
+            })
+            .collect();
+        Shaper { faces }
+    }
+
+    /// Shape the given text as described by the `shape` function.
+    ///
+    /// Fonts with which the shaper was not constructed are shaped via the `shape` function.
+    pub fn shape(
+        &self,
+        text: &str,
+        font: &Font,
+        font_size: FontSize,
+        direction: Direction,
+    ) -> Vec<Glyph> {
+        match self.faces.iter().find(|(f, _)| std::ptr::eq(*f, font)) {
+            Some((_, face)) => shape_with(text, face.as_ref(), font, font_size, direction),
+            None => shape(text, font, font_size, direction),
+        }
+    }
+
+    /// The metrics of each character as described by the `char_metrics` function.
+    pub fn char_metrics(
+        &self,
+        text: &str,
+        font: &Font,
+        font_size: FontSize,
+        direction: Direction,
+    ) -> Vec<(Scalar, Scalar)> {
+        let glyphs = self.shape(text, font, font_size, direction);
+        metrics(text, &glyphs, font, font_size)
+    }
+}
+
+// The advance width and height of each character, given the glyphs produced by shaping the text.
+fn metrics(
+    text: &str,
+    glyphs: &[Glyph],
+    font: &Font,
+    font_size: FontSize,
+) -> Vec<(Scalar, Scalar)> {
+    let mut metrics = vec![(0.0 as Scalar, 0.0 as Scalar); text.len()];
+    let scale = text::pt_to_scale(font_size);
+    for glyph in glyphs {
+        let height = font
+            .glyph(glyph.id)
+            .scaled(scale)
+            .exact_bounding_box()
+            .map(|bb| bb.min.y.abs() as Scalar)
+            .unwrap_or(0.0);
+        let (ref mut w, ref mut h) = metrics[glyph.byte];
+        *w += glyph.advance;
+        *h = h.max(height);
+    }
+    metrics
+}
+
+/// Divide the given line of text into runs of uniform direction, in visual order from left to
+/// right.
+///
+/// `text` is the full text containing the line and `line` is the byte range of the line within
+/// it.
+pub fn visual_runs(text: &str, line: Range<usize>) -> Vec<(Range<usize>, Direction)> {
+    let bidi = BidiInfo::new(text, None);
+    visual_runs_with(&bidi, line)
+}
+
+// Divide the given line into runs of uniform direction in visual order using the given
+// pre-calculated bidi information.
+pub(crate) fn visual_runs_with(
+    bidi: &BidiInfo,
+    line: Range<usize>,
+) -> Vec<(Range<usize>, Direction)> {
+    if line.start >= line.end {
+        return vec![];
+    }
+    let para = bidi
+        .paragraphs
+        .iter()
+        .find(|para| para.range.start <= line.start && line.start < para.range.end);
+    let para = match para {
+        None => return vec![(line, Direction::LeftToRight)],
+        Some(para) => para,
+    };
+    let (levels, runs) = bidi.visual_runs(para, line);
+    runs.into_iter()
+        .map(|run| {
+            let direction = direction(levels[run.start].is_rtl());
+            (run, direction)
+        })
+        .collect()
+}
+
+// Divide the given range of text into runs of uniform direction in logical order.
+pub(crate) fn logical_runs(
+    text: &str,
+    levels: &[unicode_bidi::Level],
+    range: Range<usize>,
+) -> Vec<(Range<usize>, Direction)> {
+    let mut runs = vec![];
+    let mut start = range.start;
+    let mut current = None;
+    for (i, _) in text[range.clone()].char_indices() {
+        let byte = range.start + i;
+        let dir = direction(levels[byte].is_rtl());
+        match current {
+            Some(current_dir) if current_dir == dir => (),
+            Some(current_dir) => {
+                runs.push((start..byte, current_dir));
+                start = byte;
+                current = Some(dir);
+            }
+            None => current = Some(dir),
+        }
+    }
+    if let Some(dir) = current {
+        runs.push((start..range.end, dir));
+    }
+    runs
+}
+
+fn direction(is_rtl: bool) -> Direction {
+    match is_rtl {
+        true => Direction::RightToLeft,
+        false => Direction::LeftToRight,
+    }
+}
+
+// Shape the text using the given face if there is one, otherwise one glyph per character.
+fn shape_with(
+    text: &str,
+    face: Option<&rustybuzz::Face>,
+    font: &Font,
+    font_size: FontSize,
+    direction: Direction,
+) -> Vec<Glyph> {
+    match face {
+        Some(face) => shape_opentype(text, face, font, font_size, direction),
+        None => shape_simple(text, font, font_size, direction),
+    }
+}
+
+// Shape the text via `rustybuzz` using the font's OpenType layout tables.
+fn shape_opentype(
+    text: &str,
+    face: &rustybuzz::Face,
+    font: &Font,
+    font_size: FontSize,
+    direction: Direction,
+) -> Vec<Glyph> {
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.guess_segment_properties();
+    buffer.set_direction(match direction {
+        Direction::LeftToRight => rustybuzz::Direction::LeftToRight,
+        Direction::RightToLeft => rustybuzz::Direction::RightToLeft,
+    });
+    let output = rustybuzz::shape(face, &[], buffer);
+
+    // Font units are scaled in the same manner as `rusttype`, by the distance between the
+    // ascent and descent.
+    let v_metrics = font.v_metrics_unscaled();
+    let units = text::pt_to_px(font_size) / (v_metrics.ascent - v_metrics.descent);
+
+    // The char index of each byte index.
+    let mut chars = vec![0; text.len() + 1];
+    for (char, (byte, _)) in text.char_indices().enumerate() {
+        chars[byte] = char;
+    }
+
+    let mut x = 0.0;
+    output
+        .glyph_infos()
+        .iter()
+        .zip(output.glyph_positions())
+        .map(|(info, pos)| {
+            let byte = info.cluster as usize;
+            let position = Vec2::new(
+                x + pos.x_offset as Scalar * units,
+                pos.y_offset as Scalar * units,
+            );
+            let advance = pos.x_advance as Scalar * units;
+            x += advance;
+            Glyph {
+                id: GlyphId(info.glyph_id),
+                byte,
+                char: chars[byte],
+                position,
+                advance,
+            }
+        })
+        .collect()
+}
+
+// Lay out one glyph per character using the font's kerning table.
+fn shape_simple(text: &str, font: &Font, font_size: FontSize, direction: Direction) -> Vec<Glyph> {
+    let scale = text::pt_to_scale(font_size);
+    let mut chars: Vec<_> = text.char_indices().enumerate().collect();
+    if let Direction::RightToLeft = direction {
+        chars.reverse();
+    }
+    let mut last = None;
+    let mut x = 0.0;
+    chars
+        .into_iter()
+        .map(|(char, (byte, ch))| {
+            let g = font.glyph(ch).scaled(scale);
+            let id = g.id();
+            let kern = last
+                .map(|last| font.pair_kerning(scale, last, id))
+                .unwrap_or(0.0);
+            last = Some(id);
+            // Kerning is included in the advance so that the advances sum to the total width.
+            let advance = kern + g.h_metrics().advance_width;
+            let position = Vec2::new(x + kern, 0.0);
+            x += advance;
+            Glyph {
+                id,
+                byte,
+                char,
+                position,
+                advance,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,163 @@
+//! Styled spans allowing for mixed fonts, sizes, colors and decorations within a single text
+//! layout.
+//!
+//! A **Span** applies a **Style** to a byte range of some text. Unstyled properties fall back to
+//! those of the text's **Layout**. Where spans overlap, the properties of later spans take
+//! precedence over those of earlier spans.
+
+use crate::color::{IntoLinSrgba, LinSrgba};
+use crate::text::{font, Font, FontSize};
+use std::ops::Range;
+
+/// A **Style** applied to a byte range of some text.
+#[derive(Clone, Debug)]
+pub struct Span {
+    /// The range of bytes within the text to which the style is applied.
+    pub range: Range<usize>,
+    /// The style applied to the range.
+    pub style: Style,
+}
+
+/// Styling properties for a span of text.
+///
+/// Properties that are `None` fall back to those of the text's layout.
+#[derive(Clone, Debug, Default)]
+pub struct Style {
+    /// The font used for the span, e.g. a bold or italic variant of the layout's font.
+    pub font: Option<Font>,
+    /// The font size used for the span.
+    pub font_size: Option<FontSize>,
+    /// The color used for the span.
+    pub color: Option<LinSrgba>,
+    /// Whether or not to draw a line beneath the span.
+    pub underline: bool,
+    /// Whether or not to draw a line through the span.
+    pub strikethrough: bool,
+}
+
+/// The properties of a single character after resolving all spans that contain it.
+#[derive(Clone, Debug)]
+pub struct Resolved<'a> {
+    pub font: &'a Font,
+    pub font_size: FontSize,
+    pub color: Option<LinSrgba>,
+    pub underline: bool,
+    pub strikethrough: bool,
+}
+
+impl Span {
+    /// A span applying the given style to the given range of bytes.
+    pub fn new(range: Range<usize>, style: Style) -> Self {
+        Span { range, style }
+    }
+}
+
+impl Style {
+    /// A style that leaves all properties to the text's layout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the font used for the span.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Specify the font size used for the span.
+    pub fn font_size(mut self, size: FontSize) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    /// Specify the color used for the span.
+    pub fn color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.color = Some(color.into_lin_srgba());
+        self
+    }
+
+    /// Draw a line beneath the span.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Draw a line through the span.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+}
+
+impl<'a> Resolved<'a> {
+    /// Whether or not the character is laid out with the same font and font size as the other.
+    pub fn same_metrics(&self, other: &Self) -> bool {
+        self.font_size == other.font_size
+            && (std::ptr::eq(self.font, other.font) || font::id(self.font) == font::id(other.font))
+    }
+
+    /// Whether or not all properties of the character's style match those of the other.
+    ///
+    /// Text is divided into **Run**s wherever this is not the case.
+    pub fn same_style(&self, other: &Self) -> bool {
+        self.same_metrics(other)
+            && self.color == other.color
+            && self.underline == other.underline
+            && self.strikethrough == other.strikethrough
+    }
+}
+
+/// Resolve the style of the character at the given byte index.
+///
+/// The given `font` and `font_size` are used where no span specifies otherwise.
+pub fn resolve<'a>(
+    spans: &'a [Span],
+    byte: usize,
+    font: &'a Font,
+    font_size: FontSize,
+) -> Resolved<'a> {
+    let mut resolved = Resolved {
+        font,
+        font_size,
+        color: None,
+        underline: false,
+        strikethrough: false,
+    };
+    for span in spans.iter().filter(|span| span.range.contains(&byte)) {
+        let style = &span.style;
+        if let Some(ref font) = style.font {
+            resolved.font = font;
+        }
+        if let Some(size) = style.font_size {
+            resolved.font_size = size;
+        }
+        if let Some(color) = style.color {
+            resolved.color = Some(color);
+        }
+        resolved.underline |= style.underline;
+        resolved.strikethrough |= style.strikethrough;
+    }
+    resolved
+}
+
+/// Concatenate the given styled strings into a single string along with the spans describing
+/// their styles.
+///
+/// This is useful for building rich text without calculating byte ranges by hand.
+pub fn concat<I, S>(parts: I) -> (String, Vec<Span>)
+where
+    I: IntoIterator<Item = (S, Style)>,
+    S: AsRef<str>,
+{
+    let mut text = String::new();
+    let mut spans = vec![];
+    for (s, style) in parts {
+        let start = text.len();
+        text.push_str(s.as_ref());
+        spans.push(Span::new(start..text.len(), style));
+    }
+    (text, spans)
+}
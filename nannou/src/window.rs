@@ -4,16 +4,20 @@
 //! which can be used to build a [**Window**](./struct.Window.html).
 
 use crate::color::IntoLinSrgba;
+use crate::draw::canvas::{Canvas, Fit};
 use crate::event::{
-    Key, MouseButton, MouseScrollDelta, TouchEvent, TouchPhase, TouchpadPressure, WindowEvent,
+    GamepadEvent, Key, MouseButton, MouseScrollDelta, TouchEvent, TouchPhase, TouchpadPressure,
+    WindowEvent,
 };
 use crate::frame::{self, Frame, RawFrame};
 use crate::geom;
 use crate::geom::Point2;
+use crate::gesture::Gesture;
 use crate::glam::Vec2;
 use crate::wgpu;
 use crate::App;
 use std::any::Any;
+use std::cell::Cell;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -49,6 +53,9 @@ pub struct Builder<'app> {
     max_capture_frame_jobs: u32,
     capture_frame_timeout: Option<Duration>,
     clear_color: Option<wgpu::Color>,
+    post_process: frame::PostProcess,
+    canvas_size: Option<Vec2>,
+    canvas_fit: Fit,
 }
 
 /// For storing all user functions within the window.
@@ -76,6 +83,8 @@ pub(crate) struct UserFunctions {
     pub(crate) focused: Option<FocusedFnAny>,
     pub(crate) unfocused: Option<UnfocusedFnAny>,
     pub(crate) closed: Option<ClosedFnAny>,
+    pub(crate) gamepad: Option<GamepadFnAny>,
+    pub(crate) gesture: Option<GestureFnAny>,
 }
 
 /// The user function type for drawing their model to the surface of a single window.
@@ -163,11 +172,19 @@ pub type UnfocusedFn<Model> = fn(&App, &mut Model);
 /// A function for processing window closed events.
 pub type ClosedFn<Model> = fn(&App, &mut Model);
 
+/// A function for processing gamepad events while the window is in focus.
+pub type GamepadFn<Model> = fn(&App, &mut Model, GamepadEvent);
+
+/// A function for processing gestures recognised from the window's touch events.
+pub type GestureFn<Model> = fn(&App, &mut Model, Gesture);
+
 /// Errors that might occur while building the window.
 #[derive(Debug)]
 pub enum BuildError {
     NoAvailableAdapter,
     WinitOsError(winit::error::OsError),
+    /// Windows cannot be built for a headless **App**.
+    Headless,
 }
 
 // A macro for generating a handle to a function that can be stored within the Window without
@@ -230,6 +247,8 @@ fn_any!(DroppedFileFn<M>, DroppedFileFnAny);
 fn_any!(FocusedFn<M>, FocusedFnAny);
 fn_any!(UnfocusedFn<M>, UnfocusedFnAny);
 fn_any!(ClosedFn<M>, ClosedFnAny);
+fn_any!(GamepadFn<M>, GamepadFnAny);
+fn_any!(GestureFn<M>, GestureFnAny);
 
 /// A nannou window.
 ///
@@ -248,6 +267,7 @@ pub struct Window {
     pub(crate) tracked_state: TrackedState,
     pub(crate) is_invalidated: bool, // Whether framebuffer must be cleared
     pub(crate) clear_color: wgpu::Color,
+    canvas: Cell<Option<Canvas>>,
 }
 
 // Data related to `Frame`s produced for this window's surface textures.
@@ -364,6 +384,9 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs: Default::default(),
             capture_frame_timeout: Default::default(),
             clear_color: None,
+            post_process: Default::default(),
+            canvas_size: None,
+            canvas_fit: Default::default(),
         }
     }
 
@@ -431,6 +454,26 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// Specify a fixed logical canvas size for the window.
+    ///
+    /// Drawings made to the window via `Draw::to_frame`, the window's `canvas_rect` and the
+    /// positions of mouse and touch events are all described in canvas coordinates, centred on the
+    /// origin, regardless of the actual size of the window. The canvas is fitted to the window according
+    /// to the `canvas_fit` policy.
+    pub fn canvas_size(mut self, w: f32, h: f32) -> Self {
+        self.canvas_size = Some(Vec2::new(w, h));
+        self
+    }
+
+    /// Specify how the canvas is fitted to the window when their aspect ratios differ.
+    ///
+    /// By default, this is `Fit::Letterbox`. This has no effect unless a `canvas_size` is
+    /// specified.
+    pub fn canvas_fit(mut self, fit: Fit) -> Self {
+        self.canvas_fit = fit;
+        self
+    }
+
     /// Provide a simple function for drawing to the window.
     ///
     /// This is similar to `view` but does not provide access to user data via a Model type. This
@@ -478,6 +521,16 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// Specify the stack of post-processing effects applied to each `Frame` before it is
+    /// presented.
+    ///
+    /// **Note:** This parameter has no effect if the window uses a **raw_view** function, as the
+    /// **RawFrame** provides direct access to the surface texture.
+    pub fn post_process(mut self, post_process: frame::PostProcess) -> Self {
+        self.post_process = post_process;
+        self
+    }
+
     /// A function for updating your model on `WindowEvent`s associated with this window.
     ///
     /// These include events such as key presses, mouse movement, clicks, resizing, etc.
@@ -618,6 +671,18 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// A function for processing gestures recognised from the touch events of this window.
+    ///
+    /// Taps, double taps, long presses, pans, pinches, rotations and swipes are recognised using
+    /// the default `gesture::Config` thresholds.
+    pub fn gesture<M>(mut self, f: GestureFn<M>) -> Self
+    where
+        M: 'static,
+    {
+        self.user_functions.gesture = Some(GestureFnAny::from_fn_ptr(f));
+        self
+    }
+
     /// A function for processing window moved events associated with this window.
     pub fn moved<M>(mut self, f: MovedFn<M>) -> Self
     where
@@ -691,6 +756,17 @@ impl<'app> Builder<'app> {
         self
     }
 
+    /// A function for processing gamepad events while this window is in focus.
+    ///
+    /// Gamepad events are only emitted when nannou is built with the `gamepad` feature.
+    pub fn gamepad<M>(mut self, f: GamepadFn<M>) -> Self
+    where
+        M: 'static,
+    {
+        self.user_functions.gamepad = Some(GamepadFnAny::from_fn_ptr(f));
+        self
+    }
+
     /// The maximum number of simultaneous capture frame jobs that can be run for this window
     /// before we block and wait for the existing jobs to complete.
     ///
@@ -746,8 +822,16 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
+            canvas_size,
+            canvas_fit,
         } = self;
 
+        // There is no event loop with which windows may be built for headless apps.
+        if app.headless().is_some() {
+            return Err(BuildError::Headless);
+        }
+
         // If the title was not set, default to the "nannou - <exe_name>".
         if !title_was_set {
             if let Ok(exe_path) = env::current_exe() {
@@ -852,6 +936,11 @@ impl<'app> Builder<'app> {
             color
         });
 
+        let canvas = canvas_size.map(|size| Canvas {
+            size,
+            fit: canvas_fit,
+        });
+
         // Build the window.
         let window = {
             let window_target = app
@@ -915,6 +1004,7 @@ impl<'app> Builder<'app> {
                     surface_dims,
                     surface_conf.format,
                     msaa_samples,
+                    post_process,
                 );
                 let capture =
                     frame::CaptureData::new(max_capture_frame_jobs, capture_frame_timeout);
@@ -944,8 +1034,10 @@ impl<'app> Builder<'app> {
             tracked_state,
             is_invalidated,
             clear_color,
+            canvas: Cell::new(canvas),
         };
         app.windows.borrow_mut().insert(window_id, window);
+        app.window_creation_order.borrow_mut().push(window_id);
 
         // If this is the first window, set it as the app's "focused" window.
         if app.windows.borrow().len() == 1 {
@@ -972,6 +1064,9 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
+            canvas_size,
+            canvas_fit,
         } = self;
         let window = map(window);
         Builder {
@@ -987,6 +1082,9 @@ impl<'app> Builder<'app> {
             max_capture_frame_jobs,
             capture_frame_timeout,
             clear_color,
+            post_process,
+            canvas_size,
+            canvas_fit,
         }
     }
 
@@ -1444,6 +1542,41 @@ impl Window {
         self.msaa_samples
     }
 
+    /// The stack of post-processing effects applied to each `Frame` before it is presented.
+    ///
+    /// **Note:** If the user specified a `raw_view` function rather than a `view` function, this
+    /// will always be empty.
+    pub fn post_process(&self) -> frame::PostProcess {
+        match self.frame_data {
+            None => Default::default(),
+            Some(ref data) => data
+                .render
+                .post_processor
+                .lock()
+                .expect("failed to lock `post_processor`")
+                .post_process()
+                .clone(),
+        }
+    }
+
+    /// Replace the stack of post-processing effects applied to each `Frame` before it is
+    /// presented.
+    ///
+    /// The GPU resources used by the effects are only recreated when effects are added, removed or
+    /// reordered, so it is cheap to update the parameters of the stack every frame.
+    ///
+    /// **Note:** This has no effect if the window uses a **raw_view** function, as the
+    /// **RawFrame** provides direct access to the surface texture.
+    pub fn set_post_process(&self, post_process: frame::PostProcess) {
+        if let Some(ref data) = self.frame_data {
+            data.render
+                .post_processor
+                .lock()
+                .expect("failed to lock `post_processor`")
+                .set_post_process(post_process);
+        }
+    }
+
     // Custom methods.
 
     // A utility function to simplify the reconfiguration of the window's wgpu surface.
@@ -1463,6 +1596,7 @@ impl Window {
                 self.tracked_state.physical_size.into(),
                 self.surface_conf.format,
                 self.msaa_samples,
+                self.post_process(),
             );
             self.frame_data.as_mut().unwrap().render = render_data;
         }
@@ -1494,11 +1628,56 @@ impl Window {
         geom::Rect::from_w_h(w, h)
     }
 
+    /// The rectangle representing the dimensions of the window's fixed logical `canvas`.
+    ///
+    /// If the window has no canvas, this is equal to `rect`.
+    pub fn canvas_rect(&self) -> geom::Rect {
+        match self.canvas.get() {
+            Some(canvas) => canvas.rect(),
+            None => self.rect(),
+        }
+    }
+
+    /// The fixed logical canvas fitted to the window, if one was specified.
+    pub fn canvas(&self) -> Option<Canvas> {
+        self.canvas.get()
+    }
+
+    /// Specify the fixed logical canvas fitted to the window.
+    ///
+    /// If `None`, the window is drawn to in window coordinates.
+    pub fn set_canvas(&self, canvas: Option<Canvas>) {
+        self.canvas.set(canvas);
+    }
+
+    /// Map a point from window coordinates to canvas coordinates.
+    ///
+    /// If the window has no canvas, the point is returned unchanged.
+    pub fn to_canvas(&self, point: Point2) -> Point2 {
+        match self.canvas.get() {
+            None => point,
+            Some(canvas) => canvas.to_canvas(self.size_points(), point),
+        }
+    }
+
+    // The tracked inner size of the window in points.
+    fn size_points(&self) -> Vec2 {
+        let sf = self.tracked_state.scale_factor;
+        let (w, h) = self
+            .tracked_state
+            .physical_size
+            .to_logical::<f32>(sf)
+            .into();
+        Vec2::new(w, h)
+    }
+
     /// Capture the next frame right before it is drawn to this window and write it to an image
     /// file at the given path. If a frame already exists, it will be captured before its `submit`
     /// method is called or before it is `drop`ped.
     ///
     /// The destination image file type will be inferred from the extension given in the path.
+    ///
+    /// The captured image includes the effects of the window's `post_process` stack.
     pub fn capture_frame<P>(&self, path: P)
     where
         P: AsRef<Path>,
@@ -1514,21 +1693,11 @@ impl Window {
     }
 
     fn capture_frame_inner(&self, path: &Path) {
-        // If the parent directory does not exist, create it.
-        let dir = path.parent().expect("capture_frame path has no directory");
-        if !dir.exists() {
-            std::fs::create_dir_all(&dir).expect("failed to create `capture_frame` directory");
-        }
-
-        let mut capture_next_frame_path = self
-            .frame_data
+        self.frame_data
             .as_ref()
             .expect("window capture requires that `view` draws to a `Frame` (not a `RawFrame`)")
             .capture
-            .next_frame_path
-            .lock()
-            .expect("failed to lock `capture_next_frame_path`");
-        *capture_next_frame_path = Some(path.to_path_buf());
+            .capture_next_frame(path);
     }
 
     /// Block and wait for all active capture frame jobs to complete.
@@ -1578,6 +1747,7 @@ impl fmt::Display for BuildError {
         match *self {
             BuildError::NoAvailableAdapter => write!(f, "no available wgpu adapter detected"),
             BuildError::WinitOsError(ref e) => e.fmt(f),
+            BuildError::Headless => write!(f, "cannot build a window for a headless app"),
         }
     }
 }
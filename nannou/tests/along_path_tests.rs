@@ -0,0 +1,69 @@
+#![cfg(feature = "notosans")]
+
+use nannou::geom::{self, pt2, Rect};
+use nannou::text;
+
+fn line(to: geom::Point2) -> geom::Path {
+    geom::path().begin(pt2(0.0, 0.0)).line_to(to).end().build()
+}
+
+#[test]
+fn glyphs_follow_straight_path() {
+    let text = text::text("abc")
+        .font_size(24)
+        .no_line_wrap()
+        .left_justify()
+        .build(Rect::from_w_h(400.0, 100.0));
+    let path = line(pt2(1000.0, 0.0));
+    let placed = text.glyphs_along_path(&path, 10.0);
+    assert_eq!(placed.len(), 3);
+    assert!((placed[0].origin.x - 10.0).abs() < 0.1);
+    for p in &placed {
+        assert!(p.origin.y.abs() < 0.1);
+        assert!(p.rotation.abs() < 1e-3);
+    }
+    assert!(placed[0].origin.x < placed[1].origin.x);
+    assert!(placed[1].origin.x < placed[2].origin.x);
+}
+
+#[test]
+fn glyphs_rotate_with_tangent() {
+    let text = text::text("ab")
+        .no_line_wrap()
+        .build(Rect::from_w_h(400.0, 100.0));
+    let path = line(pt2(0.0, 500.0));
+    let placed = text.glyphs_along_path(&path, 0.0);
+    assert_eq!(placed.len(), 2);
+    for p in &placed {
+        assert!((p.rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+        assert!(p.origin.x.abs() < 0.1);
+    }
+}
+
+#[test]
+fn glyphs_beyond_path_are_omitted() {
+    let text = text::text("abcdef")
+        .font_size(24)
+        .no_line_wrap()
+        .left_justify()
+        .build(Rect::from_w_h(400.0, 100.0));
+    let path = line(pt2(30.0, 0.0));
+    let placed = text.glyphs_along_path(&path, 0.0);
+    assert!(!placed.is_empty());
+    assert!(placed.len() < 6);
+    assert!(text.glyphs_along_path(&path, -1000.0).is_empty());
+}
+
+#[test]
+fn right_justified_glyphs_end_at_path_end() {
+    let text = text::text("abc")
+        .font_size(24)
+        .no_line_wrap()
+        .right_justify()
+        .build(Rect::from_w_h(400.0, 100.0));
+    let path = line(pt2(1000.0, 0.0));
+    let placed = text.glyphs_along_path(&path, 0.0);
+    assert_eq!(placed.len(), 3);
+    let last = placed.last().unwrap();
+    assert!((last.origin.x + last.glyph.advance - 1000.0).abs() < 0.5);
+}
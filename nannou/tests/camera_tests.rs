@@ -0,0 +1,23 @@
+use nannou::draw::Camera;
+use nannou::prelude::*;
+
+#[test]
+fn default_perspective_maps_z_plane_to_logical_pixels() {
+    let size = vec2(800.0, 600.0);
+    let view_proj = Camera::default_perspective().view_projection(size);
+    let ndc = view_proj.project_point3(vec3(200.0, -150.0, 0.0));
+    assert!((ndc.x - 0.5).abs() < 1e-4);
+    assert!((ndc.y + 0.5).abs() < 1e-4);
+    assert!(ndc.z > 0.0 && ndc.z < 1.0);
+}
+
+#[test]
+fn look_at_centres_target() {
+    let camera =
+        Camera::default_perspective().look_at(vec3(300.0, 200.0, 500.0), Vec3::ZERO, Vec3::Y);
+    let ndc = camera
+        .view_projection(vec2(800.0, 600.0))
+        .project_point3(Vec3::ZERO);
+    assert!(ndc.x.abs() < 1e-4);
+    assert!(ndc.y.abs() < 1e-4);
+}
@@ -0,0 +1,38 @@
+use nannou::draw::canvas::{Canvas, Fit};
+use nannou::prelude::*;
+
+#[test]
+fn letterbox_scales_uniformly_to_fit() {
+    let canvas = Canvas::new(1920.0, 1080.0);
+    let output = vec2(960.0, 960.0);
+    assert_eq!(canvas.scale(output), vec2(0.5, 0.5));
+    assert_eq!(canvas.viewport(output).wh(), vec2(960.0, 540.0));
+    assert_eq!(canvas.to_canvas(output, pt2(480.0, 0.0)), pt2(960.0, 0.0));
+}
+
+#[test]
+fn stretch_and_fill_cover_output() {
+    let output = vec2(960.0, 960.0);
+    let stretch = Canvas::new(1920.0, 1080.0).fit(Fit::Stretch);
+    assert!((stretch.viewport(output).wh() - output).length() < 1e-3);
+    let fill = Canvas::new(1920.0, 1080.0).fit(Fit::Fill);
+    let scale = 960.0 / 1080.0;
+    assert_eq!(fill.scale(output), vec2(scale, scale));
+    assert!(fill.viewport(output).w() > output.x);
+}
+
+#[test]
+fn clip_transform_round_trips_points() {
+    let output = vec2(800.0, 600.0);
+    let canvas = Canvas::new(1920.0, 1080.0);
+    let p = pt2(300.0, -200.0);
+    let q = canvas.to_canvas(output, canvas.to_output(output, p));
+    assert!((p - q).length() < 1e-3);
+    let clip = canvas
+        .clip_transform(output)
+        .transform_point3(vec3(1.0, 1.0, 0.5));
+    let viewport = canvas.viewport(output);
+    assert!((clip.x - viewport.w() / output.x).abs() < 1e-5);
+    assert!((clip.y - viewport.h() / output.y).abs() < 1e-5);
+    assert_eq!(clip.z, 0.5);
+}
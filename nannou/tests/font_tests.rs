@@ -0,0 +1,118 @@
+use nannou::text::{self, font};
+
+// Build a TrueType font containing a single square glyph for the given character.
+//
+// The bundled fonts all share the same character set, so this allows for testing fallbacks.
+#[cfg(feature = "notosans")]
+fn stub_font(ch: char) -> text::Font {
+    fn u16s(values: &[u16]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|v| v.to_be_bytes().to_vec())
+            .collect()
+    }
+    // Character code, glyph id delta and the negative values -500 and -200 as `u16`s.
+    let (code, delta) = (ch as u16, 1u16.wrapping_sub(ch as u16));
+    let (n500, n200) = (0xFE0C, 0xFF38);
+    let tables: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (
+            b"cmap",
+            u16s(&[
+                0, 1, 3, 1, 0, 12, 4, 32, 0, 4, 4, 1, 0, code, 0xFFFF, 0, code, 0xFFFF, delta, 1,
+                0, 0,
+            ]),
+        ),
+        (
+            b"glyf",
+            u16s(&[
+                1, 100, 0, 600, 700, 3, 0, 0x0101, 0x0101, 100, 500, 0, n500, 0, 0, 700, 0,
+            ]),
+        ),
+        (
+            b"head",
+            u16s(&[
+                1, 0, 1, 0, 0, 0, 0x5F0F, 0x3CF5, 0, 1000, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 600,
+                700, 0, 8, 2, 0, 0,
+            ]),
+        ),
+        (
+            b"hhea",
+            u16s(&[
+                1, 0, 800, n200, 0, 700, 0, 0, 600, 1, 0, 0, 0, 0, 0, 0, 0, 2,
+            ]),
+        ),
+        (b"hmtx", u16s(&[500, 0, 700, 100])),
+        (b"loca", u16s(&[0, 0, 17])),
+        (b"maxp", u16s(&[0, 0x5000, 2])),
+    ];
+    let n = tables.len() as u16;
+    let mut bytes = u16s(&[1, 0, n, 64, 2, n * 16 - 64]);
+    let mut data = vec![];
+    for (tag, table) in &tables {
+        let offset = 12 + 16 * tables.len() + data.len();
+        bytes.extend_from_slice(&tag[..]);
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&(offset as u32).to_be_bytes());
+        bytes.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        data.extend_from_slice(table);
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+    }
+    bytes.extend(data);
+    font::from_bytes(bytes).expect("failed to load stub font")
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn select_prefers_primary_then_fallbacks() {
+    let primary = stub_font('a');
+    assert!(font::contains(&primary, 'a'));
+    assert!(!font::contains(&primary, 'b'));
+    let fallbacks = vec![font::default_notosans()];
+    let selected = font::select('a', &primary, &fallbacks);
+    assert!(std::ptr::eq(selected, &primary));
+    let selected = font::select('b', &primary, &fallbacks);
+    assert!(std::ptr::eq(selected, &fallbacks[0]));
+    let selected = font::select('\u{10FFFD}', &primary, &fallbacks);
+    assert!(std::ptr::eq(selected, &primary));
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn map_selects_fallback_for_missing_chars() {
+    let mut map = font::Map::new();
+    let stub = map.insert(stub_font('a'));
+    let noto = map.insert(font::default_notosans());
+    assert_ne!(stub, noto);
+    map.set_fallbacks(vec![noto]);
+    assert_eq!(map.fallbacks(), &[noto]);
+    assert_eq!(map.fallback_fonts().len(), 1);
+    assert_eq!(map.font_for_char(stub, 'a'), stub);
+    assert_eq!(map.font_for_char(stub, 'b'), noto);
+    assert_eq!(map.font_for_char(stub, '\u{10FFFD}'), stub);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn layout_builder_retains_fallbacks() {
+    let layout = text::layout::Builder::default()
+        .fallback_font(font::default_notosans())
+        .fallback_font(font::default_notosans())
+        .build();
+    assert_eq!(layout.fallback_fonts.len(), 2);
+    let rebuilt = text::layout::Builder::default().layout(&layout).build();
+    assert_eq!(rebuilt.fallback_fonts.len(), 2);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn fonts_with_identical_names_keep_their_own_data() {
+    let bytes = notosans::REGULAR_TTF;
+    let a = font::from_bytes(bytes.to_vec()).expect("failed to load font");
+    let b = font::from_bytes(bytes.to_vec()).expect("failed to load font");
+    let (a_data, _) = font::data(&a).expect("no data registered");
+    let (b_data, _) = font::data(&b).expect("no data registered");
+    assert_eq!(&a_data[..], bytes);
+    assert_ne!(a_data.as_ptr(), b_data.as_ptr());
+}
@@ -0,0 +1,38 @@
+use nannou::prelude::*;
+use nannou::state::Gamepads;
+
+fn event(id: usize, kind: GamepadEventKind) -> GamepadEvent {
+    let id = GamepadId(id);
+    GamepadEvent { id, kind }
+}
+
+#[test]
+fn gamepads_track_buttons_and_axes() {
+    let mut gamepads = Gamepads::default();
+    let name = "pad".to_string();
+    gamepads.apply_event(&event(0, GamepadEventKind::Connected { name }));
+    gamepads.apply_event(&event(
+        0,
+        GamepadEventKind::ButtonPressed(GamepadButton::South),
+    ));
+    gamepads.apply_event(&event(
+        0,
+        GamepadEventKind::AxisChanged(GamepadAxis::LeftStickX, 0.5),
+    ));
+
+    let (id, pad) = gamepads.first().unwrap();
+    assert_eq!(id, GamepadId(0));
+    assert_eq!(pad.name, "pad");
+    assert!(pad.is_pressed(GamepadButton::South));
+    assert_eq!(pad.left_stick(), pt2(0.5, 0.0));
+    assert!(gamepads.any_pressed(GamepadButton::South));
+
+    gamepads.apply_event(&event(
+        0,
+        GamepadEventKind::ButtonReleased(GamepadButton::South),
+    ));
+    assert!(!gamepads.any_pressed(GamepadButton::South));
+
+    gamepads.apply_event(&event(0, GamepadEventKind::Disconnected));
+    assert!(gamepads.is_empty());
+}
@@ -0,0 +1,114 @@
+use nannou::gesture::{Gesture, Recognizer, SwipeDirection};
+use nannou::prelude::*;
+use std::time::{Duration, Instant};
+
+fn touch(id: u64, phase: TouchPhase, x: f32, y: f32) -> TouchEvent {
+    let position = pt2(x, y);
+    TouchEvent {
+        id,
+        phase,
+        position,
+    }
+}
+
+#[test]
+fn taps_and_double_taps() {
+    let mut r = Recognizer::new();
+    let t = Instant::now();
+    let ms = Duration::from_millis;
+    assert!(r
+        .touch(&touch(0, TouchPhase::Started, 0.0, 0.0), t)
+        .is_empty());
+    let gestures = r.touch(&touch(0, TouchPhase::Ended, 1.0, 0.0), t + ms(50));
+    assert_eq!(
+        gestures,
+        vec![Gesture::Tap {
+            position: pt2(1.0, 0.0)
+        }]
+    );
+
+    r.touch(&touch(1, TouchPhase::Started, 0.0, 0.0), t + ms(150));
+    let gestures = r.touch(&touch(1, TouchPhase::Ended, 0.0, 0.0), t + ms(200));
+    assert_eq!(gestures.len(), 2);
+    assert_eq!(
+        gestures[1],
+        Gesture::DoubleTap {
+            position: pt2(0.0, 0.0)
+        }
+    );
+}
+
+#[test]
+fn long_press_suppresses_tap() {
+    let mut r = Recognizer::new();
+    let t = Instant::now();
+    r.touch(&touch(0, TouchPhase::Started, 0.0, 0.0), t);
+    assert!(r.update(t + Duration::from_millis(100)).is_empty());
+    let gestures = r.update(t + Duration::from_millis(600));
+    assert_eq!(
+        gestures,
+        vec![Gesture::LongPress {
+            position: pt2(0.0, 0.0)
+        }]
+    );
+    let gestures = r.touch(
+        &touch(0, TouchPhase::Ended, 0.0, 0.0),
+        t + Duration::from_millis(700),
+    );
+    assert!(gestures.is_empty());
+}
+
+#[test]
+fn fast_pan_ends_in_swipe() {
+    let mut r = Recognizer::new();
+    let t = Instant::now();
+    let ms = Duration::from_millis;
+    r.touch(&touch(0, TouchPhase::Started, 0.0, 0.0), t);
+    let gestures = r.touch(&touch(0, TouchPhase::Moved, 50.0, 0.0), t + ms(10));
+    match gestures[..] {
+        [Gesture::Pan {
+            phase: TouchPhase::Started,
+            delta,
+            ..
+        }] => assert_eq!(delta, vec2(50.0, 0.0)),
+        _ => panic!("expected pan to start: {:?}", gestures),
+    }
+    let gestures = r.touch(&touch(0, TouchPhase::Ended, 50.0, 0.0), t + ms(20));
+    match gestures.last() {
+        Some(Gesture::Swipe { direction, .. }) => assert_eq!(*direction, SwipeDirection::Right),
+        _ => panic!("expected swipe: {:?}", gestures),
+    }
+}
+
+#[test]
+fn pan_held_before_release_does_not_swipe() {
+    let mut r = Recognizer::new();
+    let t = Instant::now();
+    let ms = Duration::from_millis;
+    r.touch(&touch(0, TouchPhase::Started, 0.0, 0.0), t);
+    r.touch(&touch(0, TouchPhase::Moved, 50.0, 0.0), t + ms(10));
+    let gestures = r.touch(&touch(0, TouchPhase::Ended, 50.0, 0.0), t + ms(2000));
+    match gestures[..] {
+        [Gesture::Pan {
+            phase: TouchPhase::Ended,
+            ..
+        }] => (),
+        _ => panic!("expected pan to end without swipe: {:?}", gestures),
+    }
+}
+
+#[test]
+fn two_touches_pinch() {
+    let mut r = Recognizer::new();
+    let t = Instant::now();
+    r.touch(&touch(0, TouchPhase::Started, -10.0, 0.0), t);
+    r.touch(&touch(1, TouchPhase::Started, 10.0, 0.0), t);
+    let gestures = r.touch(&touch(1, TouchPhase::Moved, 30.0, 0.0), t);
+    match gestures[..] {
+        [Gesture::Pinch { scale, center }] => {
+            assert_eq!(scale, 2.0);
+            assert_eq!(center, pt2(10.0, 0.0));
+        }
+        _ => panic!("expected pinch: {:?}", gestures),
+    }
+}
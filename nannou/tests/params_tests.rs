@@ -0,0 +1,58 @@
+use nannou::params::{Params, PresetValue};
+use nannou::prelude::*;
+
+fn params() -> Params {
+    Params::new()
+        .float("radius", 50.0, 0.0..=100.0)
+        .bool("animate", false)
+        .color("fill", srgba(1.0, 0.5, 0.0, 1.0))
+        .choice("shape", 1, &["ellipse", "rect", "tri"])
+}
+
+#[test]
+fn float_params_are_clamped() {
+    let mut params = params();
+    assert!(params.set_f32("radius", 250.0));
+    assert_eq!(params.get_f32("radius"), Some(100.0));
+    assert!(params.set_f32("radius", -1.0));
+    assert_eq!(params.get_f32("radius"), Some(0.0));
+    assert!(!params.set_f32("animate", 1.0));
+    assert!(!params.set_f32("missing", 1.0));
+}
+
+#[test]
+fn choices_select_by_index() {
+    let mut params = params();
+    assert_eq!(params.get_choice_name("shape"), Some("rect"));
+    assert!(params.set_choice("shape", 2));
+    assert_eq!(params.get_choice_name("shape"), Some("tri"));
+    assert!(!params.set_choice("shape", 3));
+    assert_eq!(params.get_choice("shape"), Some(2));
+}
+
+#[test]
+fn presets_apply_by_name() {
+    let mut params = params();
+    params.set_f32("radius", 10.0);
+    params.set_bool("animate", true);
+    params.set_choice("shape", 2);
+    let preset = params.preset();
+    assert_eq!(
+        preset.values.get("shape"),
+        Some(&PresetValue::Choice("tri".to_string()))
+    );
+
+    let mut restored = self::params();
+    restored.apply_preset(&preset);
+    assert_eq!(restored.get_f32("radius"), Some(10.0));
+    assert_eq!(restored.get_bool("animate"), Some(true));
+    assert_eq!(restored.get_choice_name("shape"), Some("tri"));
+}
+
+#[test]
+fn osc_addresses_use_prefix() {
+    let mut params = params().osc_prefix("/sketch/");
+    assert_eq!(params.osc_address("radius"), "/sketch/radius");
+    assert!(params.get_by_osc_address_mut("/sketch/radius").is_some());
+    assert!(params.get_by_osc_address_mut("/param/radius").is_none());
+}
@@ -0,0 +1,77 @@
+use nannou::event::{
+    GamepadButton, GamepadEvent, GamepadEventKind, GamepadId, Key, MouseButton, Update, WindowEvent,
+};
+use nannou::prelude::*;
+use nannou::recording::{Entry, RecordedEvent, Recording};
+use std::time::Duration;
+
+#[test]
+fn recording_round_trips_through_json() {
+    let update = Update {
+        since_last: Duration::from_millis(16),
+        since_start: Duration::from_millis(32),
+        leftover: Duration::from_secs(0),
+    };
+    let events = vec![
+        RecordedEvent::WindowEvent {
+            window: 0,
+            event: WindowEvent::MouseMoved(pt2(10.0, -20.0)),
+        },
+        RecordedEvent::WindowEvent {
+            window: 0,
+            event: WindowEvent::MousePressed(MouseButton::Left),
+        },
+        RecordedEvent::WindowEvent {
+            window: 1,
+            event: WindowEvent::KeyPressed(Key::Space),
+        },
+        RecordedEvent::WindowEvent {
+            window: 1,
+            event: WindowEvent::DroppedFile("sketch.png".into()),
+        },
+        RecordedEvent::Gamepad(GamepadEvent {
+            id: GamepadId(0),
+            kind: GamepadEventKind::ButtonChanged(GamepadButton::RightTrigger2, 0.5),
+        }),
+        RecordedEvent::Update(update),
+    ];
+    let entries = events
+        .into_iter()
+        .enumerate()
+        .map(|(i, event)| Entry {
+            time: Duration::from_millis(i as u64 * 4),
+            event,
+        })
+        .collect();
+    let recording = Recording { entries };
+    assert_eq!(recording.updates(), 1);
+
+    let path = std::env::temp_dir().join("nannou_recording_round_trip.json");
+    recording.save(&path).unwrap();
+    let loaded = Recording::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded, recording);
+}
+
+#[test]
+fn only_input_events_are_recorded() {
+    assert!(WindowEvent::KeyReleased(Key::A).is_input());
+    assert!(WindowEvent::DroppedFile("a.txt".into()).is_input());
+    assert!(!WindowEvent::Resized(vec2(100.0, 100.0)).is_input());
+    assert!(!WindowEvent::Focused.is_input());
+}
+
+#[test]
+fn update_alpha_is_fraction_of_interval() {
+    let update = Update {
+        since_last: Duration::from_millis(20),
+        since_start: Duration::from_millis(40),
+        leftover: Duration::from_millis(5),
+    };
+    assert_eq!(update.alpha(), 0.25);
+    let first = Update {
+        since_last: Duration::from_secs(0),
+        ..update
+    };
+    assert_eq!(first.alpha(), 0.0);
+}
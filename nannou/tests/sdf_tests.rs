@@ -0,0 +1,81 @@
+#![cfg(feature = "notosans")]
+
+use nannou::text::{self, sdf};
+
+#[test]
+fn field_is_inside_at_centre_of_stroke() {
+    let font = text::font::default_notosans();
+    let id = font.glyph('l').id();
+    let field = sdf::generate(&font, id).unwrap();
+    assert_eq!(field.data.len(), (field.width * field.height) as usize);
+    // The padded border lies well outside the outline.
+    assert!(field.data[0] < 128);
+    let row = field.height as usize / 2;
+    let max = field.data[row * field.width as usize..][..field.width as usize]
+        .iter()
+        .max()
+        .unwrap();
+    assert!(*max > 128);
+    assert!(field.bounds.w() > 0.0 && field.bounds.h() > 0.0);
+}
+
+#[test]
+fn atlas_packs_each_glyph_once() {
+    let font = text::font::default_notosans();
+    let mut atlas = sdf::Atlas::new(256, 256);
+    let a = font.glyph('a').id();
+    let b = font.glyph('b').id();
+    let space = font.glyph(' ').id();
+    let mut uploads = 0;
+    let ea = atlas
+        .get_or_insert(&font, a, |_, _| uploads += 1)
+        .unwrap()
+        .unwrap();
+    let eb = atlas
+        .get_or_insert(&font, b, |_, _| uploads += 1)
+        .unwrap()
+        .unwrap();
+    let ea2 = atlas
+        .get_or_insert(&font, a, |_, _| uploads += 1)
+        .unwrap()
+        .unwrap();
+    assert_eq!(uploads, 2);
+    assert_eq!(ea, ea2);
+    assert!(ea.uv_rect.max.x <= eb.uv_rect.min.x);
+    assert_eq!(atlas.get_or_insert(&font, space, |_, _| ()), Ok(None));
+}
+
+#[test]
+fn atlas_reports_when_full() {
+    let font = text::font::default_notosans();
+    let mut atlas = sdf::Atlas::new(64, 64);
+    let results: Vec<_> = "abcdefgh"
+        .chars()
+        .map(|ch| atlas.get_or_insert(&font, font.glyph(ch).id(), |_, _| ()))
+        .collect();
+    assert!(results.iter().any(|r| *r == Err(sdf::AtlasFull)));
+    atlas.clear();
+    assert!(atlas.get(&font, font.glyph('a').id()).is_none());
+}
+
+#[test]
+fn glyph_cache_clears_full_atlas_and_retries() {
+    use nannou::draw::renderer::{GlyphCache, GlyphCacheMode};
+    let font = text::font::default_notosans();
+    let mut cache = GlyphCache {
+        mode: GlyphCacheMode::Sdf,
+        cache: text::GlyphCache::builder().dimensions(64, 64).build(),
+        sdf_atlas: sdf::Atlas::new(64, 64),
+        pixel_buffer: vec![0; 64 * 64],
+        requires_upload: false,
+    };
+    let ids: Vec<_> = "abcdefgh".chars().map(|ch| font.glyph(ch).id()).collect();
+    let full = ids.iter().any(|&id| cache.sdf_entry(&font, id).is_err());
+    assert!(full);
+    let glyphs = [(&font, ids[0]), (&font, ids[1])];
+    let entries = cache.sdf_entries(&glyphs).unwrap();
+    assert!(entries.iter().all(|entry| entry.is_some()));
+    assert_eq!(cache.sdf_atlas.get(&font, ids[0]), Some(entries[0]));
+    let all: Vec<_> = ids.iter().map(|&id| (&font, id)).collect();
+    assert_eq!(cache.sdf_entries(&all), Err(sdf::AtlasFull));
+}
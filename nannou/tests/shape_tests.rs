@@ -0,0 +1,54 @@
+use nannou::geom::Rect;
+use nannou::text::{self, shape};
+
+#[test]
+fn bidi_visual_runs() {
+    let s = "abc אבג def";
+    let runs = shape::visual_runs(s, 0..s.len());
+    let dirs: Vec<_> = runs.iter().map(|(_, dir)| *dir).collect();
+    assert_eq!(
+        dirs,
+        vec![
+            shape::Direction::LeftToRight,
+            shape::Direction::RightToLeft,
+            shape::Direction::LeftToRight,
+        ]
+    );
+    assert_eq!(s[runs[1].0.clone()].trim(), "אבג");
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn rtl_glyphs_are_in_visual_order() {
+    let font = text::font::default_notosans();
+    let s = "אבג";
+    let glyphs = shape::shape(s, &font, 24, shape::Direction::RightToLeft);
+    assert_eq!(glyphs.first().map(|g| g.char), Some(2));
+    assert_eq!(glyphs.last().map(|g| g.char), Some(0));
+    for pair in glyphs.windows(2) {
+        assert!(pair[0].position.x <= pair[1].position.x);
+    }
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn char_metrics_match_shaped_advances() {
+    let font = text::font::default_notosans();
+    let s = "office";
+    let glyphs = shape::shape(s, &font, 24, shape::Direction::LeftToRight);
+    let metrics = shape::char_metrics(s, &font, 24, shape::Direction::LeftToRight);
+    let shaped: f32 = glyphs.iter().map(|g| g.advance).sum();
+    let measured: f32 = metrics.iter().map(|&(w, _)| w).sum();
+    assert!((shaped - measured).abs() < 0.001);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn wrap_skips_trailing_whitespace() {
+    let rect = Rect::from_w_h(40.0, 400.0);
+    let text = text::text("aaa  bbb").font_size(12).build(rect);
+    let infos = text.line_infos();
+    assert_eq!(infos.len(), 2);
+    assert_eq!(infos[0].byte_range(), 0..3);
+    assert_eq!(infos[1].byte_range(), 5..8);
+}
@@ -0,0 +1,45 @@
+use nannou::snapshot::Snapshot;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Model {
+    seed: u64,
+    camera: [f32; 3],
+    #[serde(skip)]
+    frames: u32,
+}
+
+#[test]
+fn snapshot_round_trips_through_json() {
+    let path = std::env::temp_dir().join("nannou_snapshot_round_trip.json");
+    let _ = std::fs::remove_file(&path);
+    let snapshot = Snapshot::new(&path);
+
+    // Without a snapshot file the model is kept.
+    let fresh = Model {
+        frames: 1,
+        ..Default::default()
+    };
+    let fresh = snapshot.restore(fresh);
+    assert_eq!(fresh.frames, 1);
+
+    let model = Model {
+        seed: 42,
+        camera: [1.0, 2.0, -3.0],
+        frames: 100,
+    };
+    snapshot.save(&model).unwrap();
+    let restored = snapshot.restore(fresh);
+    assert_eq!(restored.seed, 42);
+    assert_eq!(restored.camera, [1.0, 2.0, -3.0]);
+    assert_eq!(restored.frames, 0);
+
+    // A snapshot of an incompatible model is ignored.
+    std::fs::write(&path, "{\"seed\":\"not a number\"}").unwrap();
+    let kept = snapshot.restore(Model {
+        seed: 7,
+        ..Default::default()
+    });
+    assert_eq!(kept.seed, 7);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,71 @@
+use nannou::geom::Rect;
+use nannou::text::{self, span};
+
+#[test]
+fn concat_produces_contiguous_spans() {
+    let (s, spans) = span::concat(vec![
+        ("Hello, ", span::Style::new()),
+        ("world", span::Style::new().underline()),
+        ("!", span::Style::new().font_size(48)),
+    ]);
+    assert_eq!(s, "Hello, world!");
+    assert_eq!(spans.len(), 3);
+    assert_eq!(spans[0].range, 0..7);
+    assert_eq!(spans[1].range, 7..12);
+    assert_eq!(spans[2].range, 12..13);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn later_spans_take_precedence() {
+    let font = text::font::default_notosans();
+    let spans = vec![
+        text::Span::new(0..10, span::Style::new().font_size(20).underline()),
+        text::Span::new(5..10, span::Style::new().font_size(30)),
+    ];
+    let a = span::resolve(&spans, 2, &font, 12);
+    assert_eq!(a.font_size, 20);
+    assert!(a.underline);
+    let b = span::resolve(&spans, 7, &font, 12);
+    assert_eq!(b.font_size, 30);
+    assert!(b.underline);
+    let c = span::resolve(&spans, 12, &font, 12);
+    assert_eq!(c.font_size, 12);
+    assert!(!c.underline);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn spans_split_runs_and_grow_lines() {
+    let rect = Rect::from_w_h(400.0, 400.0);
+    let plain = text::text("one two").font_size(12).build(rect);
+    assert_eq!(plain.runs().len(), 1);
+
+    let styled = text::text("one two")
+        .font_size(12)
+        .span(4..7, span::Style::new().font_size(36).strikethrough())
+        .build(rect);
+    let runs = styled.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].byte_range, 0..4);
+    assert_eq!(runs[1].byte_range, 4..7);
+    assert_eq!(runs[1].start_char, 4);
+    assert_eq!(runs[0].rect.right(), runs[1].rect.left());
+    assert_eq!(runs[0].rect.bottom(), runs[1].rect.bottom());
+    assert!(styled.width() > plain.width());
+    assert!(styled.height_by_lines() > plain.height_by_lines());
+    assert_eq!(styled.decorations().count(), 1);
+}
+
+#[cfg(feature = "notosans")]
+#[test]
+fn larger_spans_wrap_sooner() {
+    let rect = Rect::from_w_h(100.0, 400.0);
+    let s = "aaa bbb ccc ddd eee";
+    let plain = text::text(s).font_size(12).build(rect);
+    let styled = text::text(s)
+        .font_size(12)
+        .span(0..s.len(), span::Style::new().font_size(32))
+        .build(rect);
+    assert!(styled.num_lines() > plain.num_lines());
+}
@@ -0,0 +1,57 @@
+use nannou::draw::Svg;
+use nannou::prelude::*;
+
+#[test]
+fn svg_rect_and_line() {
+    let draw = Draw::new();
+    draw.background().color(BLACK);
+    draw.rect().x_y(10.0, 20.0).w_h(40.0, 30.0).color(RED);
+    draw.line()
+        .start(pt2(-50.0, 0.0))
+        .end(pt2(50.0, 0.0))
+        .weight(2.0)
+        .color(WHITE);
+    let svg = Svg::from_draw(&draw, vec2(200.0, 100.0)).to_string();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("fill=\"#ff0000\""));
+    assert!(svg.contains("stroke=\"#ffffff\""));
+    assert!(svg.contains("stroke-width=\"2\""));
+    // The rect's top left corner in SVG space.
+    assert!(svg.contains("M 90 15"));
+}
+
+#[test]
+fn svg_scissor_clip_path() {
+    let draw = Draw::new();
+    let rect = geom::Rect::from_w_h(20.0, 20.0);
+    draw.scissor(rect).ellipse().w_h(50.0, 50.0);
+    let svg = Svg::from_draw(&draw, vec2(100.0, 100.0)).to_string();
+    assert!(svg.contains("<clipPath id=\"clip0\">"));
+    assert!(svg.contains("clip-path=\"url(#clip0)\""));
+}
+
+#[test]
+fn svg_gradient_fill() {
+    let draw = Draw::new();
+    let stops = vec![(0.0, RED), (1.0, BLUE)];
+    draw.rect()
+        .w_h(40.0, 40.0)
+        .gradient_radial(pt2(0.0, 0.0), 20.0, stops);
+    let svg = Svg::from_draw(&draw, vec2(100.0, 100.0)).to_string();
+    assert!(svg.contains("<radialGradient id=\"gradient0\""));
+    assert!(svg.contains("<stop offset=\"1\" stop-color=\"#0000ff\"/>"));
+    assert!(svg.contains("fill=\"url(#gradient0)\""));
+}
+
+#[test]
+fn svg_dashed_line() {
+    let draw = Draw::new();
+    draw.line()
+        .start(pt2(-50.0, 0.0))
+        .end(pt2(50.0, 0.0))
+        .dashes(&[10.0, 15.0]);
+    let svg = Svg::from_draw(&draw, vec2(200.0, 100.0)).to_string();
+    // Four dashes fit within the 100 unit line.
+    assert_eq!(svg.matches('M').count(), 4);
+    assert!(svg.contains("M 50 50 L 60 50"));
+}
@@ -0,0 +1,124 @@
+use nannou::draw::renderer::{self, VertexMode};
+use nannou::prelude::*;
+
+#[test]
+fn tessellate_rect() {
+    let draw = Draw::new();
+    draw.rect().w_h(10.0, 10.0).color(RED);
+    let tess = renderer::tessellate(&draw, [100, 100], 1.0);
+    assert_eq!(tess.mesh.points().len(), 4);
+    assert_eq!(tess.mesh.indices().len(), 6);
+    assert_eq!(tess.vertex_modes.len(), tess.mesh.points().len());
+    assert!(tess.vertex_modes.iter().all(|&m| m == VertexMode::Color));
+}
+
+#[test]
+fn tessellate_vertex_modes_per_primitive() {
+    let draw = Draw::new();
+    draw.ellipse().radius(20.0);
+    draw.text("nannou");
+    let tess = renderer::tessellate(&draw, [400, 400], 1.0);
+    assert_eq!(tess.vertex_modes.len(), tess.mesh.points().len());
+    assert_eq!(tess.vertex_modes.first(), Some(&VertexMode::Color));
+    assert_eq!(tess.vertex_modes.last(), Some(&VertexMode::Text));
+}
+
+#[test]
+fn tessellate_cuboid_face_colors() {
+    let draw = Draw::new();
+    draw.cuboid()
+        .w_h_d(10.0, 20.0, 30.0)
+        .color(BLUE)
+        .face_color(nannou::geom::cuboid::Face::Front, RED);
+    let tess = renderer::tessellate(&draw, [100, 100], 1.0);
+    assert_eq!(tess.mesh.points().len(), 36);
+    assert_eq!(tess.mesh.indices().len(), 36);
+    let red = tess.mesh.colors().iter().filter(|c| c.red > 0.5).count();
+    assert_eq!(red, 6);
+}
+
+#[test]
+fn tessellate_sphere_resolution() {
+    use nannou::draw::primitive::parametric::SetParametric;
+    use nannou::draw::primitive::Sphere;
+    use nannou::mesh::{Normals, Points, TexCoords};
+
+    let sphere = Sphere::default().radius(10.0).resolution(8, 4);
+    let surface = sphere.mesh();
+    assert_eq!(surface.points().len(), 9 * 5);
+    assert_eq!(surface.tex_coords().len(), 9 * 5);
+    for (p, n) in surface.points().iter().zip(surface.normals()) {
+        assert!((p.length() - 10.0).abs() < 1e-3);
+        assert!((n.length() - 1.0).abs() < 1e-3);
+    }
+
+    let draw = Draw::new();
+    draw.sphere().radius(10.0).resolution(8, 4);
+    let tess = renderer::tessellate(&draw, [100, 100], 1.0);
+    assert_eq!(tess.mesh.points().len(), 9 * 5);
+    assert_eq!(tess.mesh.indices().len(), 8 * 4 * 6);
+}
+
+#[test]
+fn tessellate_lit_mesh_normals() {
+    let draw = Draw::new();
+    draw.rect().w_h(10.0, 10.0);
+    let normal = vec3(0.0, 0.0, 2.0);
+    let tri = geom::Tri([
+        (pt3(0.0, 0.0, 0.0), normal),
+        (pt3(10.0, 0.0, 0.0), normal),
+        (pt3(0.0, 10.0, 0.0), normal),
+    ]);
+    draw.mesh().tris_lit(Some(tri)).x(50.0).rotate(PI);
+    let tess = renderer::tessellate(&draw, [100, 100], 1.0);
+    assert_eq!(tess.mesh.normals().len(), tess.mesh.points().len());
+    assert_eq!(&tess.vertex_modes[..4], &[VertexMode::Color; 4]);
+    assert_eq!(&tess.vertex_modes[4..], &[VertexMode::Lit; 3]);
+    assert!(tess.mesh.normals()[..4].iter().all(|&n| n == Vec3::ZERO));
+    for n in &tess.mesh.normals()[4..] {
+        assert!((*n - Vec3::Z).length() < 1e-5);
+    }
+}
+
+#[test]
+fn tessellate_linear_gradient_stops() {
+    use nannou::color::IntoLinSrgba;
+
+    let draw = Draw::new();
+    let stops = vec![(0.0, RED), (0.5, GREEN), (1.0, BLUE)];
+    draw.rect()
+        .w_h(100.0, 10.0)
+        .gradient_linear(pt2(-50.0, 0.0), pt2(50.0, 0.0), stops);
+    let tess = renderer::tessellate(&draw, [200, 200], 1.0);
+    let points = tess.mesh.points();
+    let colors = tess.mesh.colors();
+    let color_at = |x: f32| {
+        let ix = points.iter().position(|p| (p.x - x).abs() < 1e-3);
+        ix.map(|ix| colors[ix])
+    };
+    // The triangles are split at the middle stop so that it is reproduced exactly.
+    assert_eq!(color_at(-50.0), Some(RED.into_lin_srgba()));
+    assert_eq!(color_at(0.0), Some(GREEN.into_lin_srgba()));
+    assert_eq!(color_at(50.0), Some(BLUE.into_lin_srgba()));
+}
+
+#[test]
+fn tessellate_radial_gradient_shares_vertices() {
+    let stops = vec![(0.0, WHITE), (1.0, BLACK)];
+    let draw = Draw::new();
+    draw.rect()
+        .w_h(100.0, 100.0)
+        .gradient_radial(pt2(0.0, 0.0), 50.0, stops.clone());
+    let tess = renderer::tessellate(&draw, [200, 200], 1.0);
+    // Each of the rect's two triangles is subdivided into a grid of at most 33 rows of vertices.
+    assert!(tess.mesh.points().len() <= 2 * 33 * 34 / 2);
+    assert_eq!(tess.mesh.indices().len() % 3, 0);
+
+    // Small primitives are barely subdivided.
+    let draw = Draw::new();
+    draw.rect()
+        .w_h(4.0, 4.0)
+        .gradient_radial(pt2(0.0, 0.0), 2.0, stops);
+    let tess = renderer::tessellate(&draw, [200, 200], 1.0);
+    assert!(tess.mesh.points().len() <= 2 * 6);
+}
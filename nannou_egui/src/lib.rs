@@ -4,6 +4,8 @@ pub use egui_wgpu_backend;
 
 use egui::{pos2, ClippedMesh, CtxRef};
 use egui_wgpu_backend::{epi, ScreenDescriptor};
+use nannou::color::LinSrgba;
+use nannou::params::{self, Params};
 use nannou::{wgpu, winit::event::VirtualKeyCode, winit::event::WindowEvent::*};
 use std::{
     cell::RefCell,
@@ -441,6 +443,57 @@ impl<'a> Deref for FrameCtx<'a> {
     }
 }
 
+/// Add a widget for editing each of the given parameters to the `ui`.
+///
+/// Floats are edited via sliders, bools via checkboxes, colors via color pickers and choices via
+/// combo boxes. Returns `true` if any of the parameters were changed.
+pub fn edit_params(ui: &mut egui::Ui, params: &mut Params) -> bool {
+    let mut changed = false;
+    for param in params {
+        let name = &param.name;
+        match param.value {
+            params::Value::Float {
+                ref mut value,
+                min,
+                max,
+            } => {
+                let slider = egui::Slider::new(value, min..=max).text(name);
+                changed |= ui.add(slider).changed();
+            }
+            params::Value::Bool(ref mut b) => {
+                changed |= ui.checkbox(b, name).changed();
+            }
+            params::Value::Color(ref mut color) => {
+                let (r, g, b, a) = color.into_components();
+                let mut rgba = [r, g, b, a];
+                ui.horizontal(|ui| {
+                    if ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed() {
+                        let [r, g, b, a] = rgba;
+                        *color = LinSrgba::new(r, g, b, a);
+                        changed = true;
+                    }
+                    ui.label(name);
+                });
+            }
+            params::Value::Choice {
+                ref mut selected,
+                ref options,
+            } => {
+                let before = *selected;
+                egui::ComboBox::from_label(name)
+                    .selected_text(&options[*selected])
+                    .show_ui(ui, |ui| {
+                        for (ix, option) in options.iter().enumerate() {
+                            ui.selectable_value(selected, ix, option);
+                        }
+                    });
+                changed |= *selected != before;
+            }
+        }
+    }
+    changed
+}
+
 impl epi::RepaintSignal for RepaintSignal {
     fn request_repaint(&self) {
         if let Ok(guard) = self.0.lock() {
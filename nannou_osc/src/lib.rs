@@ -18,6 +18,8 @@ pub use self::send::Sender;
 use std;
 use std::net::{Ipv4Addr, SocketAddr};
 
+#[cfg(feature = "params")]
+pub mod params;
 pub mod recv;
 pub mod send;
 
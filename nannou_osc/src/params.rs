@@ -0,0 +1,120 @@
+//! Binding the parameters of a `nannou::params::Params` registry to OSC addresses.
+//!
+//! Each parameter is bound to the address produced by `Params::osc_address`, e.g.
+//! `/param/radius`. Messages are applied as follows:
+//!
+//! - **Float** parameters accept a single `Float`, `Double`, `Int` or `Long` argument.
+//! - **Bool** parameters accept a `Bool` argument or a number, where non-zero is `true`.
+//! - **Color** parameters accept an OSC `Color` or three or four numeric sRGBA components in the
+//!   range `0.0..=1.0`. Components outside of this range are clamped.
+//! - **Choice** parameters accept either an option index or an option name as a `String`.
+
+use super::{CommunicationError, Connected, Message, Packet, Receiver, Type, Unconnected};
+use nannou::color::Srgba;
+use nannou::params::{Params, Value};
+
+/// Apply the given message to the parameter bound to its address.
+///
+/// Returns `true` if the message was addressed to a parameter and its arguments were valid.
+pub fn apply_msg(params: &mut Params, msg: &Message) -> bool {
+    let param = match params.get_by_osc_address_mut(&msg.addr) {
+        None => return false,
+        Some(param) => param,
+    };
+    let args = match msg.args {
+        None => return false,
+        Some(ref args) => &args[..],
+    };
+    match param.value {
+        Value::Float { .. } => match args {
+            [arg] => arg_to_f32(arg)
+                .map(|f| param.value.set_f32(f))
+                .unwrap_or(false),
+            _ => false,
+        },
+        Value::Bool(_) => match args {
+            [Type::Bool(b)] => param.value.set_bool(*b),
+            [arg] => arg_to_f32(arg)
+                .map(|f| param.value.set_bool(f != 0.0))
+                .unwrap_or(false),
+            _ => false,
+        },
+        Value::Color(_) => match args {
+            [Type::Color(c)] => param
+                .value
+                .set_color(Srgba::new(c.red, c.green, c.blue, c.alpha)),
+            [r, g, b] => match (arg_to_unit(r), arg_to_unit(g), arg_to_unit(b)) {
+                (Some(r), Some(g), Some(b)) => param.value.set_color(Srgba::new(r, g, b, 1.0)),
+                _ => false,
+            },
+            [r, g, b, a] => match (
+                arg_to_unit(r),
+                arg_to_unit(g),
+                arg_to_unit(b),
+                arg_to_unit(a),
+            ) {
+                (Some(r), Some(g), Some(b), Some(a)) => {
+                    param.value.set_color(Srgba::new(r, g, b, a))
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        Value::Choice { .. } => match args {
+            [Type::String(name)] => param.value.set_choice_name(name),
+            [Type::Int(ix)] if *ix >= 0 => param.value.set_choice(*ix as usize),
+            [Type::Long(ix)] if *ix >= 0 => param.value.set_choice(*ix as usize),
+            _ => false,
+        },
+    }
+}
+
+/// Apply every message within the given packet, returning the number of parameters updated.
+pub fn apply_packet(params: &mut Params, packet: Packet) -> usize {
+    packet
+        .into_msgs()
+        .iter()
+        .filter(|msg| apply_msg(params, msg))
+        .count()
+}
+
+impl Receiver<Unconnected> {
+    /// Apply all pending packets to the given parameters without blocking.
+    ///
+    /// Returns the number of parameters updated.
+    pub fn try_update_params(&self, params: &mut Params) -> Result<usize, CommunicationError> {
+        let mut count = 0;
+        while let Some((packet, _addr)) = self.try_recv()? {
+            count += apply_packet(params, packet);
+        }
+        Ok(count)
+    }
+}
+
+impl Receiver<Connected> {
+    /// Apply all pending packets to the given parameters without blocking.
+    ///
+    /// Returns the number of parameters updated.
+    pub fn try_update_params(&self, params: &mut Params) -> Result<usize, CommunicationError> {
+        let mut count = 0;
+        while let Some(packet) = self.try_recv()? {
+            count += apply_packet(params, packet);
+        }
+        Ok(count)
+    }
+}
+
+fn arg_to_f32(arg: &Type) -> Option<f32> {
+    match *arg {
+        Type::Float(f) => Some(f),
+        Type::Double(d) => Some(d as f32),
+        Type::Int(i) => Some(i as f32),
+        Type::Long(l) => Some(l as f32),
+        _ => None,
+    }
+}
+
+// A numeric argument clamped to the range `0.0..=1.0`, e.g. a color component.
+fn arg_to_unit(arg: &Type) -> Option<f32> {
+    arg_to_f32(arg).map(|f| f.clamp(0.0, 1.0))
+}
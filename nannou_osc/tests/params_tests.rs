@@ -0,0 +1,110 @@
+#![cfg(feature = "params")]
+
+use nannou::params::Params;
+use nannou::prelude::*;
+use nannou_osc::params::apply_msg;
+use nannou_osc::{Message, Type};
+
+fn params() -> Params {
+    Params::new()
+        .float("radius", 50.0, 0.0..=100.0)
+        .bool("animate", false)
+        .color("fill", srgba(1.0, 0.5, 0.0, 1.0))
+        .choice("shape", 1, &["ellipse", "rect", "tri"])
+}
+
+// Apply a message with the given arguments to the parameter with the given name.
+fn apply(params: &mut Params, name: &str, args: Vec<Type>) -> bool {
+    let msg = Message {
+        addr: params.osc_address(name),
+        args: Some(args),
+    };
+    apply_msg(params, &msg)
+}
+
+#[test]
+fn float_from_int_long_and_double() {
+    let mut params = params();
+    assert!(apply(&mut params, "radius", vec![Type::Int(10)]));
+    assert_eq!(params.get_f32("radius"), Some(10.0));
+    assert!(apply(&mut params, "radius", vec![Type::Long(20)]));
+    assert_eq!(params.get_f32("radius"), Some(20.0));
+    assert!(apply(&mut params, "radius", vec![Type::Double(30.5)]));
+    assert_eq!(params.get_f32("radius"), Some(30.5));
+    assert!(!apply(
+        &mut params,
+        "radius",
+        vec![Type::String("40".into())]
+    ));
+    assert_eq!(params.get_f32("radius"), Some(30.5));
+}
+
+#[test]
+fn bool_from_number() {
+    let mut params = params();
+    assert!(apply(&mut params, "animate", vec![Type::Int(1)]));
+    assert_eq!(params.get_bool("animate"), Some(true));
+    assert!(apply(&mut params, "animate", vec![Type::Float(0.0)]));
+    assert_eq!(params.get_bool("animate"), Some(false));
+    assert!(apply(&mut params, "animate", vec![Type::Bool(true)]));
+    assert_eq!(params.get_bool("animate"), Some(true));
+}
+
+#[test]
+fn color_from_three_and_four_components() {
+    let mut params = params();
+    let rgb = vec![Type::Float(0.0), Type::Int(1), Type::Double(0.0)];
+    assert!(apply(&mut params, "fill", rgb));
+    assert_eq!(
+        params.get_color("fill"),
+        Some(lin_srgba(0.0, 1.0, 0.0, 1.0))
+    );
+    let rgba = vec![
+        Type::Float(1.0),
+        Type::Float(0.0),
+        Type::Float(1.0),
+        Type::Float(0.0),
+    ];
+    assert!(apply(&mut params, "fill", rgba));
+    assert_eq!(
+        params.get_color("fill"),
+        Some(lin_srgba(1.0, 0.0, 1.0, 0.0))
+    );
+    assert!(!apply(&mut params, "fill", vec![Type::Float(1.0)]));
+}
+
+#[test]
+fn color_components_are_clamped() {
+    let mut params = params();
+    let rgba = vec![
+        Type::Float(2.0),
+        Type::Float(-1.0),
+        Type::Int(5),
+        Type::Double(1.5),
+    ];
+    assert!(apply(&mut params, "fill", rgba));
+    assert_eq!(
+        params.get_color("fill"),
+        Some(lin_srgba(1.0, 0.0, 1.0, 1.0))
+    );
+}
+
+#[test]
+fn choice_by_index_or_name() {
+    let mut params = params();
+    assert!(apply(&mut params, "shape", vec![Type::Int(2)]));
+    assert_eq!(params.get_choice_name("shape"), Some("tri"));
+    assert!(apply(
+        &mut params,
+        "shape",
+        vec![Type::String("ellipse".into())]
+    ));
+    assert_eq!(params.get_choice("shape"), Some(0));
+    assert!(!apply(&mut params, "shape", vec![Type::Int(3)]));
+    assert!(!apply(
+        &mut params,
+        "shape",
+        vec![Type::String("star".into())]
+    ));
+    assert_eq!(params.get_choice("shape"), Some(0));
+}